@@ -1,7 +1,9 @@
 use anchor_lang::{AccountDeserialize, InstructionData, ToAccountMetas};
+use bounty_hunter::state::BountyAccess;
 use solana_client::rpc_config::RpcProgramAccountsConfig;
 use solana_client::rpc_filter::{Memcmp, MemcmpEncodedBytes, RpcFilterType};
-use solana_sdk::instruction::Instruction;
+use solana_sdk::hash::Hash;
+use solana_sdk::instruction::{AccountMeta, Instruction};
 use {
     clap::{crate_description, crate_name, crate_version, Arg, Command},
     solana_clap_v3_utils::{
@@ -21,9 +23,12 @@ use {
         signature::{Signature, Signer},
         transaction::Transaction,
     },
-    std::{error::Error, process::exit, rc::Rc, sync::Arc},
+    std::{error::Error, process::exit, rc::Rc, str::FromStr, sync::Arc},
 };
 
+/// passed with the token accounts in case a recipient requires memos on incoming transfers
+const MEMO_PROGRAM_ID: Pubkey = solana_sdk::pubkey!("MemoSq4gqABAXKb96qnH8TysNcWxMyWCqXgDLGmfcHr");
+
 struct Config {
     commitment_config: CommitmentConfig,
     payer: Arc<dyn Signer>,
@@ -31,6 +36,134 @@ struct Config {
     verbose: bool,
 }
 
+/// the token accounts an instruction needs for a bounty paid in an SPL token
+#[derive(Clone, Copy)]
+struct TokenAccounts {
+    vault: Pubkey,
+    owner_ata: Pubkey,
+    token_program: Pubkey,
+}
+
+async fn fetch_token_accounts(
+    rpc_client: &Arc<RpcClient>,
+    mint: &Pubkey,
+    bounty: &Pubkey,
+    owner: &Pubkey,
+) -> TokenAccounts {
+    let mint_acc = rpc_client.get_account(mint).await.unwrap();
+
+    // Token-2022 mints derive their associated token accounts from their own program id
+    TokenAccounts {
+        vault: spl_associated_token_account_interface::address::get_associated_token_address_with_program_id(
+            bounty,
+            mint,
+            &mint_acc.owner,
+        ),
+        owner_ata: spl_associated_token_account_interface::address::get_associated_token_address_with_program_id(
+            owner,
+            mint,
+            &mint_acc.owner,
+        ),
+        token_program: mint_acc.owner,
+    }
+}
+
+/// the remaining accounts paying (with a treasury) or refunding every reward leg to `recipient`,
+/// together with the instructions creating the recipient's token accounts if they don't exist
+async fn fetch_reward_leg_accounts(
+    rpc_client: &Arc<RpcClient>,
+    payer: &Pubkey,
+    bounty: &bounty_hunter::state::Bounty,
+    bounty_address: &Pubkey,
+    recipient: &Pubkey,
+    treasury: Option<&Pubkey>,
+) -> (Vec<AccountMeta>, Vec<Instruction>) {
+    let mut accounts = Vec::new();
+    let mut create_accounts = Vec::new();
+
+    for leg in &bounty.reward_legs {
+        let leg_accounts =
+            fetch_token_accounts(rpc_client, &leg.mint, bounty_address, recipient).await;
+
+        accounts.push(AccountMeta::new(leg.mint, false));
+        accounts.push(AccountMeta::new(leg_accounts.vault, false));
+        accounts.push(AccountMeta::new(leg_accounts.owner_ata, false));
+        create_accounts.push(
+            spl_associated_token_account_interface::instruction::create_associated_token_account_idempotent(
+                payer,
+                recipient,
+                &leg.mint,
+                &leg_accounts.token_program,
+            ),
+        );
+
+        if let Some(treasury) = treasury {
+            let treasury_token_account = spl_associated_token_account_interface::address::get_associated_token_address_with_program_id(
+                treasury,
+                &leg.mint,
+                &leg_accounts.token_program,
+            );
+            accounts.push(AccountMeta::new(treasury_token_account, false));
+            create_accounts.push(
+                spl_associated_token_account_interface::instruction::create_associated_token_account_idempotent(
+                    payer,
+                    treasury,
+                    &leg.mint,
+                    &leg_accounts.token_program,
+                ),
+            );
+        }
+
+        accounts.push(AccountMeta::new_readonly(leg_accounts.token_program, false));
+    }
+
+    (accounts, create_accounts)
+}
+
+fn format_reward_legs(legs: &[bounty_hunter::state::RewardLeg]) -> String {
+    legs.iter()
+        .map(|leg| {
+            format!(
+                "\n\t\t {} - {} - escrowed: {}",
+                leg.mint, leg.amount, leg.escrowed
+            )
+        })
+        .collect()
+}
+
+fn format_milestones(milestones: &[bounty_hunter::state::Milestone]) -> String {
+    milestones
+        .iter()
+        .enumerate()
+        .map(|(index, milestone)| {
+            format!(
+                "\n\t\t [{}] {} - {} - approved submission: {:?}",
+                index, milestone.description, milestone.amount, milestone.approved_submission
+            )
+        })
+        .collect()
+}
+
+fn format_access(access: &BountyAccess) -> String {
+    match access {
+        BountyAccess::Public => "public".to_string(),
+        BountyAccess::AllowList { hunters } => format!("allow-list {:?}", hunters),
+        BountyAccess::MerkleRoot { root } => format!("merkle root {}", Hash::new_from_array(*root)),
+    }
+}
+
+fn parse_hash(hash: &str) -> [u8; 32] {
+    Hash::from_str(hash)
+        .expect("unable to parse base58 hash")
+        .to_bytes()
+}
+
+/// SHA-256 of a local copy of the metadata document, stored on chain next to the URI it is published at
+fn hash_metadata_file(path: &str) -> [u8; 32] {
+    let document = std::fs::read(path).expect("unable to read metadata file");
+    solana_sdk::hash::hash(&document).to_bytes()
+}
+
 async fn process_get_bounty(
     rpc_client: &Arc<RpcClient>,
     bounty_address: Pubkey,
@@ -41,8 +174,8 @@ async fn process_get_bounty(
         .expect("bounty does not exist");
 
     println!(
-        "BOUNTY: \n\t maker: {} \n\t description: {} \n\t link: {} \n\t mint: {} \n\t reward: {} \n\t accepted submission: {}",
-        bounty.maker, bounty.description, bounty.link, bounty.mint, bounty.reward, bounty.accepted_submission
+        "BOUNTY: \n\t maker: {} \n\t description: {} \n\t link: {} \n\t mint: {} \n\t reward: {} \n\t accepted submissions: {:?} \n\t deadline: {:?} \n\t submissions: {} \n\t prize splits (bps): {:?} \n\t milestones: {} \n\t crowdfunded: {} \n\t contributors: {} \n\t refund pool: {:?} \n\t escrowed: {} \n\t arbiter: {:?} \n\t dispute: {:?} \n\t status: {:?} \n\t access: {} \n\t submission bond: {} \n\t reward legs: {} \n\t edits: {} \n\t updated slot: {} \n\t content hash: {:?}",
+        bounty.maker, bounty.description, bounty.link, bounty.mint, bounty.reward, bounty.accepted_submissions, bounty.deadline, bounty.submissions, bounty.prize_bps, format_milestones(&bounty.milestones), bounty.funded, bounty.contributors, bounty.refund_pool, bounty.escrowed, bounty.arbiter, bounty.dispute, bounty.status, format_access(&bounty.access), bounty.submission_bond, format_reward_legs(&bounty.reward_legs), bounty.edits, bounty.updated_slot, bounty.content_hash.map(Hash::new_from_array)
     );
 
     Ok(())
@@ -81,14 +214,31 @@ async fn process_get_all_bounties(rpc_client: &Arc<RpcClient>) -> Result<(), Box
             .expect("bounty does not exist");
 
         println!(
-            "BOUNTY {}: \n\t maker: {} \n\t description: {} \n\t link: {} \n\t mint: {} \n\t reward: {} \n\t accepted submission: {}",
+            "BOUNTY {}: \n\t maker: {} \n\t description: {} \n\t link: {} \n\t mint: {} \n\t reward: {} \n\t accepted submissions: {:?} \n\t deadline: {:?} \n\t submissions: {} \n\t prize splits (bps): {:?} \n\t milestones: {} \n\t crowdfunded: {} \n\t contributors: {} \n\t refund pool: {:?} \n\t escrowed: {} \n\t arbiter: {:?} \n\t dispute: {:?} \n\t status: {:?} \n\t access: {} \n\t submission bond: {} \n\t reward legs: {} \n\t edits: {} \n\t updated slot: {} \n\t content hash: {:?}",
             pk,
             bounty.maker,
             bounty.description,
             bounty.link,
             bounty.mint,
             bounty.reward,
-            bounty.accepted_submission
+            bounty.accepted_submissions,
+            bounty.deadline,
+            bounty.submissions,
+            bounty.prize_bps,
+            format_milestones(&bounty.milestones),
+            bounty.funded,
+            bounty.contributors,
+            bounty.refund_pool,
+            bounty.escrowed,
+            bounty.arbiter,
+            bounty.dispute,
+            bounty.status,
+            format_access(&bounty.access),
+            bounty.submission_bond,
+            format_reward_legs(&bounty.reward_legs),
+            bounty.edits,
+            bounty.updated_slot,
+            bounty.content_hash.map(Hash::new_from_array)
         );
     }
 
@@ -108,8 +258,93 @@ async fn process_get_submission(
         .expect("bounty does not exist");
 
     println!(
-        "SUBMISSION: \n\t hunter: {} \n\t notes: {} \n\t link: {} \n\t bounty: {}",
-        submission.hunter, submission.notes, submission.link, submission.bounty
+        "SUBMISSION: \n\t hunter: {} \n\t notes: {} \n\t link: {} \n\t bounty: {} \n\t status: {:?} \n\t edits: {} \n\t updated at: {} \n\t bond: {} \n\t content hash: {:?}",
+        submission.hunter, submission.notes, submission.link, submission.bounty, submission.status, submission.edits, submission.updated_at, submission.bond, submission.content_hash.map(Hash::new_from_array)
+    );
+
+    Ok(())
+}
+
+/// downloads the metadata document a bounty or submission points to and checks it against the
+/// content hash stored on chain
+async fn process_verify_metadata(
+    rpc_client: &Arc<RpcClient>,
+    address: Pubkey,
+) -> Result<(), Box<dyn Error>> {
+    let data = rpc_client.get_account_data(&address).await.unwrap();
+
+    let (uri, content_hash) =
+        match bounty_hunter::state::Bounty::try_deserialize(&mut data.as_ref()) {
+            Ok(bounty) => (bounty.description, bounty.content_hash),
+            Err(_) => {
+                let submission =
+                    bounty_hunter::state::Submission::try_deserialize(&mut data.as_ref())
+                        .expect("neither a bounty nor a submission");
+                (submission.notes, submission.content_hash)
+            }
+        };
+    let content_hash = content_hash.ok_or("no metadata document, the text is stored inline")?;
+
+    let document = reqwest::get(&uri)
+        .await?
+        .error_for_status()?
+        .bytes()
+        .await?;
+    let fetched = solana_sdk::hash::hash(&document);
+
+    if fetched.to_bytes() != content_hash {
+        return Err(format!(
+            "{} hashes to {}, expected {}",
+            uri,
+            fetched,
+            Hash::new_from_array(content_hash)
+        )
+        .into());
+    }
+    println!("{} matches {}", uri, fetched);
+
+    Ok(())
+}
+
+async fn process_get_hunter_profile(
+    rpc_client: &Arc<RpcClient>,
+    hunter: Pubkey,
+) -> Result<(), Box<dyn Error>> {
+    let data = rpc_client
+        .get_account_data(&hunter_profile_address(&hunter))
+        .await?;
+
+    let profile = bounty_hunter::state::HunterProfile::try_deserialize(&mut data.as_ref())
+        .expect("hunter profile does not exist");
+
+    let earnings: String = profile
+        .earnings
+        .iter()
+        .map(|earnings| format!("\n\t\t {} - {}", earnings.mint, earnings.amount))
+        .collect();
+
+    println!(
+        "HUNTER PROFILE: \n\t hunter: {} \n\t bounties won: {} \n\t earnings: {} \n\t last win at: {:?}",
+        profile.hunter, profile.bounties_won, earnings, profile.last_win_at
+    );
+
+    Ok(())
+}
+
+async fn process_get_maker_profile(
+    rpc_client: &Arc<RpcClient>,
+    maker: Pubkey,
+) -> Result<(), Box<dyn Error>> {
+    let data = rpc_client
+        .get_account_data(&maker_profile_address(&maker))
+        .await?;
+
+    let profile = bounty_hunter::state::MakerProfile::try_deserialize(&mut data.as_ref())
+        .expect("maker profile does not exist");
+
+    println!(
+        "MAKER PROFILE: \n\t maker: {} \n\t bounties created: {} \n\t payouts: {} \n\t bounties cancelled: {} \n\t expired with submissions: {}",
+        profile.maker, profile.bounties_created, profile.payouts, profile.bounties_cancelled, profile.expired_with_submissions
     );
 
     Ok(())
@@ -136,41 +371,197 @@ async fn process_accept_submission(
     let bounty = bounty_hunter::state::Bounty::try_deserialize(&mut data.as_ref())
         .expect("bounty does not exist");
 
-    let vault = spl_associated_token_account_interface::address::get_associated_token_address(
-        &submission.bounty,
-        &bounty.mint,
-    );
-
-    let hunter_ata = spl_associated_token_account_interface::address::get_associated_token_address(
-        &submission.hunter,
-        &bounty.mint,
-    );
+    let token_accounts = if bounty.is_native() {
+        None
+    } else {
+        Some(
+            fetch_token_accounts(
+                rpc_client,
+                &bounty.mint,
+                &submission.bounty,
+                &submission.hunter,
+            )
+            .await,
+        )
+    };
 
-    let mint_acc = rpc_client.get_account(&bounty.mint).await.unwrap();
+    let (config, config_address) = fetch_config(rpc_client).await;
+    // the treasury token account is only needed while a fee is charged
+    let treasury_token_account = token_accounts
+        .filter(|_| config.fee_bps > 0)
+        .map(|t| {
+            spl_associated_token_account_interface::address::get_associated_token_address_with_program_id(
+                &config.treasury,
+                &bounty.mint,
+                &t.token_program,
+            )
+        });
 
-    let accounts = bounty_hunter::accounts::AcceptSolution {
+    let mut accounts = bounty_hunter::accounts::AcceptSolution {
         maker: payer.pubkey(),
         bounty: submission.bounty,
         submission: submission_address,
-        vault,
+        receipt: receipt_address(&submission.bounty, bounty.payout_index()),
+        maker_profile: maker_profile_address(&payer.pubkey()),
+        hunter_profile: hunter_profile_address(&submission.hunter),
+        vault: token_accounts.map(|t| t.vault),
         hunter: submission.hunter,
-        mint: bounty.mint,
-        hunter_token_account: hunter_ata,
-        token_program: mint_acc.owner,
-        associated_token_program: spl_associated_token_account_interface::program::ID,
+        mint: token_accounts.map(|_| bounty.mint),
+        // created at the maker's expense if the hunter has never held the mint
+        hunter_token_account: token_accounts.map(|t| t.owner_ata),
+        token_program: token_accounts.map(|t| t.token_program),
+        associated_token_program: token_accounts
+            .map(|_| spl_associated_token_account_interface::program::ID),
+        config: config_address,
+        treasury: config.treasury,
+        treasury_token_account,
+        system_program: solana_system_interface::program::ID,
+        memo_program: token_accounts.map(|_| MEMO_PROGRAM_ID),
+        event_authority: event_authority_address(),
+        program: bounty_hunter::ID,
     }
     .to_account_metas(None);
 
+    let (leg_accounts, mut instructions) = fetch_reward_leg_accounts(
+        rpc_client,
+        &payer.pubkey(),
+        &bounty,
+        &submission.bounty,
+        &submission.hunter,
+        Some(&config.treasury),
+    )
+    .await;
+    accounts.extend(leg_accounts);
+
+    // only submissions in review can be accepted
+    if submission.status == bounty_hunter::state::SubmissionStatus::Pending {
+        instructions.push(review_instruction(
+            &payer.pubkey(),
+            &submission.bounty,
+            &submission_address,
+        ));
+    }
+
     let data = bounty_hunter::instruction::AcceptSolution {}.data();
 
-    let ix = Instruction {
+    instructions.push(Instruction {
         accounts,
         data,
         program_id: bounty_hunter::ID,
+    });
+
+    let mut transaction =
+        Transaction::new_unsigned(Message::new(&instructions, Some(&payer.pubkey())));
+
+    let blockhash = rpc_client
+        .get_latest_blockhash()
+        .await
+        .map_err(|err| format!("error: unable to get latest blockhash: {}", err))?;
+
+    transaction
+        .try_sign(&[payer], blockhash)
+        .map_err(|err| format!("error: failed to sign transaction: {}", err))?;
+
+    let signature = rpc_client
+        .send_and_confirm_transaction_with_spinner(&transaction)
+        .await
+        .map_err(|err| format!("error: send transaction: {}", err))?;
+
+    Ok(signature)
+}
+
+async fn process_approve_milestone(
+    rpc_client: &Arc<RpcClient>,
+    payer: &Arc<dyn Signer>,
+    submission_address: Pubkey,
+    index: u8,
+) -> Result<Signature, Box<dyn Error>> {
+    let data = rpc_client
+        .get_account_data(&submission_address)
+        .await
+        .unwrap();
+
+    let submission = bounty_hunter::state::Submission::try_deserialize(&mut data.as_ref())
+        .expect("bounty does not exist");
+
+    let data = rpc_client
+        .get_account_data(&submission.bounty)
+        .await
+        .unwrap();
+
+    let bounty = bounty_hunter::state::Bounty::try_deserialize(&mut data.as_ref())
+        .expect("bounty does not exist");
+
+    let token_accounts = if bounty.is_native() {
+        None
+    } else {
+        Some(
+            fetch_token_accounts(
+                rpc_client,
+                &bounty.mint,
+                &submission.bounty,
+                &submission.hunter,
+            )
+            .await,
+        )
     };
 
+    let (config, config_address) = fetch_config(rpc_client).await;
+    // the treasury token account is only needed while a fee is charged
+    let treasury_token_account = token_accounts
+        .filter(|_| config.fee_bps > 0)
+        .map(|t| {
+            spl_associated_token_account_interface::address::get_associated_token_address_with_program_id(
+                &config.treasury,
+                &bounty.mint,
+                &t.token_program,
+            )
+        });
+
+    let accounts = bounty_hunter::accounts::ApproveMilestone {
+        maker: payer.pubkey(),
+        bounty: submission.bounty,
+        submission: submission_address,
+        receipt: receipt_address(&submission.bounty, index),
+        maker_profile: maker_profile_address(&payer.pubkey()),
+        hunter_profile: hunter_profile_address(&submission.hunter),
+        vault: token_accounts.map(|t| t.vault),
+        hunter: submission.hunter,
+        mint: token_accounts.map(|_| bounty.mint),
+        // created at the maker's expense if the hunter has never held the mint
+        hunter_token_account: token_accounts.map(|t| t.owner_ata),
+        token_program: token_accounts.map(|t| t.token_program),
+        associated_token_program: token_accounts
+            .map(|_| spl_associated_token_account_interface::program::ID),
+        config: config_address,
+        treasury: config.treasury,
+        treasury_token_account,
+        system_program: solana_system_interface::program::ID,
+        memo_program: token_accounts.map(|_| MEMO_PROGRAM_ID),
+        event_authority: event_authority_address(),
+        program: bounty_hunter::ID,
+    }
+    .to_account_metas(None);
+
+    let data = bounty_hunter::instruction::ApproveMilestone { index }.data();
+
+    // only submissions in review can be approved, later milestones reuse the accepted one
+    let mut instructions = Vec::new();
+    if submission.status == bounty_hunter::state::SubmissionStatus::Pending {
+        instructions.push(review_instruction(
+            &payer.pubkey(),
+            &submission.bounty,
+            &submission_address,
+        ));
+    }
+    instructions.push(Instruction {
+        accounts,
+        data,
+        program_id: bounty_hunter::ID,
+    });
+
     let mut transaction =
-        Transaction::new_unsigned(Message::new([ix].as_slice(), Some(&payer.pubkey())));
+        Transaction::new_unsigned(Message::new(&instructions, Some(&payer.pubkey())));
 
     let blockhash = rpc_client
         .get_latest_blockhash()
@@ -231,22 +622,55 @@ async fn process_get_all_submissions(
                 .expect("submission does not exist");
 
         println!(
-            "SUBMISSION {}: \n\t hunter: {} \n\t notes: {} \n\t link: {} \n\t bounty: {}",
-            pk, submission.hunter, submission.notes, submission.link, submission.bounty
+            "SUBMISSION {}: \n\t hunter: {} \n\t notes: {} \n\t link: {} \n\t bounty: {} \n\t status: {:?} \n\t edits: {} \n\t updated at: {}",
+            pk,
+            submission.hunter,
+            submission.notes,
+            submission.link,
+            submission.bounty,
+            submission.status,
+            submission.edits,
+            submission.updated_at
         );
     }
 
     Ok(())
 }
 
+/// runs one of the program's input checks before anything is sent, with its message as the error
+fn check_input(check: anchor_lang::Result<()>) -> Result<(), Box<dyn Error>> {
+    check.map_err(|err| match err {
+        anchor_lang::error::Error::AnchorError(err) => format!("error: {}", err.error_msg).into(),
+        err => format!("error: {}", err).into(),
+    })
+}
+
+#[allow(clippy::too_many_arguments)]
 async fn process_create_bounty(
     rpc_client: &Arc<RpcClient>,
     payer: &Arc<dyn Signer>,
     description: String,
     link: String,
     reward: u64,
-    mint: Pubkey,
+    mint: Option<Pubkey>,
+    deadline: Option<i64>,
+    prize_bps: Vec<u16>,
+    milestones: Vec<bounty_hunter::MilestoneArgs>,
+    arbiter: Option<Pubkey>,
+    access: BountyAccess,
+    submission_bond: u64,
+    content_hash: Option<[u8; 32]>,
 ) -> Result<Signature, Box<dyn Error>> {
+    check_input(bounty_hunter::validation::check_description(&description))?;
+    check_input(bounty_hunter::validation::check_link(&link))?;
+    check_input(bounty_hunter::validation::check_reward(reward))?;
+    check_input(bounty_hunter::validation::check_min_reward(
+        fetch_min_reward(rpc_client, &mint.unwrap_or_default())
+            .await
+            .as_ref(),
+        reward,
+    ))?;
+
     let seed: u64 = rand::random();
     let bounty = Pubkey::find_program_address(
         &[
@@ -257,25 +681,28 @@ async fn process_create_bounty(
         &bounty_hunter::ID,
     );
 
-    let mint_acc = rpc_client.get_account(&mint).await.unwrap();
-
-    let maker_ata = spl_associated_token_account_interface::address::get_associated_token_address(
-        &payer.pubkey(),
-        &mint,
-    );
-    let vault = spl_associated_token_account_interface::address::get_associated_token_address(
-        &bounty.0, &mint,
-    );
+    // without a mint the reward is paid in native SOL and no token accounts are involved
+    let token_accounts = match mint {
+        Some(mint) => {
+            Some(fetch_token_accounts(rpc_client, &mint, &bounty.0, &payer.pubkey()).await)
+        }
+        None => None,
+    };
 
     let accounts = bounty_hunter::accounts::CreateBounty {
         bounty: bounty.0,
         maker: payer.pubkey(),
+        maker_profile: maker_profile_address(&payer.pubkey()),
         system_program: solana_system_interface::program::ID,
         mint,
-        maker_token_account: maker_ata,
-        vault,
-        token_program: mint_acc.owner,
-        associated_token_program: spl_associated_token_account_interface::program::ID,
+        maker_token_account: token_accounts.map(|t| t.owner_ata),
+        vault: token_accounts.map(|t| t.vault),
+        min_reward: min_reward_address(&mint.unwrap_or_default()),
+        token_program: token_accounts.map(|t| t.token_program),
+        associated_token_program: token_accounts
+            .map(|_| spl_associated_token_account_interface::program::ID),
+        event_authority: event_authority_address(),
+        program: bounty_hunter::ID,
     }
     .to_account_metas(None);
 
@@ -284,6 +711,13 @@ async fn process_create_bounty(
         link,
         reward,
         seed,
+        deadline,
+        prize_bps,
+        milestones,
+        arbiter,
+        access,
+        submission_bond,
+        content_hash,
     }
     .data();
 
@@ -321,7 +755,12 @@ async fn process_submit_solution(
     bounty_address: Pubkey,
     notes: String,
     link: String,
+    proof: Vec<[u8; 32]>,
+    content_hash: Option<[u8; 32]>,
 ) -> Result<Signature, Box<dyn Error>> {
+    check_input(bounty_hunter::validation::check_link(&link))?;
+    check_input(bounty_hunter::validation::check_notes(&notes))?;
+
     let submission = Pubkey::find_program_address(
         &[
             b"submission",
@@ -337,10 +776,18 @@ async fn process_submit_solution(
         hunter: payer.pubkey(),
         submission,
         system_program: solana_system_interface::program::ID,
+        event_authority: event_authority_address(),
+        program: bounty_hunter::ID,
     }
     .to_account_metas(None);
 
-    let data = bounty_hunter::instruction::SubmitSolution { notes, link }.data();
+    let data = bounty_hunter::instruction::SubmitSolution {
+        notes,
+        link,
+        proof,
+        content_hash,
+    }
+    .data();
 
     let ix = Instruction {
         accounts,
@@ -370,40 +817,95 @@ async fn process_submit_solution(
     Ok(signature)
 }
 
-async fn process_cancel_bounty(
+async fn process_edit_submission(
     rpc_client: &Arc<RpcClient>,
     payer: &Arc<dyn Signer>,
     bounty_address: Pubkey,
+    notes: String,
+    link: String,
+    content_hash: Option<[u8; 32]>,
 ) -> Result<Signature, Box<dyn Error>> {
-    let data = rpc_client.get_account_data(&bounty_address).await.unwrap();
+    check_input(bounty_hunter::validation::check_link(&link))?;
+    check_input(bounty_hunter::validation::check_notes(&notes))?;
 
-    let bounty = bounty_hunter::state::Bounty::try_deserialize(&mut data.as_ref())
-        .expect("bounty does not exist");
+    let submission = Pubkey::find_program_address(
+        &[
+            b"submission",
+            payer.pubkey().as_ref(),
+            bounty_address.as_ref(),
+        ],
+        &bounty_hunter::ID,
+    )
+    .0;
 
-    let vault = spl_associated_token_account_interface::address::get_associated_token_address(
-        &bounty_address,
-        &bounty.mint,
-    );
+    let accounts = bounty_hunter::accounts::EditSubmission {
+        hunter: payer.pubkey(),
+        bounty: bounty_address,
+        submission,
+        system_program: solana_system_interface::program::ID,
+        event_authority: event_authority_address(),
+        program: bounty_hunter::ID,
+    }
+    .to_account_metas(None);
 
-    let maker_ata = spl_associated_token_account_interface::address::get_associated_token_address(
-        &payer.pubkey(),
-        &bounty.mint,
-    );
+    let data = bounty_hunter::instruction::EditSubmission {
+        link,
+        notes,
+        content_hash,
+    }
+    .data();
 
-    let mint_acc = rpc_client.get_account(&bounty.mint).await.unwrap();
+    let ix = Instruction {
+        accounts,
+        data,
+        program_id: bounty_hunter::ID,
+    };
+
+    let mut transaction =
+        Transaction::new_unsigned(Message::new([ix].as_slice(), Some(&payer.pubkey())));
+
+    let blockhash = rpc_client
+        .get_latest_blockhash()
+        .await
+        .map_err(|err| format!("error: unable to get latest blockhash: {}", err))?;
+
+    transaction
+        .try_sign(&[payer], blockhash)
+        .map_err(|err| format!("error: failed to sign transaction: {}", err))?;
+
+    let signature = rpc_client
+        .send_and_confirm_transaction_with_spinner(&transaction)
+        .await
+        .map_err(|err| format!("error: send transaction: {}", err))?;
+
+    Ok(signature)
+}
+
+async fn process_withdraw_submission(
+    rpc_client: &Arc<RpcClient>,
+    payer: &Arc<dyn Signer>,
+    bounty_address: Pubkey,
+) -> Result<Signature, Box<dyn Error>> {
+    let submission = Pubkey::find_program_address(
+        &[
+            b"submission",
+            payer.pubkey().as_ref(),
+            bounty_address.as_ref(),
+        ],
+        &bounty_hunter::ID,
+    )
+    .0;
 
-    let accounts = bounty_hunter::accounts::CancelBounty {
+    let accounts = bounty_hunter::accounts::WithdrawSubmission {
+        hunter: payer.pubkey(),
         bounty: bounty_address,
-        maker: payer.pubkey(),
-        vault,
-        mint: bounty.mint,
-        maker_token_account: maker_ata,
-        token_program: mint_acc.owner,
-        associated_token_program: spl_associated_token_account_interface::program::ID,
+        submission,
+        event_authority: event_authority_address(),
+        program: bounty_hunter::ID,
     }
     .to_account_metas(None);
 
-    let data = bounty_hunter::instruction::CancelBounty {}.data();
+    let data = bounty_hunter::instruction::WithdrawSubmission {}.data();
 
     let ix = Instruction {
         accounts,
@@ -431,25 +933,1259 @@ async fn process_cancel_bounty(
     Ok(signature)
 }
 
-#[tokio::main]
-async fn main() -> Result<(), Box<dyn Error>> {
-    let app_matches = Command::new(crate_name!())
-        .about(crate_description!())
-        .version(crate_version!())
-        .subcommand_required(true)
-        .arg_required_else_help(true)
-        .arg({
-            let arg = Arg::new("config_file")
-                .short('C')
-                .long("config")
-                .value_name("PATH")
-                .takes_value(true)
-                .global(true)
-                .help("Configuration file to use");
-            if let Some(ref config_file) = *solana_cli_config::CONFIG_FILE {
-                arg.default_value(config_file)
-            } else {
-                arg
+/// locks a submission for review, it can't be edited anymore and can only be accepted now
+fn review_instruction(maker: &Pubkey, bounty: &Pubkey, submission: &Pubkey) -> Instruction {
+    let accounts = bounty_hunter::accounts::ReviewSubmission {
+        maker: *maker,
+        bounty: *bounty,
+        submission: *submission,
+        event_authority: event_authority_address(),
+        program: bounty_hunter::ID,
+    }
+    .to_account_metas(None);
+
+    let data = bounty_hunter::instruction::ReviewSubmission {}.data();
+
+    Instruction {
+        accounts,
+        data,
+        program_id: bounty_hunter::ID,
+    }
+}
+
+async fn process_review_submission(
+    rpc_client: &Arc<RpcClient>,
+    payer: &Arc<dyn Signer>,
+    submission_address: Pubkey,
+) -> Result<Signature, Box<dyn Error>> {
+    let data = rpc_client
+        .get_account_data(&submission_address)
+        .await
+        .unwrap();
+
+    let submission = bounty_hunter::state::Submission::try_deserialize(&mut data.as_ref())
+        .expect("submission does not exist");
+
+    let ix = review_instruction(&payer.pubkey(), &submission.bounty, &submission_address);
+
+    let mut transaction =
+        Transaction::new_unsigned(Message::new([ix].as_slice(), Some(&payer.pubkey())));
+
+    let blockhash = rpc_client
+        .get_latest_blockhash()
+        .await
+        .map_err(|err| format!("error: unable to get latest blockhash: {}", err))?;
+
+    transaction
+        .try_sign(&[payer], blockhash)
+        .map_err(|err| format!("error: failed to sign transaction: {}", err))?;
+
+    let signature = rpc_client
+        .send_and_confirm_transaction_with_spinner(&transaction)
+        .await
+        .map_err(|err| format!("error: send transaction: {}", err))?;
+
+    Ok(signature)
+}
+
+async fn process_slash_submission(
+    rpc_client: &Arc<RpcClient>,
+    payer: &Arc<dyn Signer>,
+    submission_address: Pubkey,
+) -> Result<Signature, Box<dyn Error>> {
+    let data = rpc_client
+        .get_account_data(&submission_address)
+        .await
+        .unwrap();
+
+    let submission = bounty_hunter::state::Submission::try_deserialize(&mut data.as_ref())
+        .expect("submission does not exist");
+
+    let (config, config_address) = fetch_config(rpc_client).await;
+
+    let accounts = bounty_hunter::accounts::SlashSubmission {
+        maker: payer.pubkey(),
+        bounty: submission.bounty,
+        submission: submission_address,
+        hunter: submission.hunter,
+        config: config_address,
+        treasury: config.treasury,
+        event_authority: event_authority_address(),
+        program: bounty_hunter::ID,
+    }
+    .to_account_metas(None);
+
+    let data = bounty_hunter::instruction::SlashSubmission {}.data();
+
+    let ix = Instruction {
+        accounts,
+        data,
+        program_id: bounty_hunter::ID,
+    };
+
+    let mut transaction =
+        Transaction::new_unsigned(Message::new([ix].as_slice(), Some(&payer.pubkey())));
+
+    let blockhash = rpc_client
+        .get_latest_blockhash()
+        .await
+        .map_err(|err| format!("error: unable to get latest blockhash: {}", err))?;
+
+    transaction
+        .try_sign(&[payer], blockhash)
+        .map_err(|err| format!("error: failed to sign transaction: {}", err))?;
+
+    let signature = rpc_client
+        .send_and_confirm_transaction_with_spinner(&transaction)
+        .await
+        .map_err(|err| format!("error: send transaction: {}", err))?;
+
+    Ok(signature)
+}
+
+async fn process_close_submission(
+    rpc_client: &Arc<RpcClient>,
+    payer: &Arc<dyn Signer>,
+    submission_address: Pubkey,
+) -> Result<Signature, Box<dyn Error>> {
+    let data = rpc_client
+        .get_account_data(&submission_address)
+        .await
+        .unwrap();
+
+    let submission = bounty_hunter::state::Submission::try_deserialize(&mut data.as_ref())
+        .expect("submission does not exist");
+
+    let accounts = bounty_hunter::accounts::CloseSubmission {
+        payer: payer.pubkey(),
+        hunter: submission.hunter,
+        bounty: submission.bounty,
+        submission: submission_address,
+        event_authority: event_authority_address(),
+        program: bounty_hunter::ID,
+    }
+    .to_account_metas(None);
+
+    let data = bounty_hunter::instruction::CloseSubmission {}.data();
+
+    let ix = Instruction {
+        accounts,
+        data,
+        program_id: bounty_hunter::ID,
+    };
+
+    let mut transaction =
+        Transaction::new_unsigned(Message::new([ix].as_slice(), Some(&payer.pubkey())));
+
+    let blockhash = rpc_client
+        .get_latest_blockhash()
+        .await
+        .map_err(|err| format!("error: unable to get latest blockhash: {}", err))?;
+
+    transaction
+        .try_sign(&[payer], blockhash)
+        .map_err(|err| format!("error: failed to sign transaction: {}", err))?;
+
+    let signature = rpc_client
+        .send_and_confirm_transaction_with_spinner(&transaction)
+        .await
+        .map_err(|err| format!("error: send transaction: {}", err))?;
+
+    Ok(signature)
+}
+
+async fn process_update_bounty(
+    rpc_client: &Arc<RpcClient>,
+    payer: &Arc<dyn Signer>,
+    bounty_address: Pubkey,
+    description: String,
+    link: String,
+    content_hash: Option<[u8; 32]>,
+) -> Result<Signature, Box<dyn Error>> {
+    check_input(bounty_hunter::validation::check_description(&description))?;
+    check_input(bounty_hunter::validation::check_link(&link))?;
+
+    let accounts = bounty_hunter::accounts::UpdateBounty {
+        maker: payer.pubkey(),
+        bounty: bounty_address,
+        system_program: solana_system_interface::program::ID,
+        event_authority: event_authority_address(),
+        program: bounty_hunter::ID,
+    }
+    .to_account_metas(None);
+
+    let data = bounty_hunter::instruction::UpdateBounty {
+        description,
+        link,
+        content_hash,
+    }
+    .data();
+
+    let ix = Instruction {
+        accounts,
+        data,
+        program_id: bounty_hunter::ID,
+    };
+
+    let mut transaction =
+        Transaction::new_unsigned(Message::new([ix].as_slice(), Some(&payer.pubkey())));
+
+    let blockhash = rpc_client
+        .get_latest_blockhash()
+        .await
+        .map_err(|err| format!("error: unable to get latest blockhash: {}", err))?;
+
+    transaction
+        .try_sign(&[payer], blockhash)
+        .map_err(|err| format!("error: failed to sign transaction: {}", err))?;
+
+    let signature = rpc_client
+        .send_and_confirm_transaction_with_spinner(&transaction)
+        .await
+        .map_err(|err| format!("error: send transaction: {}", err))?;
+
+    Ok(signature)
+}
+
+async fn process_cancel_bounty(
+    rpc_client: &Arc<RpcClient>,
+    payer: &Arc<dyn Signer>,
+    bounty_address: Pubkey,
+) -> Result<Signature, Box<dyn Error>> {
+    let data = rpc_client.get_account_data(&bounty_address).await.unwrap();
+
+    let bounty = bounty_hunter::state::Bounty::try_deserialize(&mut data.as_ref())
+        .expect("bounty does not exist");
+
+    let token_accounts = if bounty.is_native() {
+        None
+    } else {
+        Some(fetch_token_accounts(rpc_client, &bounty.mint, &bounty_address, &payer.pubkey()).await)
+    };
+
+    let mut accounts = bounty_hunter::accounts::CancelBounty {
+        bounty: bounty_address,
+        maker: payer.pubkey(),
+        maker_profile: maker_profile_address(&payer.pubkey()),
+        vault: token_accounts.map(|t| t.vault),
+        mint: token_accounts.map(|_| bounty.mint),
+        maker_token_account: token_accounts.map(|t| t.owner_ata),
+        token_program: token_accounts.map(|t| t.token_program),
+        associated_token_program: token_accounts
+            .map(|_| spl_associated_token_account_interface::program::ID),
+        memo_program: token_accounts.map(|_| MEMO_PROGRAM_ID),
+        event_authority: event_authority_address(),
+        program: bounty_hunter::ID,
+    }
+    .to_account_metas(None);
+
+    let (leg_accounts, mut instructions) = fetch_reward_leg_accounts(
+        rpc_client,
+        &payer.pubkey(),
+        &bounty,
+        &bounty_address,
+        &payer.pubkey(),
+        None,
+    )
+    .await;
+    accounts.extend(leg_accounts);
+
+    let data = bounty_hunter::instruction::CancelBounty {}.data();
+
+    instructions.push(Instruction {
+        accounts,
+        data,
+        program_id: bounty_hunter::ID,
+    });
+
+    let mut transaction =
+        Transaction::new_unsigned(Message::new(&instructions, Some(&payer.pubkey())));
+
+    let blockhash = rpc_client
+        .get_latest_blockhash()
+        .await
+        .map_err(|err| format!("error: unable to get latest blockhash: {}", err))?;
+
+    transaction
+        .try_sign(&[payer], blockhash)
+        .map_err(|err| format!("error: failed to sign transaction: {}", err))?;
+
+    let signature = rpc_client
+        .send_and_confirm_transaction_with_spinner(&transaction)
+        .await
+        .map_err(|err| format!("error: send transaction: {}", err))?;
+
+    Ok(signature)
+}
+
+async fn process_sweep_excess(
+    rpc_client: &Arc<RpcClient>,
+    payer: &Arc<dyn Signer>,
+    bounty_address: Pubkey,
+) -> Result<Signature, Box<dyn Error>> {
+    let data = rpc_client.get_account_data(&bounty_address).await.unwrap();
+
+    let bounty = bounty_hunter::state::Bounty::try_deserialize(&mut data.as_ref())
+        .expect("bounty does not exist");
+
+    let token_accounts = if bounty.is_native() {
+        None
+    } else {
+        Some(fetch_token_accounts(rpc_client, &bounty.mint, &bounty_address, &payer.pubkey()).await)
+    };
+
+    // the vault is gone once its last payout closed it, only reward legs can be swept then
+    let vault_open = match token_accounts {
+        Some(t) => rpc_client.get_account(&t.vault).await.is_ok(),
+        None => false,
+    };
+    let open_accounts = token_accounts.filter(|_| vault_open);
+
+    let mut accounts = bounty_hunter::accounts::SweepExcess {
+        bounty: bounty_address,
+        maker: payer.pubkey(),
+        vault: open_accounts.map(|t| t.vault),
+        mint: token_accounts.map(|_| bounty.mint),
+        maker_token_account: open_accounts.map(|t| t.owner_ata),
+        token_program: token_accounts.map(|t| t.token_program),
+        associated_token_program: token_accounts
+            .map(|_| spl_associated_token_account_interface::program::ID),
+        memo_program: token_accounts.map(|_| MEMO_PROGRAM_ID),
+        event_authority: event_authority_address(),
+        program: bounty_hunter::ID,
+    }
+    .to_account_metas(None);
+
+    let (leg_accounts, mut instructions) = fetch_reward_leg_accounts(
+        rpc_client,
+        &payer.pubkey(),
+        &bounty,
+        &bounty_address,
+        &payer.pubkey(),
+        None,
+    )
+    .await;
+    accounts.extend(leg_accounts);
+
+    let data = bounty_hunter::instruction::SweepExcess {}.data();
+
+    instructions.push(Instruction {
+        accounts,
+        data,
+        program_id: bounty_hunter::ID,
+    });
+
+    let mut transaction =
+        Transaction::new_unsigned(Message::new(&instructions, Some(&payer.pubkey())));
+
+    let blockhash = rpc_client
+        .get_latest_blockhash()
+        .await
+        .map_err(|err| format!("error: unable to get latest blockhash: {}", err))?;
+
+    transaction
+        .try_sign(&[payer], blockhash)
+        .map_err(|err| format!("error: failed to sign transaction: {}", err))?;
+
+    let signature = rpc_client
+        .send_and_confirm_transaction_with_spinner(&transaction)
+        .await
+        .map_err(|err| format!("error: send transaction: {}", err))?;
+
+    Ok(signature)
+}
+
+async fn process_expire_bounty(
+    rpc_client: &Arc<RpcClient>,
+    payer: &Arc<dyn Signer>,
+    bounty_address: Pubkey,
+) -> Result<Signature, Box<dyn Error>> {
+    let data = rpc_client.get_account_data(&bounty_address).await.unwrap();
+
+    let bounty = bounty_hunter::state::Bounty::try_deserialize(&mut data.as_ref())
+        .expect("bounty does not exist");
+
+    let token_accounts = if bounty.is_native() {
+        None
+    } else {
+        Some(fetch_token_accounts(rpc_client, &bounty.mint, &bounty_address, &bounty.maker).await)
+    };
+
+    let mut accounts = bounty_hunter::accounts::ExpireBounty {
+        payer: payer.pubkey(),
+        maker: bounty.maker,
+        bounty: bounty_address,
+        maker_profile: maker_profile_address(&bounty.maker),
+        vault: token_accounts.map(|t| t.vault),
+        mint: token_accounts.map(|_| bounty.mint),
+        maker_token_account: token_accounts.map(|t| t.owner_ata),
+        token_program: token_accounts.map(|t| t.token_program),
+        associated_token_program: token_accounts
+            .map(|_| spl_associated_token_account_interface::program::ID),
+        memo_program: token_accounts.map(|_| MEMO_PROGRAM_ID),
+        event_authority: event_authority_address(),
+        program: bounty_hunter::ID,
+    }
+    .to_account_metas(None);
+
+    let (leg_accounts, mut instructions) = fetch_reward_leg_accounts(
+        rpc_client,
+        &payer.pubkey(),
+        &bounty,
+        &bounty_address,
+        &bounty.maker,
+        None,
+    )
+    .await;
+    accounts.extend(leg_accounts);
+
+    let data = bounty_hunter::instruction::ExpireBounty {}.data();
+
+    instructions.push(Instruction {
+        accounts,
+        data,
+        program_id: bounty_hunter::ID,
+    });
+
+    let mut transaction =
+        Transaction::new_unsigned(Message::new(&instructions, Some(&payer.pubkey())));
+
+    let blockhash = rpc_client
+        .get_latest_blockhash()
+        .await
+        .map_err(|err| format!("error: unable to get latest blockhash: {}", err))?;
+
+    transaction
+        .try_sign(&[payer], blockhash)
+        .map_err(|err| format!("error: failed to sign transaction: {}", err))?;
+
+    let signature = rpc_client
+        .send_and_confirm_transaction_with_spinner(&transaction)
+        .await
+        .map_err(|err| format!("error: send transaction: {}", err))?;
+
+    Ok(signature)
+}
+
+async fn process_close_bounty(
+    rpc_client: &Arc<RpcClient>,
+    payer: &Arc<dyn Signer>,
+    bounty_address: Pubkey,
+) -> Result<Signature, Box<dyn Error>> {
+    let data = rpc_client.get_account_data(&bounty_address).await.unwrap();
+
+    let bounty = bounty_hunter::state::Bounty::try_deserialize(&mut data.as_ref())
+        .expect("bounty does not exist");
+
+    let token_accounts = if bounty.is_native() {
+        None
+    } else {
+        Some(fetch_token_accounts(rpc_client, &bounty.mint, &bounty_address, &payer.pubkey()).await)
+    };
+    // a vault still holding excess is swept and closed along with the bounty
+    let vault_open = match token_accounts {
+        Some(t) => rpc_client.get_account(&t.vault).await.is_ok(),
+        None => false,
+    };
+    let open_accounts = token_accounts.filter(|_| vault_open);
+
+    let mut accounts = bounty_hunter::accounts::CloseBounty {
+        maker: payer.pubkey(),
+        bounty: bounty_address,
+        vault: token_accounts.map(|t| t.vault),
+        mint: token_accounts.map(|_| bounty.mint),
+        maker_token_account: open_accounts.map(|t| t.owner_ata),
+        token_program: token_accounts.map(|t| t.token_program),
+        associated_token_program: token_accounts
+            .map(|_| spl_associated_token_account_interface::program::ID),
+        memo_program: token_accounts.map(|_| MEMO_PROGRAM_ID),
+        event_authority: event_authority_address(),
+        program: bounty_hunter::ID,
+    }
+    .to_account_metas(None);
+
+    // reward leg vaults still open because of excess are swept and closed too
+    let (leg_accounts, mut instructions) = fetch_reward_leg_accounts(
+        rpc_client,
+        &payer.pubkey(),
+        &bounty,
+        &bounty_address,
+        &payer.pubkey(),
+        None,
+    )
+    .await;
+    accounts.extend(leg_accounts);
+
+    let data = bounty_hunter::instruction::CloseBounty {}.data();
+
+    instructions.push(Instruction {
+        accounts,
+        data,
+        program_id: bounty_hunter::ID,
+    });
+
+    let mut transaction =
+        Transaction::new_unsigned(Message::new(&instructions, Some(&payer.pubkey())));
+
+    let blockhash = rpc_client
+        .get_latest_blockhash()
+        .await
+        .map_err(|err| format!("error: unable to get latest blockhash: {}", err))?;
+
+    transaction
+        .try_sign(&[payer], blockhash)
+        .map_err(|err| format!("error: failed to sign transaction: {}", err))?;
+
+    let signature = rpc_client
+        .send_and_confirm_transaction_with_spinner(&transaction)
+        .await
+        .map_err(|err| format!("error: send transaction: {}", err))?;
+
+    Ok(signature)
+}
+
+async fn process_open_dispute(
+    rpc_client: &Arc<RpcClient>,
+    payer: &Arc<dyn Signer>,
+    submission_address: Pubkey,
+) -> Result<Signature, Box<dyn Error>> {
+    let data = rpc_client
+        .get_account_data(&submission_address)
+        .await
+        .unwrap();
+
+    let submission = bounty_hunter::state::Submission::try_deserialize(&mut data.as_ref())
+        .expect("submission does not exist");
+
+    let accounts = bounty_hunter::accounts::OpenDispute {
+        hunter: payer.pubkey(),
+        bounty: submission.bounty,
+        submission: submission_address,
+        event_authority: event_authority_address(),
+        program: bounty_hunter::ID,
+    }
+    .to_account_metas(None);
+
+    let data = bounty_hunter::instruction::OpenDispute {}.data();
+
+    let ix = Instruction {
+        accounts,
+        data,
+        program_id: bounty_hunter::ID,
+    };
+
+    let mut transaction =
+        Transaction::new_unsigned(Message::new([ix].as_slice(), Some(&payer.pubkey())));
+
+    let blockhash = rpc_client
+        .get_latest_blockhash()
+        .await
+        .map_err(|err| format!("error: unable to get latest blockhash: {}", err))?;
+
+    transaction
+        .try_sign(&[payer], blockhash)
+        .map_err(|err| format!("error: failed to sign transaction: {}", err))?;
+
+    let signature = rpc_client
+        .send_and_confirm_transaction_with_spinner(&transaction)
+        .await
+        .map_err(|err| format!("error: send transaction: {}", err))?;
+
+    Ok(signature)
+}
+
+async fn process_resolve_dispute(
+    rpc_client: &Arc<RpcClient>,
+    payer: &Arc<dyn Signer>,
+    submission_address: Pubkey,
+    award_hunter: bool,
+) -> Result<Signature, Box<dyn Error>> {
+    let data = rpc_client
+        .get_account_data(&submission_address)
+        .await
+        .unwrap();
+
+    let submission = bounty_hunter::state::Submission::try_deserialize(&mut data.as_ref())
+        .expect("submission does not exist");
+
+    let data = rpc_client
+        .get_account_data(&submission.bounty)
+        .await
+        .unwrap();
+
+    let bounty = bounty_hunter::state::Bounty::try_deserialize(&mut data.as_ref())
+        .expect("bounty does not exist");
+
+    // siding with the maker moves no funds, so no token accounts are needed
+    let hunter_accounts = if bounty.is_native() || !award_hunter {
+        None
+    } else {
+        Some(
+            fetch_token_accounts(
+                rpc_client,
+                &bounty.mint,
+                &submission.bounty,
+                &submission.hunter,
+            )
+            .await,
+        )
+    };
+
+    let (config, config_address) = fetch_config(rpc_client).await;
+    // the treasury token account is only needed while a fee is charged
+    let treasury_token_account = hunter_accounts
+        .filter(|_| config.fee_bps > 0)
+        .map(|t| {
+            spl_associated_token_account_interface::address::get_associated_token_address_with_program_id(
+                &config.treasury,
+                &bounty.mint,
+                &t.token_program,
+            )
+        });
+
+    let mut accounts = bounty_hunter::accounts::ResolveDispute {
+        arbiter: payer.pubkey(),
+        maker: bounty.maker,
+        bounty: submission.bounty,
+        submission: submission_address,
+        receipt: award_hunter.then(|| receipt_address(&submission.bounty, bounty.payout_index())),
+        maker_profile: maker_profile_address(&bounty.maker),
+        hunter_profile: award_hunter.then(|| hunter_profile_address(&submission.hunter)),
+        hunter: submission.hunter,
+        vault: hunter_accounts.map(|t| t.vault),
+        mint: hunter_accounts.map(|_| bounty.mint),
+        // created at the arbiter's expense if the hunter has never held the mint
+        hunter_token_account: hunter_accounts.map(|t| t.owner_ata),
+        config: config_address,
+        treasury: config.treasury,
+        treasury_token_account,
+        system_program: solana_system_interface::program::ID,
+        token_program: hunter_accounts.map(|t| t.token_program),
+        associated_token_program: hunter_accounts
+            .map(|_| spl_associated_token_account_interface::program::ID),
+        memo_program: hunter_accounts.map(|_| MEMO_PROGRAM_ID),
+        event_authority: event_authority_address(),
+        program: bounty_hunter::ID,
+    }
+    .to_account_metas(None);
+
+    // the legs follow the main reward to the hunter
+    let mut instructions = vec![];
+    if award_hunter {
+        let (leg_accounts, leg_instructions) = fetch_reward_leg_accounts(
+            rpc_client,
+            &payer.pubkey(),
+            &bounty,
+            &submission.bounty,
+            &submission.hunter,
+            Some(&config.treasury),
+        )
+        .await;
+        accounts.extend(leg_accounts);
+        instructions = leg_instructions;
+    }
+
+    let data = bounty_hunter::instruction::ResolveDispute { award_hunter }.data();
+
+    instructions.push(Instruction {
+        accounts,
+        data,
+        program_id: bounty_hunter::ID,
+    });
+
+    let mut transaction =
+        Transaction::new_unsigned(Message::new(&instructions, Some(&payer.pubkey())));
+
+    let blockhash = rpc_client
+        .get_latest_blockhash()
+        .await
+        .map_err(|err| format!("error: unable to get latest blockhash: {}", err))?;
+
+    transaction
+        .try_sign(&[payer], blockhash)
+        .map_err(|err| format!("error: failed to sign transaction: {}", err))?;
+
+    let signature = rpc_client
+        .send_and_confirm_transaction_with_spinner(&transaction)
+        .await
+        .map_err(|err| format!("error: send transaction: {}", err))?;
+
+    Ok(signature)
+}
+
+async fn process_add_reward_leg(
+    rpc_client: &Arc<RpcClient>,
+    payer: &Arc<dyn Signer>,
+    bounty_address: Pubkey,
+    mint: Pubkey,
+    amount: u64,
+) -> Result<Signature, Box<dyn Error>> {
+    let token_accounts =
+        fetch_token_accounts(rpc_client, &mint, &bounty_address, &payer.pubkey()).await;
+
+    let accounts = bounty_hunter::accounts::AddRewardLeg {
+        maker: payer.pubkey(),
+        bounty: bounty_address,
+        mint,
+        maker_token_account: token_accounts.owner_ata,
+        vault: token_accounts.vault,
+        system_program: solana_system_interface::program::ID,
+        token_program: token_accounts.token_program,
+        associated_token_program: spl_associated_token_account_interface::program::ID,
+        event_authority: event_authority_address(),
+        program: bounty_hunter::ID,
+    }
+    .to_account_metas(None);
+
+    let data = bounty_hunter::instruction::AddRewardLeg { amount }.data();
+
+    let ix = Instruction {
+        accounts,
+        data,
+        program_id: bounty_hunter::ID,
+    };
+
+    let mut transaction =
+        Transaction::new_unsigned(Message::new([ix].as_slice(), Some(&payer.pubkey())));
+
+    let blockhash = rpc_client
+        .get_latest_blockhash()
+        .await
+        .map_err(|err| format!("error: unable to get latest blockhash: {}", err))?;
+
+    transaction
+        .try_sign(&[payer], blockhash)
+        .map_err(|err| format!("error: failed to sign transaction: {}", err))?;
+
+    let signature = rpc_client
+        .send_and_confirm_transaction_with_spinner(&transaction)
+        .await
+        .map_err(|err| format!("error: send transaction: {}", err))?;
+
+    Ok(signature)
+}
+
+async fn process_fund_bounty(
+    rpc_client: &Arc<RpcClient>,
+    payer: &Arc<dyn Signer>,
+    bounty_address: Pubkey,
+    amount: u64,
+) -> Result<Signature, Box<dyn Error>> {
+    let data = rpc_client.get_account_data(&bounty_address).await.unwrap();
+
+    let bounty = bounty_hunter::state::Bounty::try_deserialize(&mut data.as_ref())
+        .expect("bounty does not exist");
+
+    let contribution = Pubkey::find_program_address(
+        &[
+            b"contribution",
+            bounty_address.as_ref(),
+            payer.pubkey().as_ref(),
+        ],
+        &bounty_hunter::ID,
+    )
+    .0;
+
+    let token_accounts = if bounty.is_native() {
+        None
+    } else {
+        Some(fetch_token_accounts(rpc_client, &bounty.mint, &bounty_address, &payer.pubkey()).await)
+    };
+
+    let accounts = bounty_hunter::accounts::FundBounty {
+        funder: payer.pubkey(),
+        bounty: bounty_address,
+        contribution,
+        mint: token_accounts.map(|_| bounty.mint),
+        funder_token_account: token_accounts.map(|t| t.owner_ata),
+        vault: token_accounts.map(|t| t.vault),
+        system_program: solana_system_interface::program::ID,
+        token_program: token_accounts.map(|t| t.token_program),
+        associated_token_program: token_accounts
+            .map(|_| spl_associated_token_account_interface::program::ID),
+        event_authority: event_authority_address(),
+        program: bounty_hunter::ID,
+    }
+    .to_account_metas(None);
+
+    let data = bounty_hunter::instruction::FundBounty { amount }.data();
+
+    let ix = Instruction {
+        accounts,
+        data,
+        program_id: bounty_hunter::ID,
+    };
+
+    let mut transaction =
+        Transaction::new_unsigned(Message::new([ix].as_slice(), Some(&payer.pubkey())));
+
+    let blockhash = rpc_client
+        .get_latest_blockhash()
+        .await
+        .map_err(|err| format!("error: unable to get latest blockhash: {}", err))?;
+
+    transaction
+        .try_sign(&[payer], blockhash)
+        .map_err(|err| format!("error: failed to sign transaction: {}", err))?;
+
+    let signature = rpc_client
+        .send_and_confirm_transaction_with_spinner(&transaction)
+        .await
+        .map_err(|err| format!("error: send transaction: {}", err))?;
+
+    println!("contribution : {:?}", contribution);
+
+    Ok(signature)
+}
+
+async fn process_increase_reward(
+    rpc_client: &Arc<RpcClient>,
+    payer: &Arc<dyn Signer>,
+    bounty_address: Pubkey,
+    amount: u64,
+) -> Result<Signature, Box<dyn Error>> {
+    let data = rpc_client.get_account_data(&bounty_address).await.unwrap();
+
+    let bounty = bounty_hunter::state::Bounty::try_deserialize(&mut data.as_ref())
+        .expect("bounty does not exist");
+
+    let token_accounts = if bounty.is_native() {
+        None
+    } else {
+        Some(fetch_token_accounts(rpc_client, &bounty.mint, &bounty_address, &payer.pubkey()).await)
+    };
+
+    let accounts = bounty_hunter::accounts::IncreaseReward {
+        maker: payer.pubkey(),
+        bounty: bounty_address,
+        mint: token_accounts.map(|_| bounty.mint),
+        maker_token_account: token_accounts.map(|t| t.owner_ata),
+        vault: token_accounts.map(|t| t.vault),
+        system_program: solana_system_interface::program::ID,
+        token_program: token_accounts.map(|t| t.token_program),
+        associated_token_program: token_accounts
+            .map(|_| spl_associated_token_account_interface::program::ID),
+        event_authority: event_authority_address(),
+        program: bounty_hunter::ID,
+    }
+    .to_account_metas(None);
+
+    let data = bounty_hunter::instruction::IncreaseReward { amount }.data();
+
+    let ix = Instruction {
+        accounts,
+        data,
+        program_id: bounty_hunter::ID,
+    };
+
+    let mut transaction =
+        Transaction::new_unsigned(Message::new([ix].as_slice(), Some(&payer.pubkey())));
+
+    let blockhash = rpc_client
+        .get_latest_blockhash()
+        .await
+        .map_err(|err| format!("error: unable to get latest blockhash: {}", err))?;
+
+    transaction
+        .try_sign(&[payer], blockhash)
+        .map_err(|err| format!("error: failed to sign transaction: {}", err))?;
+
+    let signature = rpc_client
+        .send_and_confirm_transaction_with_spinner(&transaction)
+        .await
+        .map_err(|err| format!("error: send transaction: {}", err))?;
+
+    Ok(signature)
+}
+
+async fn process_decrease_reward(
+    rpc_client: &Arc<RpcClient>,
+    payer: &Arc<dyn Signer>,
+    bounty_address: Pubkey,
+    amount: u64,
+) -> Result<Signature, Box<dyn Error>> {
+    let data = rpc_client.get_account_data(&bounty_address).await.unwrap();
+
+    let bounty = bounty_hunter::state::Bounty::try_deserialize(&mut data.as_ref())
+        .expect("bounty does not exist");
+
+    let token_accounts = if bounty.is_native() {
+        None
+    } else {
+        Some(fetch_token_accounts(rpc_client, &bounty.mint, &bounty_address, &payer.pubkey()).await)
+    };
+
+    let accounts = bounty_hunter::accounts::DecreaseReward {
+        maker: payer.pubkey(),
+        bounty: bounty_address,
+        mint: token_accounts.map(|_| bounty.mint),
+        vault: token_accounts.map(|t| t.vault),
+        maker_token_account: token_accounts.map(|t| t.owner_ata),
+        min_reward: min_reward_address(&bounty.mint),
+        token_program: token_accounts.map(|t| t.token_program),
+        associated_token_program: token_accounts
+            .map(|_| spl_associated_token_account_interface::program::ID),
+        memo_program: token_accounts.map(|_| MEMO_PROGRAM_ID),
+        event_authority: event_authority_address(),
+        program: bounty_hunter::ID,
+    }
+    .to_account_metas(None);
+
+    let data = bounty_hunter::instruction::DecreaseReward { amount }.data();
+
+    let ix = Instruction {
+        accounts,
+        data,
+        program_id: bounty_hunter::ID,
+    };
+
+    let mut transaction =
+        Transaction::new_unsigned(Message::new([ix].as_slice(), Some(&payer.pubkey())));
+
+    let blockhash = rpc_client
+        .get_latest_blockhash()
+        .await
+        .map_err(|err| format!("error: unable to get latest blockhash: {}", err))?;
+
+    transaction
+        .try_sign(&[payer], blockhash)
+        .map_err(|err| format!("error: failed to sign transaction: {}", err))?;
+
+    let signature = rpc_client
+        .send_and_confirm_transaction_with_spinner(&transaction)
+        .await
+        .map_err(|err| format!("error: send transaction: {}", err))?;
+
+    Ok(signature)
+}
+
+async fn process_refund_contribution(
+    rpc_client: &Arc<RpcClient>,
+    payer: &Arc<dyn Signer>,
+    bounty_address: Pubkey,
+    funder: Pubkey,
+) -> Result<Signature, Box<dyn Error>> {
+    let data = rpc_client.get_account_data(&bounty_address).await.unwrap();
+
+    let bounty = bounty_hunter::state::Bounty::try_deserialize(&mut data.as_ref())
+        .expect("bounty does not exist");
+
+    let contribution = Pubkey::find_program_address(
+        &[b"contribution", bounty_address.as_ref(), funder.as_ref()],
+        &bounty_hunter::ID,
+    )
+    .0;
+
+    // once paid out the vault is gone and only the contribution rent is returned
+    let token_accounts = if bounty.is_native() || bounty.refund_pool.is_none() {
+        None
+    } else {
+        Some(fetch_token_accounts(rpc_client, &bounty.mint, &bounty_address, &funder).await)
+    };
+    let mint = if bounty.is_native() {
+        None
+    } else {
+        Some(bounty.mint)
+    };
+
+    let accounts = bounty_hunter::accounts::RefundContribution {
+        payer: payer.pubkey(),
+        funder,
+        maker: bounty.maker,
+        bounty: bounty_address,
+        contribution,
+        vault: token_accounts.map(|t| t.vault),
+        mint,
+        funder_token_account: token_accounts.map(|t| t.owner_ata),
+        token_program: token_accounts.map(|t| t.token_program),
+        associated_token_program: token_accounts
+            .map(|_| spl_associated_token_account_interface::program::ID),
+        memo_program: token_accounts.map(|_| MEMO_PROGRAM_ID),
+        event_authority: event_authority_address(),
+        program: bounty_hunter::ID,
+    }
+    .to_account_metas(None);
+
+    let data = bounty_hunter::instruction::RefundContribution {}.data();
+
+    let ix = Instruction {
+        accounts,
+        data,
+        program_id: bounty_hunter::ID,
+    };
+
+    let mut transaction =
+        Transaction::new_unsigned(Message::new([ix].as_slice(), Some(&payer.pubkey())));
+
+    let blockhash = rpc_client
+        .get_latest_blockhash()
+        .await
+        .map_err(|err| format!("error: unable to get latest blockhash: {}", err))?;
+
+    transaction
+        .try_sign(&[payer], blockhash)
+        .map_err(|err| format!("error: failed to sign transaction: {}", err))?;
+
+    let signature = rpc_client
+        .send_and_confirm_transaction_with_spinner(&transaction)
+        .await
+        .map_err(|err| format!("error: send transaction: {}", err))?;
+
+    Ok(signature)
+}
+
+fn config_address() -> Pubkey {
+    Pubkey::find_program_address(&[b"config"], &bounty_hunter::ID).0
+}
+
+/// proof of the payout made for a prize tier or milestone of a bounty
+fn receipt_address(bounty: &Pubkey, index: u8) -> Pubkey {
+    Pubkey::find_program_address(&[b"receipt", bounty.as_ref(), &[index]], &bounty_hunter::ID).0
+}
+
+fn hunter_profile_address(hunter: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[b"hunter_profile", hunter.as_ref()], &bounty_hunter::ID).0
+}
+
+fn maker_profile_address(maker: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[b"maker_profile", maker.as_ref()], &bounty_hunter::ID).0
+}
+
+/// native SOL minimums live under the default pubkey
+fn min_reward_address(mint: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[b"min_reward", mint.as_ref()], &bounty_hunter::ID).0
+}
+
+/// signs the self CPI every instruction emits its event through
+fn event_authority_address() -> Pubkey {
+    Pubkey::find_program_address(&[b"__event_authority"], &bounty_hunter::ID).0
+}
+
+async fn fetch_config(rpc_client: &Arc<RpcClient>) -> (bounty_hunter::state::Config, Pubkey) {
+    let config_address = config_address();
+    let data = rpc_client
+        .get_account_data(&config_address)
+        .await
+        .expect("config is not initialized");
+
+    let config = bounty_hunter::state::Config::try_deserialize(&mut data.as_ref())
+        .expect("config does not exist");
+
+    (config, config_address)
+}
+
+async fn process_get_config(rpc_client: &Arc<RpcClient>) -> Result<(), Box<dyn Error>> {
+    let (config, config_address) = fetch_config(rpc_client).await;
+
+    println!(
+        "CONFIG {}: \n\t admin: {} \n\t fee (bps): {} \n\t treasury: {}",
+        config_address, config.admin, config.fee_bps, config.treasury
+    );
+
+    Ok(())
+}
+
+async fn process_initialize_config(
+    rpc_client: &Arc<RpcClient>,
+    payer: &Arc<dyn Signer>,
+    fee_bps: u16,
+    treasury: Pubkey,
+) -> Result<Signature, Box<dyn Error>> {
+    let accounts = bounty_hunter::accounts::InitializeConfig {
+        admin: payer.pubkey(),
+        config: config_address(),
+        // only the program's upgrade authority can initialize the config
+        program_data: solana_sdk::bpf_loader_upgradeable::get_program_data_address(
+            &bounty_hunter::ID,
+        ),
+        system_program: solana_system_interface::program::ID,
+        event_authority: event_authority_address(),
+        program: bounty_hunter::ID,
+    }
+    .to_account_metas(None);
+
+    let data = bounty_hunter::instruction::InitializeConfig { fee_bps, treasury }.data();
+
+    let ix = Instruction {
+        accounts,
+        data,
+        program_id: bounty_hunter::ID,
+    };
+
+    let mut transaction =
+        Transaction::new_unsigned(Message::new([ix].as_slice(), Some(&payer.pubkey())));
+
+    let blockhash = rpc_client
+        .get_latest_blockhash()
+        .await
+        .map_err(|err| format!("error: unable to get latest blockhash: {}", err))?;
+
+    transaction
+        .try_sign(&[payer], blockhash)
+        .map_err(|err| format!("error: failed to sign transaction: {}", err))?;
+
+    let signature = rpc_client
+        .send_and_confirm_transaction_with_spinner(&transaction)
+        .await
+        .map_err(|err| format!("error: send transaction: {}", err))?;
+
+    Ok(signature)
+}
+
+async fn process_update_config(
+    rpc_client: &Arc<RpcClient>,
+    payer: &Arc<dyn Signer>,
+    admin: Option<Pubkey>,
+    fee_bps: Option<u16>,
+    treasury: Option<Pubkey>,
+) -> Result<Signature, Box<dyn Error>> {
+    // anything not given keeps its current value
+    let (config, config_address) = fetch_config(rpc_client).await;
+
+    let accounts = bounty_hunter::accounts::UpdateConfig {
+        admin: payer.pubkey(),
+        config: config_address,
+        event_authority: event_authority_address(),
+        program: bounty_hunter::ID,
+    }
+    .to_account_metas(None);
+
+    let data = bounty_hunter::instruction::UpdateConfig {
+        admin: admin.unwrap_or(config.admin),
+        fee_bps: fee_bps.unwrap_or(config.fee_bps),
+        treasury: treasury.unwrap_or(config.treasury),
+    }
+    .data();
+
+    let ix = Instruction {
+        accounts,
+        data,
+        program_id: bounty_hunter::ID,
+    };
+
+    let mut transaction =
+        Transaction::new_unsigned(Message::new([ix].as_slice(), Some(&payer.pubkey())));
+
+    let blockhash = rpc_client
+        .get_latest_blockhash()
+        .await
+        .map_err(|err| format!("error: unable to get latest blockhash: {}", err))?;
+
+    transaction
+        .try_sign(&[payer], blockhash)
+        .map_err(|err| format!("error: failed to sign transaction: {}", err))?;
+
+    let signature = rpc_client
+        .send_and_confirm_transaction_with_spinner(&transaction)
+        .await
+        .map_err(|err| format!("error: send transaction: {}", err))?;
+
+    Ok(signature)
+}
+
+/// `None` while the admin hasn't set a minimum for the mint
+async fn fetch_min_reward(
+    rpc_client: &Arc<RpcClient>,
+    mint: &Pubkey,
+) -> Option<bounty_hunter::state::MinReward> {
+    let data = rpc_client
+        .get_account_data(&min_reward_address(mint))
+        .await
+        .ok()?;
+
+    bounty_hunter::state::MinReward::try_deserialize(&mut data.as_ref()).ok()
+}
+
+async fn process_get_min_reward(
+    rpc_client: &Arc<RpcClient>,
+    mint: Option<Pubkey>,
+) -> Result<(), Box<dyn Error>> {
+    let mint = mint.unwrap_or_default();
+    let amount = fetch_min_reward(rpc_client, &mint)
+        .await
+        .map_or("none".to_string(), |min_reward| {
+            min_reward.amount.to_string()
+        });
+
+    println!(
+        "MIN REWARD {}: \n\t mint: {} \n\t amount: {}",
+        min_reward_address(&mint),
+        if mint == Pubkey::default() {
+            "native SOL".to_string()
+        } else {
+            mint.to_string()
+        },
+        amount
+    );
+
+    Ok(())
+}
+
+async fn process_set_min_reward(
+    rpc_client: &Arc<RpcClient>,
+    payer: &Arc<dyn Signer>,
+    mint: Option<Pubkey>,
+    amount: u64,
+) -> Result<Signature, Box<dyn Error>> {
+    let mint = mint.unwrap_or_default();
+
+    let accounts = bounty_hunter::accounts::SetMinReward {
+        admin: payer.pubkey(),
+        config: config_address(),
+        min_reward: min_reward_address(&mint),
+        system_program: solana_system_interface::program::ID,
+        event_authority: event_authority_address(),
+        program: bounty_hunter::ID,
+    }
+    .to_account_metas(None);
+
+    let data = bounty_hunter::instruction::SetMinReward { mint, amount }.data();
+
+    let ix = Instruction {
+        accounts,
+        data,
+        program_id: bounty_hunter::ID,
+    };
+
+    let mut transaction =
+        Transaction::new_unsigned(Message::new([ix].as_slice(), Some(&payer.pubkey())));
+
+    let blockhash = rpc_client
+        .get_latest_blockhash()
+        .await
+        .map_err(|err| format!("error: unable to get latest blockhash: {}", err))?;
+
+    transaction
+        .try_sign(&[payer], blockhash)
+        .map_err(|err| format!("error: failed to sign transaction: {}", err))?;
+
+    let signature = rpc_client
+        .send_and_confirm_transaction_with_spinner(&transaction)
+        .await
+        .map_err(|err| format!("error: send transaction: {}", err))?;
+
+    Ok(signature)
+}
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn Error>> {
+    let app_matches = Command::new(crate_name!())
+        .about(crate_description!())
+        .version(crate_version!())
+        .subcommand_required(true)
+        .arg_required_else_help(true)
+        .arg({
+            let arg = Arg::new("config_file")
+                .short('C')
+                .long("config")
+                .value_name("PATH")
+                .takes_value(true)
+                .global(true)
+                .help("Configuration file to use");
+            if let Some(ref config_file) = *solana_cli_config::CONFIG_FILE {
+                arg.default_value(config_file)
+            } else {
+                arg
             }
         })
         .arg(
@@ -462,27 +2198,376 @@ async fn main() -> Result<(), Box<dyn Error>> {
                 .global(true)
                 .help("Filepath or URL to a keypair [default: client keypair]"),
         )
-        .arg(
-            Arg::new("verbose")
-                .long("verbose")
-                .short('v')
-                .takes_value(false)
-                .global(true)
-                .help("Show additional information"),
+        .arg(
+            Arg::new("verbose")
+                .long("verbose")
+                .short('v')
+                .takes_value(false)
+                .global(true)
+                .help("Show additional information"),
+        )
+        .arg(
+            Arg::new("json_rpc_url")
+                .short('u')
+                .long("url")
+                .value_name("URL")
+                .takes_value(true)
+                .global(true)
+                .value_parser(parse_url_or_moniker)
+                .help("JSON RPC URL for the cluster [default: value from configuration file]"),
+        )
+        .subcommand(
+            Command::new("create-bounty")
+                .about("Creates a bounty")
+                .arg(
+                    Arg::new("description")
+                        .value_name("description")
+                        .takes_value(true)
+                        .required(true)
+                        .help("Bounty description"),
+                )
+                .arg(
+                    Arg::new("link")
+                        .value_name("link")
+                        .takes_value(true)
+                        .required(true)
+                        .help("Bounty link"),
+                )
+                .arg(
+                    Arg::new("reward")
+                        .value_name("reward")
+                        .takes_value(true)
+                        .required(true)
+                        .help("Bounty reward, in lamports for native SOL bounties"),
+                )
+                .arg(
+                    Arg::new("mint")
+                        .value_name("mint")
+                        .value_parser(SignerSourceParserBuilder::default().allow_pubkey().build())
+                        .takes_value(true)
+                        .required(false)
+                        .help("Specify the mint address [default: reward in native SOL]"),
+                )
+                .arg(
+                    Arg::new("deadline")
+                        .value_name("UNIX_TIMESTAMP")
+                        .long("deadline")
+                        .short('d')
+                        .takes_value(true)
+                        .required(false)
+                        .help("Unix timestamp after which the bounty can be expired"),
+                )
+                .arg(
+                    Arg::new("prizes")
+                        .value_name("BPS,BPS,...")
+                        .long("prizes")
+                        .short('p')
+                        .takes_value(true)
+                        .required(false)
+                        .help("Comma separated prize tiers in basis points, e.g. 5000,3000,2000 [default: single winner]"),
+                )
+                .arg(
+                    Arg::new("milestone")
+                        .value_name("DESCRIPTION=AMOUNT")
+                        .long("milestone")
+                        .short('m')
+                        .takes_value(true)
+                        .multiple_occurrences(true)
+                        .required(false)
+                        .help("Milestone released separately, repeat for each one; amounts must add up to the reward"),
+                )
+                .arg(
+                    Arg::new("arbiter")
+                        .value_name("arbiter")
+                        .long("arbiter")
+                        .short('a')
+                        .value_parser(SignerSourceParserBuilder::default().allow_pubkey().build())
+                        .takes_value(true)
+                        .required(false)
+                        .help("Address allowed to settle disputes between the maker and hunters"),
+                )
+                .arg(
+                    Arg::new("allow")
+                        .value_name("HUNTER")
+                        .long("allow")
+                        .value_parser(SignerSourceParserBuilder::default().allow_pubkey().build())
+                        .takes_value(true)
+                        .multiple_occurrences(true)
+                        .required(false)
+                        .conflicts_with("merkle_root")
+                        .help("Only lets this hunter submit, repeat for each one [default: anyone can submit]"),
+                )
+                .arg(
+                    Arg::new("merkle_root")
+                        .value_name("HASH")
+                        .long("merkle-root")
+                        .takes_value(true)
+                        .required(false)
+                        .help("Only lets hunters with a proof against this base58 root submit"),
+                )
+                .arg(
+                    Arg::new("bond")
+                        .value_name("LAMPORTS")
+                        .long("bond")
+                        .takes_value(true)
+                        .required(false)
+                        .help("Lamports every hunter locks in their submission, slashable as spam [default: 0]"),
+                )
+                .arg(
+                    Arg::new("metadata")
+                        .value_name("FILE")
+                        .long("metadata")
+                        .takes_value(true)
+                        .required(false)
+                        .help("Local copy of the metadata document the description is the URI of, its SHA-256 is stored on chain"),
+                ),
+        )
+        .subcommand(
+            Command::new("submit-solution")
+                .about("Submits a solution to a bounty")
+                .arg(
+                    Arg::new("bounty_address")
+                        .value_name("bounty_address")
+                        .value_parser(SignerSourceParserBuilder::default().allow_pubkey().build())
+                        .takes_value(true)
+                        .required(true)
+                        .help("Specify the bounty address"),
+                )
+                .arg(
+                    Arg::new("notes")
+                        .value_name("notes")
+                        .takes_value(true)
+                        .required(true)
+                        .help("Submission notes"),
+                )
+                .arg(
+                    Arg::new("link")
+                        .value_name("link")
+                        .takes_value(true)
+                        .required(true)
+                        .help("Submission link"),
+                )
+                .arg(
+                    Arg::new("proof")
+                        .value_name("HASH")
+                        .long("proof")
+                        .takes_value(true)
+                        .multiple_occurrences(true)
+                        .required(false)
+                        .help("Base58 sibling hash of the Merkle proof for private bounties, repeat for each level from the leaf up"),
+                )
+                .arg(
+                    Arg::new("metadata")
+                        .value_name("FILE")
+                        .long("metadata")
+                        .takes_value(true)
+                        .required(false)
+                        .help("Local copy of the metadata document the notes is the URI of, its SHA-256 is stored on chain"),
+                ),
+        )
+        .subcommand(
+            Command::new("edit-submission")
+                .about("Changes a pending submission")
+                .arg(
+                    Arg::new("bounty_address")
+                        .value_name("bounty_address")
+                        .value_parser(SignerSourceParserBuilder::default().allow_pubkey().build())
+                        .takes_value(true)
+                        .required(true)
+                        .help("Specify the bounty address"),
+                )
+                .arg(
+                    Arg::new("notes")
+                        .value_name("notes")
+                        .takes_value(true)
+                        .required(true)
+                        .help("Submission notes"),
+                )
+                .arg(
+                    Arg::new("link")
+                        .value_name("link")
+                        .takes_value(true)
+                        .required(true)
+                        .help("Submission link"),
+                )
+                .arg(
+                    Arg::new("metadata")
+                        .value_name("FILE")
+                        .long("metadata")
+                        .takes_value(true)
+                        .required(false)
+                        .help("Local copy of the metadata document the notes is the URI of, its SHA-256 is stored on chain"),
+                ),
+        )
+        .subcommand(
+            Command::new("withdraw-submission")
+                .about("Withdraws your submission to a bounty and reclaims its rent and bond")
+                .arg(
+                    Arg::new("bounty_address")
+                        .value_name("bounty_address")
+                        .value_parser(SignerSourceParserBuilder::default().allow_pubkey().build())
+                        .takes_value(true)
+                        .required(true)
+                        .help("Specify the bounty address"),
+                ),
+        )
+        .subcommand(
+            Command::new("review-submission")
+                .about("Locks a submission against edits while you review it")
+                .arg(
+                    Arg::new("submission_address")
+                        .value_name("submission_address")
+                        .value_parser(SignerSourceParserBuilder::default().allow_pubkey().build())
+                        .takes_value(true)
+                        .required(true)
+                        .index(1)
+                        .display_order(1)
+                        .help("Specify the submission address"),
+                ),
+        )
+        .subcommand(
+            Command::new("slash-submission")
+                .about("Throws out a spam submission, its bond goes to the treasury")
+                .arg(
+                    Arg::new("submission_address")
+                        .value_name("submission_address")
+                        .value_parser(SignerSourceParserBuilder::default().allow_pubkey().build())
+                        .takes_value(true)
+                        .required(true)
+                        .index(1)
+                        .display_order(1)
+                        .help("Specify the submission address"),
+                ),
+        )
+        .subcommand(
+            Command::new("close-submission")
+                .about("Closes a submission to a finished bounty and returns its rent to the hunter")
+                .arg(
+                    Arg::new("submission_address")
+                        .value_name("submission_address")
+                        .value_parser(SignerSourceParserBuilder::default().allow_pubkey().build())
+                        .takes_value(true)
+                        .required(true)
+                        .index(1)
+                        .display_order(1)
+                        .help("Specify the submission address"),
+                ),
         )
-        .arg(
-            Arg::new("json_rpc_url")
-                .short('u')
-                .long("url")
-                .value_name("URL")
-                .takes_value(true)
-                .global(true)
-                .value_parser(parse_url_or_moniker)
-                .help("JSON RPC URL for the cluster [default: value from configuration file]"),
+        .subcommand(
+            Command::new("get-bounty").about("Gets a bounty").arg(
+                Arg::new("bounty_address")
+                    .value_name("bounty_address")
+                    .value_parser(SignerSourceParserBuilder::default().allow_pubkey().build())
+                    .takes_value(true)
+                    .required(true)
+                    .index(1)
+                    .display_order(1)
+                    .help("Specify the bounty address"),
+            ),
         )
         .subcommand(
-            Command::new("create-bounty")
-                .about("Creates a bounty")
+            Command::new("get-submission")
+                .about("Gets a submission")
+                .arg(
+                    Arg::new("submission_address")
+                        .value_name("submission_address")
+                        .value_parser(SignerSourceParserBuilder::default().allow_pubkey().build())
+                        .takes_value(true)
+                        .required(true)
+                        .index(1)
+                        .display_order(1)
+                        .help("Specify the submission address"),
+                ),
+        )
+        .subcommand(
+            Command::new("verify-metadata")
+                .about("Downloads the metadata document of a bounty or submission and checks its hash")
+                .arg(
+                    Arg::new("address")
+                        .value_name("address")
+                        .value_parser(SignerSourceParserBuilder::default().allow_pubkey().build())
+                        .takes_value(true)
+                        .required(true)
+                        .index(1)
+                        .display_order(1)
+                        .help("Specify the bounty or submission address"),
+                ),
+        )
+        .subcommand(
+            Command::new("get-hunter-profile")
+                .about("Gets a hunter's wins and earnings")
+                .arg(
+                    Arg::new("hunter")
+                        .value_name("hunter")
+                        .value_parser(SignerSourceParserBuilder::default().allow_pubkey().build())
+                        .takes_value(true)
+                        .required(true)
+                        .index(1)
+                        .display_order(1)
+                        .help("Specify the hunter address"),
+                ),
+        )
+        .subcommand(
+            Command::new("get-maker-profile")
+                .about("Gets a maker's created, paid out and cancelled bounties")
+                .arg(
+                    Arg::new("maker")
+                        .value_name("maker")
+                        .value_parser(SignerSourceParserBuilder::default().allow_pubkey().build())
+                        .takes_value(true)
+                        .required(true)
+                        .index(1)
+                        .display_order(1)
+                        .help("Specify the maker address"),
+                ),
+        )
+        .subcommand(
+            Command::new("accept-submission")
+                .about("Accepts a submission, locking it for review first if it is still pending")
+                .arg(
+                    Arg::new("submission_address")
+                        .value_name("submission_address")
+                        .value_parser(SignerSourceParserBuilder::default().allow_pubkey().build())
+                        .takes_value(true)
+                        .required(true)
+                        .index(1)
+                        .display_order(1)
+                        .help("Specify the submission address"),
+                ),
+        )
+        .subcommand(
+            Command::new("approve-milestone")
+                .about("Approves a bounty milestone for a submission")
+                .arg(
+                    Arg::new("submission_address")
+                        .value_name("submission_address")
+                        .value_parser(SignerSourceParserBuilder::default().allow_pubkey().build())
+                        .takes_value(true)
+                        .required(true)
+                        .index(1)
+                        .display_order(1)
+                        .help("Specify the submission address"),
+                )
+                .arg(
+                    Arg::new("index")
+                        .value_name("index")
+                        .takes_value(true)
+                        .required(true)
+                        .index(2)
+                        .help("Index of the milestone to approve"),
+                ),
+        )
+        .subcommand(
+            Command::new("update-bounty")
+                .about("Amends the description and link of a bounty nothing has been paid from")
+                .arg(
+                    Arg::new("bounty_address")
+                        .value_name("bounty_address")
+                        .value_parser(SignerSourceParserBuilder::default().allow_pubkey().build())
+                        .takes_value(true)
+                        .required(true)
+                        .help("Specify the bounty address"),
+                )
                 .arg(
                     Arg::new("description")
                         .value_name("description")
@@ -495,101 +2580,311 @@ async fn main() -> Result<(), Box<dyn Error>> {
                         .value_name("link")
                         .takes_value(true)
                         .required(true)
-                        .help("Bounty link"),
+                        .help("Bounty link"),
+                )
+                .arg(
+                    Arg::new("metadata")
+                        .value_name("FILE")
+                        .long("metadata")
+                        .takes_value(true)
+                        .required(false)
+                        .help("Local copy of the metadata document the description is the URI of, its SHA-256 is stored on chain"),
+                ),
+        )
+        .subcommand(
+            Command::new("cancel-bounty").about("Cancels a bounty").arg(
+                Arg::new("bounty_address")
+                    .value_name("bounty_address")
+                    .value_parser(SignerSourceParserBuilder::default().allow_pubkey().build())
+                    .takes_value(true)
+                    .required(true)
+                    .index(1)
+                    .display_order(1)
+                    .help("Specify the bounty address"),
+            ),
+        )
+        .subcommand(
+            Command::new("expire-bounty")
+                .about("Refunds an expired bounty to its maker")
+                .arg(
+                    Arg::new("bounty_address")
+                        .value_name("bounty_address")
+                        .value_parser(SignerSourceParserBuilder::default().allow_pubkey().build())
+                        .takes_value(true)
+                        .required(true)
+                        .index(1)
+                        .display_order(1)
+                        .help("Specify the bounty address"),
+                ),
+        )
+        .subcommand(
+            Command::new("open-dispute")
+                .about("Escalates a submission to the bounty arbiter")
+                .arg(
+                    Arg::new("submission_address")
+                        .value_name("submission_address")
+                        .value_parser(SignerSourceParserBuilder::default().allow_pubkey().build())
+                        .takes_value(true)
+                        .required(true)
+                        .index(1)
+                        .display_order(1)
+                        .help("Specify the submission address"),
+                ),
+        )
+        .subcommand(
+            Command::new("resolve-dispute")
+                .about("Settles the open dispute on a submission, as the bounty arbiter")
+                .arg(
+                    Arg::new("submission_address")
+                        .value_name("submission_address")
+                        .value_parser(SignerSourceParserBuilder::default().allow_pubkey().build())
+                        .takes_value(true)
+                        .required(true)
+                        .index(1)
+                        .display_order(1)
+                        .help("Specify the submission address"),
+                )
+                .arg(
+                    Arg::new("award_hunter")
+                        .long("award-hunter")
+                        .takes_value(false)
+                        .help("Accept the submission on the maker's behalf [default: reject the submission]"),
+                ),
+        )
+        .subcommand(
+            Command::new("add-reward-leg")
+                .about("Escrows a second reward mint next to a bounty's main reward")
+                .arg(
+                    Arg::new("bounty_address")
+                        .value_name("bounty_address")
+                        .value_parser(SignerSourceParserBuilder::default().allow_pubkey().build())
+                        .takes_value(true)
+                        .required(true)
+                        .index(1)
+                        .display_order(1)
+                        .help("Specify the bounty address"),
+                )
+                .arg(
+                    Arg::new("mint")
+                        .value_name("mint")
+                        .value_parser(SignerSourceParserBuilder::default().allow_pubkey().build())
+                        .takes_value(true)
+                        .required(true)
+                        .index(2)
+                        .help("Mint of the extra reward"),
+                )
+                .arg(
+                    Arg::new("amount")
+                        .value_name("amount")
+                        .takes_value(true)
+                        .required(true)
+                        .index(3)
+                        .help("Amount of the extra reward, split like the main reward"),
+                ),
+        )
+        .subcommand(
+            Command::new("fund-bounty")
+                .about("Adds funds to a bounty")
+                .arg(
+                    Arg::new("bounty_address")
+                        .value_name("bounty_address")
+                        .value_parser(SignerSourceParserBuilder::default().allow_pubkey().build())
+                        .takes_value(true)
+                        .required(true)
+                        .index(1)
+                        .display_order(1)
+                        .help("Specify the bounty address"),
+                )
+                .arg(
+                    Arg::new("amount")
+                        .value_name("amount")
+                        .takes_value(true)
+                        .required(true)
+                        .index(2)
+                        .help("Amount to add, in lamports for native SOL bounties"),
+                ),
+        )
+        .subcommand(
+            Command::new("increase-reward")
+                .about("Deposits more into the maker's reward")
+                .arg(
+                    Arg::new("bounty_address")
+                        .value_name("bounty_address")
+                        .value_parser(SignerSourceParserBuilder::default().allow_pubkey().build())
+                        .takes_value(true)
+                        .required(true)
+                        .index(1)
+                        .display_order(1)
+                        .help("Specify the bounty address"),
+                )
+                .arg(
+                    Arg::new("amount")
+                        .value_name("amount")
+                        .takes_value(true)
+                        .required(true)
+                        .index(2)
+                        .help("Amount to add, in lamports for native SOL bounties"),
+                ),
+        )
+        .subcommand(
+            Command::new("decrease-reward")
+                .about("Takes part of the maker's reward back while the bounty has no submissions")
+                .arg(
+                    Arg::new("bounty_address")
+                        .value_name("bounty_address")
+                        .value_parser(SignerSourceParserBuilder::default().allow_pubkey().build())
+                        .takes_value(true)
+                        .required(true)
+                        .index(1)
+                        .display_order(1)
+                        .help("Specify the bounty address"),
+                )
+                .arg(
+                    Arg::new("amount")
+                        .value_name("amount")
+                        .takes_value(true)
+                        .required(true)
+                        .index(2)
+                        .help("Amount to take back, in lamports for native SOL bounties"),
+                ),
+        )
+        .subcommand(
+            Command::new("refund-contribution")
+                .about("Refunds a contribution to a cancelled, expired or paid out bounty")
+                .arg(
+                    Arg::new("bounty_address")
+                        .value_name("bounty_address")
+                        .value_parser(SignerSourceParserBuilder::default().allow_pubkey().build())
+                        .takes_value(true)
+                        .required(true)
+                        .index(1)
+                        .display_order(1)
+                        .help("Specify the bounty address"),
                 )
                 .arg(
-                    Arg::new("reward")
-                        .value_name("reward")
+                    Arg::new("funder")
+                        .value_name("funder")
+                        .value_parser(SignerSourceParserBuilder::default().allow_pubkey().build())
                         .takes_value(true)
-                        .required(true)
-                        .help("Bounty reward"),
-                )
+                        .long("funder")
+                        .short('f')
+                        .required(false)
+                        .help("Funder to refund [default: payer]"),
+                ),
+        )
+        .subcommand(
+            Command::new("sweep-excess")
+                .about("Returns tokens sent to a bounty vault beyond its escrow to the maker")
                 .arg(
-                    Arg::new("mint")
-                        .value_name("mint")
+                    Arg::new("bounty_address")
+                        .value_name("bounty_address")
                         .value_parser(SignerSourceParserBuilder::default().allow_pubkey().build())
                         .takes_value(true)
                         .required(true)
-                        .help("Specify the mint address"),
+                        .index(1)
+                        .display_order(1)
+                        .help("Specify the bounty address"),
                 ),
         )
         .subcommand(
-            Command::new("submit-solution")
-                .about("Submits a solution to a bounty")
+            Command::new("close-bounty")
+                .about("Closes a finished bounty and returns its rent to the maker")
                 .arg(
                     Arg::new("bounty_address")
                         .value_name("bounty_address")
                         .value_parser(SignerSourceParserBuilder::default().allow_pubkey().build())
                         .takes_value(true)
                         .required(true)
+                        .index(1)
+                        .display_order(1)
                         .help("Specify the bounty address"),
-                )
+                ),
+        )
+        .subcommand(
+            Command::new("initialize-config")
+                .about("Creates the program config, required after deployment before anything can be paid out. The payer must be the upgrade authority and becomes its admin")
                 .arg(
-                    Arg::new("notes")
-                        .value_name("notes")
+                    Arg::new("fee_bps")
+                        .value_name("fee_bps")
                         .takes_value(true)
                         .required(true)
-                        .help("Submission notes"),
+                        .index(1)
+                        .help("Protocol fee taken from hunter payouts, in basis points"),
                 )
                 .arg(
-                    Arg::new("link")
-                        .value_name("link")
+                    Arg::new("treasury")
+                        .value_name("treasury")
+                        .value_parser(SignerSourceParserBuilder::default().allow_pubkey().build())
                         .takes_value(true)
                         .required(true)
-                        .help("Submission link"),
+                        .index(2)
+                        .help("Wallet receiving the protocol fees"),
                 ),
         )
         .subcommand(
-            Command::new("get-bounty").about("Gets a bounty").arg(
-                Arg::new("bounty_address")
-                    .value_name("bounty_address")
-                    .value_parser(SignerSourceParserBuilder::default().allow_pubkey().build())
-                    .takes_value(true)
-                    .required(true)
-                    .index(1)
-                    .display_order(1)
-                    .help("Specify the bounty address"),
-            ),
-        )
-        .subcommand(
-            Command::new("get-submission")
-                .about("Gets a submission")
+            Command::new("update-config")
+                .about("Updates the program config")
                 .arg(
-                    Arg::new("submission_address")
-                        .value_name("submission_address")
+                    Arg::new("admin")
+                        .value_name("admin")
                         .value_parser(SignerSourceParserBuilder::default().allow_pubkey().build())
                         .takes_value(true)
-                        .required(true)
-                        .index(1)
-                        .display_order(1)
-                        .help("Specify the submission address"),
+                        .long("admin")
+                        .required(false)
+                        .help("New config admin"),
+                )
+                .arg(
+                    Arg::new("fee_bps")
+                        .value_name("fee_bps")
+                        .takes_value(true)
+                        .long("fee-bps")
+                        .required(false)
+                        .help("New protocol fee, in basis points"),
+                )
+                .arg(
+                    Arg::new("treasury")
+                        .value_name("treasury")
+                        .value_parser(SignerSourceParserBuilder::default().allow_pubkey().build())
+                        .takes_value(true)
+                        .long("treasury")
+                        .required(false)
+                        .help("New treasury wallet"),
                 ),
         )
+        .subcommand(Command::new("get-config").about("Gets the program config"))
         .subcommand(
-            Command::new("accept-submission")
-                .about("Accepts a submission")
+            Command::new("set-min-reward")
+                .about("Sets the smallest reward new bounties in a mint can offer")
                 .arg(
-                    Arg::new("submission_address")
-                        .value_name("submission_address")
-                        .value_parser(SignerSourceParserBuilder::default().allow_pubkey().build())
+                    Arg::new("amount")
+                        .value_name("amount")
                         .takes_value(true)
                         .required(true)
                         .index(1)
-                        .display_order(1)
-                        .help("Specify the submission address"),
+                        .help("Minimum reward, 0 lifts the minimum"),
+                )
+                .arg(
+                    Arg::new("mint")
+                        .value_name("mint")
+                        .value_parser(SignerSourceParserBuilder::default().allow_pubkey().build())
+                        .takes_value(true)
+                        .long("mint")
+                        .required(false)
+                        .help("Mint the minimum applies to [default: native SOL]"),
                 ),
         )
         .subcommand(
-            Command::new("cancel-bounty").about("Cancels a bounty").arg(
-                Arg::new("bounty_address")
-                    .value_name("bounty_address")
-                    .value_parser(SignerSourceParserBuilder::default().allow_pubkey().build())
-                    .takes_value(true)
-                    .required(true)
-                    .index(1)
-                    .display_order(1)
-                    .help("Specify the bounty address"),
-            ),
+            Command::new("get-min-reward")
+                .about("Gets the minimum reward of a mint")
+                .arg(
+                    Arg::new("mint")
+                        .value_name("mint")
+                        .value_parser(SignerSourceParserBuilder::default().allow_pubkey().build())
+                        .takes_value(true)
+                        .long("mint")
+                        .required(false)
+                        .help("Mint of the minimum [default: native SOL]"),
+                ),
         )
         .subcommand(Command::new("get-all-bounties").about("Gets all bounties"))
         .subcommand(
@@ -628,95 +2923,384 @@ async fn main() -> Result<(), Box<dyn Error>> {
                 &cli_config.keypair_path,
                 "payer",
                 &mut wallet_manager,
-            )?
-        };
-
-        let json_rpc_url = normalize_to_url_if_moniker(
-            matches
-                .get_one::<String>("json_rpc_url")
-                .unwrap_or(&cli_config.json_rpc_url),
-        );
-
-        Config {
-            commitment_config: CommitmentConfig::confirmed(),
-            payer: Arc::from(payer),
-            json_rpc_url,
-            verbose: matches.try_contains_id("verbose")?,
+            )?
+        };
+
+        let json_rpc_url = normalize_to_url_if_moniker(
+            matches
+                .get_one::<String>("json_rpc_url")
+                .unwrap_or(&cli_config.json_rpc_url),
+        );
+
+        Config {
+            commitment_config: CommitmentConfig::confirmed(),
+            payer: Arc::from(payer),
+            json_rpc_url,
+            verbose: matches.try_contains_id("verbose")?,
+        }
+    };
+    solana_logger::setup_with_default("solana=info");
+
+    if config.verbose {
+        println!("JSON RPC URL: {}", config.json_rpc_url);
+    }
+    let rpc_client = Arc::new(RpcClient::new_with_commitment(
+        config.json_rpc_url.clone(),
+        config.commitment_config,
+    ));
+
+    match (command, matches) {
+        ("create-bounty", arg_matches) => {
+            let description: &String = arg_matches
+                .get_one("description")
+                .expect("description is missing");
+            let link: &String = arg_matches.get_one("link").expect("description is missing");
+            let reward: u64 = arg_matches
+                .get_one::<String>("reward")
+                .expect("description is missing")
+                .parse()
+                .expect("unable to parse to u64");
+            let mint =
+                SignerSource::try_get_pubkey(arg_matches, "mint", &mut wallet_manager).unwrap();
+            let deadline: Option<i64> = arg_matches
+                .get_one::<String>("deadline")
+                .map(|deadline| deadline.parse().expect("unable to parse to i64"));
+            let prize_bps: Vec<u16> = arg_matches
+                .get_one::<String>("prizes")
+                .map(|prizes| {
+                    prizes
+                        .split(',')
+                        .map(|bps| bps.trim().parse().expect("unable to parse to u16"))
+                        .collect()
+                })
+                .unwrap_or_default();
+            let milestones: Vec<bounty_hunter::MilestoneArgs> = arg_matches
+                .get_many::<String>("milestone")
+                .map(|milestones| {
+                    milestones
+                        .map(|milestone| {
+                            let (description, amount) = milestone
+                                .rsplit_once('=')
+                                .expect("milestone must be DESCRIPTION=AMOUNT");
+                            bounty_hunter::MilestoneArgs {
+                                description: description.to_string(),
+                                amount: amount.parse().expect("unable to parse to u64"),
+                            }
+                        })
+                        .collect()
+                })
+                .unwrap_or_default();
+            let arbiter =
+                SignerSource::try_get_pubkey(arg_matches, "arbiter", &mut wallet_manager).unwrap();
+            let access = match (
+                SignerSource::try_get_pubkeys(arg_matches, "allow", &mut wallet_manager).unwrap(),
+                arg_matches.get_one::<String>("merkle_root"),
+            ) {
+                (Some(hunters), _) => BountyAccess::AllowList { hunters },
+                (None, Some(root)) => BountyAccess::MerkleRoot {
+                    root: parse_hash(root),
+                },
+                (None, None) => BountyAccess::Public,
+            };
+            let submission_bond: u64 = arg_matches
+                .get_one::<String>("bond")
+                .map(|bond| bond.parse().expect("unable to parse to u64"))
+                .unwrap_or_default();
+            let content_hash = arg_matches
+                .get_one::<String>("metadata")
+                .map(|path| hash_metadata_file(path));
+            let response = process_create_bounty(
+                &rpc_client,
+                &config.payer,
+                description.clone(),
+                link.clone(),
+                reward,
+                mint,
+                deadline,
+                prize_bps,
+                milestones,
+                arbiter,
+                access,
+                submission_bond,
+                content_hash,
+            )
+            .await
+            .unwrap_or_else(|err| {
+                eprintln!("error: create-bounty: {}", err);
+                exit(1);
+            });
+            println!("{}", response);
+        }
+        ("submit-solution", arg_matches) => {
+            let notes: &String = arg_matches.get_one("notes").expect("notes is missing");
+            let link: &String = arg_matches.get_one("link").expect("link is missing");
+            let bounty_address =
+                SignerSource::try_get_pubkey(arg_matches, "bounty_address", &mut wallet_manager)
+                    .unwrap()
+                    .unwrap();
+            let proof: Vec<[u8; 32]> = arg_matches
+                .get_many::<String>("proof")
+                .map(|proof| proof.map(|hash| parse_hash(hash)).collect())
+                .unwrap_or_default();
+            let content_hash = arg_matches
+                .get_one::<String>("metadata")
+                .map(|path| hash_metadata_file(path));
+            let response = process_submit_solution(
+                &rpc_client,
+                &config.payer,
+                bounty_address,
+                notes.clone(),
+                link.clone(),
+                proof,
+                content_hash,
+            )
+            .await
+            .unwrap_or_else(|err| {
+                eprintln!("error: submit-solution: {}", err);
+                exit(1);
+            });
+            println!("{}", response);
+        }
+        ("edit-submission", arg_matches) => {
+            let notes: &String = arg_matches.get_one("notes").expect("notes is missing");
+            let link: &String = arg_matches.get_one("link").expect("link is missing");
+            let bounty_address =
+                SignerSource::try_get_pubkey(arg_matches, "bounty_address", &mut wallet_manager)
+                    .unwrap()
+                    .unwrap();
+            let content_hash = arg_matches
+                .get_one::<String>("metadata")
+                .map(|path| hash_metadata_file(path));
+            let response = process_edit_submission(
+                &rpc_client,
+                &config.payer,
+                bounty_address,
+                notes.clone(),
+                link.clone(),
+                content_hash,
+            )
+            .await
+            .unwrap_or_else(|err| {
+                eprintln!("error: edit-submission: {}", err);
+                exit(1);
+            });
+            println!("{}", response);
+        }
+        ("withdraw-submission", arg_matches) => {
+            let bounty_address =
+                SignerSource::try_get_pubkey(arg_matches, "bounty_address", &mut wallet_manager)
+                    .unwrap()
+                    .unwrap();
+            let response = process_withdraw_submission(&rpc_client, &config.payer, bounty_address)
+                .await
+                .unwrap_or_else(|err| {
+                    eprintln!("error: withdraw-submission: {}", err);
+                    exit(1);
+                });
+            println!("{}", response);
+        }
+        ("review-submission", arg_matches) => {
+            let submission_address = SignerSource::try_get_pubkey(
+                arg_matches,
+                "submission_address",
+                &mut wallet_manager,
+            )
+            .unwrap()
+            .unwrap();
+            let response =
+                process_review_submission(&rpc_client, &config.payer, submission_address)
+                    .await
+                    .unwrap_or_else(|err| {
+                        eprintln!("error: review-submission: {}", err);
+                        exit(1);
+                    });
+            println!("{}", response);
+        }
+        ("slash-submission", arg_matches) => {
+            let submission_address = SignerSource::try_get_pubkey(
+                arg_matches,
+                "submission_address",
+                &mut wallet_manager,
+            )
+            .unwrap()
+            .unwrap();
+            let response = process_slash_submission(&rpc_client, &config.payer, submission_address)
+                .await
+                .unwrap_or_else(|err| {
+                    eprintln!("error: slash-submission: {}", err);
+                    exit(1);
+                });
+            println!("{}", response);
+        }
+        ("close-submission", arg_matches) => {
+            let submission_address = SignerSource::try_get_pubkey(
+                arg_matches,
+                "submission_address",
+                &mut wallet_manager,
+            )
+            .unwrap()
+            .unwrap();
+            let response = process_close_submission(&rpc_client, &config.payer, submission_address)
+                .await
+                .unwrap_or_else(|err| {
+                    eprintln!("error: close-submission: {}", err);
+                    exit(1);
+                });
+            println!("{}", response);
+        }
+        ("get-bounty", arg_matches) => {
+            let bounty_address =
+                SignerSource::try_get_pubkey(arg_matches, "bounty_address", &mut wallet_manager)
+                    .unwrap()
+                    .unwrap();
+            process_get_bounty(&rpc_client, bounty_address)
+                .await
+                .unwrap_or_else(|err| {
+                    eprintln!("error: get-bounty: {}", err);
+                    exit(1);
+                });
+        }
+        ("get-submission", arg_matches) => {
+            let submission_address = SignerSource::try_get_pubkey(
+                arg_matches,
+                "submission_address",
+                &mut wallet_manager,
+            )
+            .unwrap()
+            .unwrap();
+            process_get_submission(&rpc_client, submission_address)
+                .await
+                .unwrap_or_else(|err| {
+                    eprintln!("error: get-submission: {}", err);
+                    exit(1);
+                });
         }
-    };
-    solana_logger::setup_with_default("solana=info");
-
-    if config.verbose {
-        println!("JSON RPC URL: {}", config.json_rpc_url);
-    }
-    let rpc_client = Arc::new(RpcClient::new_with_commitment(
-        config.json_rpc_url.clone(),
-        config.commitment_config,
-    ));
-
-    match (command, matches) {
-        ("create-bounty", arg_matches) => {
-            let description: &String = arg_matches
-                .get_one("description")
-                .expect("description is missing");
-            let link: &String = arg_matches.get_one("link").expect("description is missing");
-            let reward: u64 = arg_matches
-                .get_one::<String>("reward")
-                .expect("description is missing")
-                .parse()
-                .expect("unable to parse to u64");
-            let mint = SignerSource::try_get_pubkey(arg_matches, "mint", &mut wallet_manager)
+        ("verify-metadata", arg_matches) => {
+            let address = SignerSource::try_get_pubkey(arg_matches, "address", &mut wallet_manager)
                 .unwrap()
                 .unwrap();
-            let response = process_create_bounty(
-                &rpc_client,
-                &config.payer,
-                description.clone(),
-                link.clone(),
-                reward,
-                mint,
+            process_verify_metadata(&rpc_client, address)
+                .await
+                .unwrap_or_else(|err| {
+                    eprintln!("error: verify-metadata: {}", err);
+                    exit(1);
+                });
+        }
+        ("get-hunter-profile", arg_matches) => {
+            let hunter = SignerSource::try_get_pubkey(arg_matches, "hunter", &mut wallet_manager)
+                .unwrap()
+                .unwrap();
+            process_get_hunter_profile(&rpc_client, hunter)
+                .await
+                .unwrap_or_else(|err| {
+                    eprintln!("error: get-hunter-profile: {}", err);
+                    exit(1);
+                });
+        }
+        ("get-maker-profile", arg_matches) => {
+            let maker = SignerSource::try_get_pubkey(arg_matches, "maker", &mut wallet_manager)
+                .unwrap()
+                .unwrap();
+            process_get_maker_profile(&rpc_client, maker)
+                .await
+                .unwrap_or_else(|err| {
+                    eprintln!("error: get-maker-profile: {}", err);
+                    exit(1);
+                });
+        }
+        ("accept-submission", arg_matches) => {
+            let submission_address = SignerSource::try_get_pubkey(
+                arg_matches,
+                "submission_address",
+                &mut wallet_manager,
             )
-            .await
-            .unwrap_or_else(|err| {
-                eprintln!("error: create-bounty: {}", err);
-                exit(1);
-            });
+            .unwrap()
+            .unwrap();
+            let response =
+                process_accept_submission(&rpc_client, &config.payer, submission_address)
+                    .await
+                    .unwrap_or_else(|err| {
+                        eprintln!("error: accept-submission: {}", err);
+                        exit(1);
+                    });
             println!("{}", response);
         }
-        ("submit-solution", arg_matches) => {
-            let notes: &String = arg_matches.get_one("notes").expect("notes is missing");
+        ("approve-milestone", arg_matches) => {
+            let submission_address = SignerSource::try_get_pubkey(
+                arg_matches,
+                "submission_address",
+                &mut wallet_manager,
+            )
+            .unwrap()
+            .unwrap();
+            let index: u8 = arg_matches
+                .get_one::<String>("index")
+                .expect("index is missing")
+                .parse()
+                .expect("unable to parse to u8");
+            let response =
+                process_approve_milestone(&rpc_client, &config.payer, submission_address, index)
+                    .await
+                    .unwrap_or_else(|err| {
+                        eprintln!("error: approve-milestone: {}", err);
+                        exit(1);
+                    });
+            println!("{}", response);
+        }
+        ("update-bounty", arg_matches) => {
+            let description: &String = arg_matches
+                .get_one("description")
+                .expect("description is missing");
             let link: &String = arg_matches.get_one("link").expect("link is missing");
             let bounty_address =
                 SignerSource::try_get_pubkey(arg_matches, "bounty_address", &mut wallet_manager)
                     .unwrap()
                     .unwrap();
-            let response = process_submit_solution(
+            let content_hash = arg_matches
+                .get_one::<String>("metadata")
+                .map(|path| hash_metadata_file(path));
+            let response = process_update_bounty(
                 &rpc_client,
                 &config.payer,
                 bounty_address,
-                notes.clone(),
+                description.clone(),
                 link.clone(),
+                content_hash,
             )
             .await
             .unwrap_or_else(|err| {
-                eprintln!("error: submit-solution: {}", err);
+                eprintln!("error: update-bounty: {}", err);
                 exit(1);
             });
             println!("{}", response);
         }
-        ("get-bounty", arg_matches) => {
+        ("cancel-bounty", arg_matches) => {
             let bounty_address =
                 SignerSource::try_get_pubkey(arg_matches, "bounty_address", &mut wallet_manager)
                     .unwrap()
                     .unwrap();
-            process_get_bounty(&rpc_client, bounty_address)
+            let response = process_cancel_bounty(&rpc_client, &config.payer, bounty_address)
                 .await
                 .unwrap_or_else(|err| {
-                    eprintln!("error: get-bounty: {}", err);
+                    eprintln!("error: cancel-bounty: {}", err);
                     exit(1);
                 });
+            println!("{}", response);
         }
-        ("get-submission", arg_matches) => {
+        ("expire-bounty", arg_matches) => {
+            let bounty_address =
+                SignerSource::try_get_pubkey(arg_matches, "bounty_address", &mut wallet_manager)
+                    .unwrap()
+                    .unwrap();
+            let response = process_expire_bounty(&rpc_client, &config.payer, bounty_address)
+                .await
+                .unwrap_or_else(|err| {
+                    eprintln!("error: expire-bounty: {}", err);
+                    exit(1);
+                });
+            println!("{}", response);
+        }
+        ("open-dispute", arg_matches) => {
             let submission_address = SignerSource::try_get_pubkey(
                 arg_matches,
                 "submission_address",
@@ -724,14 +3308,15 @@ async fn main() -> Result<(), Box<dyn Error>> {
             )
             .unwrap()
             .unwrap();
-            process_get_submission(&rpc_client, submission_address)
+            let response = process_open_dispute(&rpc_client, &config.payer, submission_address)
                 .await
                 .unwrap_or_else(|err| {
-                    eprintln!("error: get-submission: {}", err);
+                    eprintln!("error: open-dispute: {}", err);
                     exit(1);
                 });
+            println!("{}", response);
         }
-        ("accept-submission", arg_matches) => {
+        ("resolve-dispute", arg_matches) => {
             let submission_address = SignerSource::try_get_pubkey(
                 arg_matches,
                 "submission_address",
@@ -739,28 +3324,208 @@ async fn main() -> Result<(), Box<dyn Error>> {
             )
             .unwrap()
             .unwrap();
+            let award_hunter = arg_matches.contains_id("award_hunter");
+            let response = process_resolve_dispute(
+                &rpc_client,
+                &config.payer,
+                submission_address,
+                award_hunter,
+            )
+            .await
+            .unwrap_or_else(|err| {
+                eprintln!("error: resolve-dispute: {}", err);
+                exit(1);
+            });
+            println!("{}", response);
+        }
+        ("add-reward-leg", arg_matches) => {
+            let bounty_address =
+                SignerSource::try_get_pubkey(arg_matches, "bounty_address", &mut wallet_manager)
+                    .unwrap()
+                    .unwrap();
+            let mint = SignerSource::try_get_pubkey(arg_matches, "mint", &mut wallet_manager)
+                .unwrap()
+                .unwrap();
+            let amount: u64 = arg_matches
+                .get_one::<String>("amount")
+                .expect("amount is missing")
+                .parse()
+                .expect("unable to parse to u64");
             let response =
-                process_accept_submission(&rpc_client, &config.payer, submission_address)
+                process_add_reward_leg(&rpc_client, &config.payer, bounty_address, mint, amount)
                     .await
                     .unwrap_or_else(|err| {
-                        eprintln!("error: accept-submission: {}", err);
+                        eprintln!("error: add-reward-leg: {}", err);
                         exit(1);
                     });
             println!("{}", response);
         }
-        ("cancel-bounty", arg_matches) => {
+        ("fund-bounty", arg_matches) => {
             let bounty_address =
                 SignerSource::try_get_pubkey(arg_matches, "bounty_address", &mut wallet_manager)
                     .unwrap()
                     .unwrap();
-            let response = process_cancel_bounty(&rpc_client, &config.payer, bounty_address)
+            let amount: u64 = arg_matches
+                .get_one::<String>("amount")
+                .expect("amount is missing")
+                .parse()
+                .expect("unable to parse to u64");
+            let response = process_fund_bounty(&rpc_client, &config.payer, bounty_address, amount)
                 .await
                 .unwrap_or_else(|err| {
-                    eprintln!("error: cancel-bounty: {}", err);
+                    eprintln!("error: fund-bounty: {}", err);
+                    exit(1);
+                });
+            println!("{}", response);
+        }
+        ("increase-reward", arg_matches) => {
+            let bounty_address =
+                SignerSource::try_get_pubkey(arg_matches, "bounty_address", &mut wallet_manager)
+                    .unwrap()
+                    .unwrap();
+            let amount: u64 = arg_matches
+                .get_one::<String>("amount")
+                .expect("amount is missing")
+                .parse()
+                .expect("unable to parse to u64");
+            let response =
+                process_increase_reward(&rpc_client, &config.payer, bounty_address, amount)
+                    .await
+                    .unwrap_or_else(|err| {
+                        eprintln!("error: increase-reward: {}", err);
+                        exit(1);
+                    });
+            println!("{}", response);
+        }
+        ("decrease-reward", arg_matches) => {
+            let bounty_address =
+                SignerSource::try_get_pubkey(arg_matches, "bounty_address", &mut wallet_manager)
+                    .unwrap()
+                    .unwrap();
+            let amount: u64 = arg_matches
+                .get_one::<String>("amount")
+                .expect("amount is missing")
+                .parse()
+                .expect("unable to parse to u64");
+            let response =
+                process_decrease_reward(&rpc_client, &config.payer, bounty_address, amount)
+                    .await
+                    .unwrap_or_else(|err| {
+                        eprintln!("error: decrease-reward: {}", err);
+                        exit(1);
+                    });
+            println!("{}", response);
+        }
+        ("refund-contribution", arg_matches) => {
+            let bounty_address =
+                SignerSource::try_get_pubkey(arg_matches, "bounty_address", &mut wallet_manager)
+                    .unwrap()
+                    .unwrap();
+            let funder = SignerSource::try_get_pubkey(arg_matches, "funder", &mut wallet_manager)
+                .unwrap()
+                .unwrap_or_else(|| config.payer.pubkey());
+            let response =
+                process_refund_contribution(&rpc_client, &config.payer, bounty_address, funder)
+                    .await
+                    .unwrap_or_else(|err| {
+                        eprintln!("error: refund-contribution: {}", err);
+                        exit(1);
+                    });
+            println!("{}", response);
+        }
+        ("sweep-excess", arg_matches) => {
+            let bounty_address =
+                SignerSource::try_get_pubkey(arg_matches, "bounty_address", &mut wallet_manager)
+                    .unwrap()
+                    .unwrap();
+            let response = process_sweep_excess(&rpc_client, &config.payer, bounty_address)
+                .await
+                .unwrap_or_else(|err| {
+                    eprintln!("error: sweep-excess: {}", err);
+                    exit(1);
+                });
+            println!("{}", response);
+        }
+        ("close-bounty", arg_matches) => {
+            let bounty_address =
+                SignerSource::try_get_pubkey(arg_matches, "bounty_address", &mut wallet_manager)
+                    .unwrap()
+                    .unwrap();
+            let response = process_close_bounty(&rpc_client, &config.payer, bounty_address)
+                .await
+                .unwrap_or_else(|err| {
+                    eprintln!("error: close-bounty: {}", err);
                     exit(1);
                 });
             println!("{}", response);
         }
+        ("initialize-config", arg_matches) => {
+            let fee_bps: u16 = arg_matches
+                .get_one::<String>("fee_bps")
+                .expect("fee_bps is missing")
+                .parse()
+                .expect("unable to parse to u16");
+            let treasury =
+                SignerSource::try_get_pubkey(arg_matches, "treasury", &mut wallet_manager)
+                    .unwrap()
+                    .unwrap();
+            let response = process_initialize_config(&rpc_client, &config.payer, fee_bps, treasury)
+                .await
+                .unwrap_or_else(|err| {
+                    eprintln!("error: initialize-config: {}", err);
+                    exit(1);
+                });
+            println!("{}", response);
+        }
+        ("update-config", arg_matches) => {
+            let admin =
+                SignerSource::try_get_pubkey(arg_matches, "admin", &mut wallet_manager).unwrap();
+            let fee_bps: Option<u16> = arg_matches
+                .get_one::<String>("fee_bps")
+                .map(|fee_bps| fee_bps.parse().expect("unable to parse to u16"));
+            let treasury =
+                SignerSource::try_get_pubkey(arg_matches, "treasury", &mut wallet_manager).unwrap();
+            let response =
+                process_update_config(&rpc_client, &config.payer, admin, fee_bps, treasury)
+                    .await
+                    .unwrap_or_else(|err| {
+                        eprintln!("error: update-config: {}", err);
+                        exit(1);
+                    });
+            println!("{}", response);
+        }
+        ("get-config", _arg_matches) => {
+            process_get_config(&rpc_client).await.unwrap_or_else(|err| {
+                eprintln!("error: get-config: {}", err);
+                exit(1);
+            });
+        }
+        ("set-min-reward", arg_matches) => {
+            let amount: u64 = arg_matches
+                .get_one::<String>("amount")
+                .expect("amount is missing")
+                .parse()
+                .expect("unable to parse to u64");
+            let mint =
+                SignerSource::try_get_pubkey(arg_matches, "mint", &mut wallet_manager).unwrap();
+            let response = process_set_min_reward(&rpc_client, &config.payer, mint, amount)
+                .await
+                .unwrap_or_else(|err| {
+                    eprintln!("error: set-min-reward: {}", err);
+                    exit(1);
+                });
+            println!("{}", response);
+        }
+        ("get-min-reward", arg_matches) => {
+            let mint =
+                SignerSource::try_get_pubkey(arg_matches, "mint", &mut wallet_manager).unwrap();
+            process_get_min_reward(&rpc_client, mint)
+                .await
+                .unwrap_or_else(|err| {
+                    eprintln!("error: get-min-reward: {}", err);
+                    exit(1);
+                });
+        }
         ("get-all-bounties", _arg_matches) => {
             process_get_all_bounties(&rpc_client)
                 .await
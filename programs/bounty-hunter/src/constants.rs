@@ -2,3 +2,41 @@ use anchor_lang::prelude::*;
 
 #[constant]
 pub const SEED: &str = "anchor";
+
+/// maximum number of prize tiers (and therefore winners) a bounty can have
+#[constant]
+pub const MAX_WINNERS: usize = 5;
+
+#[constant]
+pub const BPS_DENOMINATOR: u64 = 10_000;
+
+/// highest protocol fee the admin can set, a fee change also applies to bounties already funded
+#[constant]
+pub const MAX_FEE_BPS: u16 = 1_000;
+
+#[constant]
+pub const MAX_MILESTONES: usize = 5;
+
+#[constant]
+pub const MAX_MILESTONE_DESCRIPTION_LEN: usize = 64;
+
+/// mints a bounty can pay out on top of its main reward
+#[constant]
+pub const MAX_REWARD_LEGS: usize = 3;
+
+/// hunters a private bounty can list inline, longer lists go through a Merkle root
+#[constant]
+pub const MAX_ALLOWED_HUNTERS: usize = 10;
+
+/// mints a `HunterProfile` keeps earnings for
+#[constant]
+pub const MAX_PROFILE_MINTS: usize = 10;
+
+#[constant]
+pub const MAX_DESCRIPTION_LEN: usize = 1024;
+
+#[constant]
+pub const MAX_LINK_LEN: usize = 100;
+
+#[constant]
+pub const MAX_NOTES_LEN: usize = 1024;
@@ -10,4 +10,110 @@ pub enum BountyHunterErrors {
     BountyClosed,
     #[msg("Wrong mint")]
     InvalidMint,
+    #[msg("Deadline must be in the future")]
+    DeadlineInPast,
+    #[msg("Bounty Expired")]
+    BountyExpired,
+    #[msg("Bounty Not Expired")]
+    BountyNotExpired,
+    #[msg("Bounty already has submissions")]
+    BountyHasSubmissions,
+    #[msg("Prize splits must be non-zero and add up to 10000 basis points")]
+    InvalidPrizeSplit,
+    #[msg("Submission Already Accepted")]
+    SubmissionAlreadyAccepted,
+    #[msg("Token accounts are required for bounties with a mint")]
+    MissingTokenAccounts,
+    #[msg("Milestones must have a description and add up to the reward")]
+    InvalidMilestones,
+    #[msg("Milestone bounties can't have prize tiers")]
+    MilestonesWithPrizeTiers,
+    #[msg("Bounty is paid through milestones")]
+    BountyHasMilestones,
+    #[msg("Invalid Milestone")]
+    InvalidMilestone,
+    #[msg("Milestone Already Approved")]
+    MilestoneAlreadyApproved,
+    #[msg("Bounty is not being refunded")]
+    BountyNotRefunding,
+    #[msg("Invalid Contribution")]
+    InvalidContribution,
+    #[msg("Nothing to sweep")]
+    NothingToSweep,
+    #[msg("Invalid Config authority")]
+    InvalidConfigAuthority,
+    #[msg("Fee can't be more than 1000 basis points")]
+    InvalidFee,
+    #[msg("Wrong treasury")]
+    InvalidTreasury,
+    #[msg("Bounty has no arbiter")]
+    NoArbiter,
+    #[msg("Invalid Arbiter")]
+    InvalidArbiter,
+    #[msg("Bounty already has an open dispute")]
+    DisputeAlreadyOpen,
+    #[msg("Bounty is under dispute")]
+    BountyDisputed,
+    #[msg("Submission is not under dispute")]
+    NotDisputed,
+    #[msg("Bounty can't move to that status")]
+    InvalidStatusTransition,
+    #[msg("Submission can no longer be edited")]
+    SubmissionLocked,
+    #[msg("Bounty is still open")]
+    BountyStillOpen,
+    #[msg("Bounty still holds escrowed funds")]
+    BountyHasEscrow,
+    #[msg("Bounty still has contributions to refund")]
+    BountyHasContributions,
+    #[msg("A receipt account is required to pay the hunter")]
+    MissingReceipt,
+    #[msg("Mint has a Token-2022 extension bounties can't hold")]
+    UnsupportedMint,
+    #[msg("Recipient token account requires a memo, pass the memo program")]
+    MissingMemoProgram,
+    #[msg("Too many hunters in the allow-list")]
+    TooManyAllowedHunters,
+    #[msg("Hunter is not allowed to submit to this bounty")]
+    HunterNotAllowed,
+    #[msg("A hunter profile account is required to pay the hunter")]
+    MissingHunterProfile,
+    #[msg("Bounty already has the maximum number of reward legs")]
+    TooManyRewardLegs,
+    #[msg("Mint is already paid out by this bounty")]
+    DuplicateRewardMint,
+    #[msg("Reward legs can't be added to bounties with milestones or payouts")]
+    RewardLegsLocked,
+    #[msg("Pass the accounts of every reward leg in the remaining accounts")]
+    MissingRewardLegAccounts,
+    #[msg("Invalid reward leg account")]
+    InvalidRewardLegAccount,
+    #[msg("Reward can only be decreased by less than the maker's reward")]
+    InvalidRewardDecrease,
+    #[msg("Description is too long")]
+    DescriptionTooLong,
+    #[msg("Link is too long")]
+    LinkTooLong,
+    #[msg("Bounty terms can't change once a submission has been paid")]
+    BountyHasPayouts,
+    #[msg("Notes are too long")]
+    NotesTooLong,
+    #[msg("Link is empty")]
+    EmptyLink,
+    #[msg("Link must start with https:// or http://")]
+    InvalidLinkScheme,
+    #[msg("Reward must be greater than zero")]
+    ZeroReward,
+    #[msg("Reward is below the minimum set for its mint")]
+    RewardBelowMinimum,
+    #[msg("Contribution must be greater than zero")]
+    ZeroContribution,
+    #[msg("Submission was rejected by the arbiter")]
+    SubmissionRejected,
+    #[msg("Submission must be in review before it can be accepted")]
+    SubmissionNotInReview,
+    #[msg("Bounty has no submission bond to slash")]
+    NoSubmissionBond,
+    #[msg("Vault is not the bounty's token account")]
+    InvalidVault,
 }
@@ -0,0 +1,241 @@
+//! Moves the main reward out of a bounty, from the bounty account's own lamports on native SOL
+//! bounties or from its vault on token bounties. Every payout, refund, sweep and vault close goes
+//! through here, signed by the bounty PDA
+
+use anchor_lang::prelude::*;
+use anchor_spl::memo::Memo;
+use anchor_spl::token_interface::{
+    close_account, transfer_checked, CloseAccount, Mint, TokenAccount, TokenInterface,
+    TransferChecked,
+};
+
+use crate::{
+    error::BountyHunterErrors,
+    extensions,
+    state::{Bounty, Config},
+};
+
+/// the token side of a token bounty
+pub struct Vault<'a, 'info> {
+    pub vault: &'a InterfaceAccount<'info, TokenAccount>,
+    pub mint: &'a InterfaceAccount<'info, Mint>,
+    pub token_program: &'a Interface<'info, TokenInterface>,
+    pub memo_program: &'a Option<Program<'info, Memo>>,
+}
+
+impl<'a, 'info> Vault<'a, 'info> {
+    /// `None` for native SOL bounties, a token bounty needs every account
+    pub fn of(
+        bounty: &Bounty,
+        vault: &'a Option<InterfaceAccount<'info, TokenAccount>>,
+        mint: &'a Option<InterfaceAccount<'info, Mint>>,
+        token_program: &'a Option<Interface<'info, TokenInterface>>,
+        memo_program: &'a Option<Program<'info, Memo>>,
+    ) -> Result<Option<Self>> {
+        if bounty.is_native() {
+            return Ok(None);
+        }
+        let (Some(vault), Some(mint), Some(token_program)) = (vault, mint, token_program) else {
+            return err!(BountyHunterErrors::MissingTokenAccounts);
+        };
+        Ok(Some(Self {
+            vault,
+            mint,
+            token_program,
+            memo_program,
+        }))
+    }
+}
+
+/// who a transfer goes to: the wallet itself on native SOL bounties, its token account otherwise
+#[derive(Clone, Copy)]
+pub struct Recipient<'a, 'info> {
+    pub wallet: &'a AccountInfo<'info>,
+    pub token_account: Option<&'a InterfaceAccount<'info, TokenAccount>>,
+}
+
+/// pays `amount` to the hunter less the protocol fee, which goes to the treasury. The Token-2022
+/// transfer fee is taken from the fee reserve so the hunter gets the payout in full. The vault is
+/// closed with the last payout, unless excess or an unused fee reserve keeps it open until the
+/// maker sweeps it
+pub fn pay<'info>(
+    bounty: &mut Account<'info, Bounty>,
+    vault: Option<&Vault<'_, 'info>>,
+    config: &Config,
+    amount: u64,
+    hunter: Recipient<'_, 'info>,
+    treasury: Recipient<'_, 'info>,
+    maker: &AccountInfo<'info>,
+) -> Result<()> {
+    let fee = config.fee(amount)?;
+    let payout = amount - fee;
+
+    let Some(vault) = vault else {
+        bounty.sub_lamports(amount)?;
+        hunter.wallet.add_lamports(payout)?;
+        treasury.wallet.add_lamports(fee)?;
+        return Ok(());
+    };
+
+    let top_up = (extensions::gross_amount(vault.mint, payout)? - payout).min(bounty.fee_reserve);
+    bounty.fee_reserve -= top_up;
+
+    send(bounty, vault, hunter, payout + top_up)?;
+    if fee > 0 {
+        send(bounty, vault, treasury, fee)?;
+    }
+
+    if bounty.is_completed() && vault.vault.amount <= amount + top_up {
+        close_vault(bounty, vault, maker)?;
+    }
+    Ok(())
+}
+
+/// sends `amount` of the escrow to `to`
+pub fn refund<'info>(
+    bounty: &Account<'info, Bounty>,
+    vault: Option<&Vault<'_, 'info>>,
+    amount: u64,
+    to: Recipient<'_, 'info>,
+) -> Result<()> {
+    let Some(vault) = vault else {
+        bounty.sub_lamports(amount)?;
+        to.wallet.add_lamports(amount)?;
+        return Ok(());
+    };
+    send(bounty, vault, to, amount)
+}
+
+/// refunds a cancelled or expired bounty and returns the maker's part. Without crowdfunding
+/// everything left goes back to the maker, excess and fee reserve included. Otherwise the maker
+/// takes its pro rata share now and funders claim theirs with `refund_contribution`
+pub fn refund_maker<'info>(
+    bounty: &mut Account<'info, Bounty>,
+    vault: Option<&Vault<'_, 'info>>,
+    maker: Recipient<'_, 'info>,
+) -> Result<u64> {
+    if bounty.contributors == 0 {
+        let escrowed = bounty.escrowed;
+        bounty.release(escrowed)?;
+        bounty.fee_reserve = 0;
+
+        let Some(vault) = vault else {
+            // the bounty account is kept for its status, only its rent stays behind
+            let info = bounty.to_account_info();
+            let rent = Rent::get()?.minimum_balance(info.data_len());
+            let amount = info.lamports().saturating_sub(rent);
+            refund(bounty, None, amount, maker)?;
+            return Ok(amount);
+        };
+
+        let amount = vault.vault.amount;
+        refund(bounty, Some(vault), amount, maker)?;
+        close_vault(bounty, vault, maker.wallet)?;
+        return Ok(amount);
+    }
+
+    let pool = bounty.escrowed;
+    let maker_share = bounty.refund_share(pool, bounty.reward)?;
+    bounty.refund_pool = Some(pool);
+    bounty.release(maker_share)?;
+
+    refund(bounty, vault, maker_share, maker)?;
+    Ok(maker_share)
+}
+
+/// closes the vault to the maker, Token-2022 withheld fees are harvested to the mint first
+pub fn close_vault<'info>(
+    bounty: &Account<'info, Bounty>,
+    vault: &Vault<'_, 'info>,
+    maker: &AccountInfo<'info>,
+) -> Result<()> {
+    extensions::harvest_withheld(vault.token_program, vault.mint, vault.vault)?;
+    close(
+        bounty,
+        &vault.token_program.to_account_info(),
+        &vault.vault.to_account_info(),
+        maker,
+    )
+}
+
+fn send<'info>(
+    bounty: &Account<'info, Bounty>,
+    vault: &Vault<'_, 'info>,
+    to: Recipient<'_, 'info>,
+    amount: u64,
+) -> Result<()> {
+    let Some(token_account) = to.token_account else {
+        return err!(BountyHunterErrors::MissingTokenAccounts);
+    };
+
+    extensions::memo_if_required(vault.memo_program, token_account)?;
+    transfer(
+        bounty,
+        &vault.token_program.to_account_info(),
+        &vault.mint.to_account_info(),
+        &vault.vault.to_account_info(),
+        &token_account.to_account_info(),
+        amount,
+        vault.mint.decimals,
+    )
+}
+
+/// `transfer_checked` out of a token account owned by the bounty
+pub fn transfer<'info>(
+    bounty: &Account<'info, Bounty>,
+    token_program: &AccountInfo<'info>,
+    mint: &AccountInfo<'info>,
+    from: &AccountInfo<'info>,
+    to: &AccountInfo<'info>,
+    amount: u64,
+    decimals: u8,
+) -> Result<()> {
+    let bounty_seeds = [
+        b"bounty",
+        bounty.maker.as_ref(),
+        &bounty.seed.to_le_bytes(),
+        &[bounty.bump],
+    ];
+    let signer_seeds = [bounty_seeds.as_ref()];
+
+    transfer_checked(
+        CpiContext::new_with_signer(
+            token_program.clone(),
+            TransferChecked {
+                from: from.clone(),
+                mint: mint.clone(),
+                to: to.clone(),
+                authority: bounty.to_account_info(),
+            },
+            signer_seeds.as_ref(),
+        ),
+        amount,
+        decimals,
+    )
+}
+
+/// closes a token account owned by the bounty, its rent goes to `destination`
+pub fn close<'info>(
+    bounty: &Account<'info, Bounty>,
+    token_program: &AccountInfo<'info>,
+    account: &AccountInfo<'info>,
+    destination: &AccountInfo<'info>,
+) -> Result<()> {
+    let bounty_seeds = [
+        b"bounty",
+        bounty.maker.as_ref(),
+        &bounty.seed.to_le_bytes(),
+        &[bounty.bump],
+    ];
+    let signer_seeds = [bounty_seeds.as_ref()];
+
+    close_account(CpiContext::new_with_signer(
+        token_program.clone(),
+        CloseAccount {
+            account: account.clone(),
+            destination: destination.clone(),
+            authority: bounty.to_account_info(),
+        },
+        signer_seeds.as_ref(),
+    ))
+}
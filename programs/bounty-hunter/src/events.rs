@@ -0,0 +1,194 @@
+//! Emitted through `emit_cpi!` so indexers can follow the program without polling accounts,
+//! `mint` is `Pubkey::default()` for native SOL bounties
+
+use anchor_lang::prelude::*;
+
+#[event]
+pub struct BountyCreated {
+    pub bounty: Pubkey,
+    pub maker: Pubkey,
+    pub mint: Pubkey,
+    pub reward: u64,
+    pub deadline: Option<i64>,
+}
+
+#[event]
+pub struct RewardLegAdded {
+    pub bounty: Pubkey,
+    pub maker: Pubkey,
+    pub mint: Pubkey,
+    pub amount: u64,
+}
+
+#[event]
+pub struct BountyUpdated {
+    pub bounty: Pubkey,
+    pub maker: Pubkey,
+    pub edits: u32,
+}
+
+/// `reward` is the maker's reward after the change
+#[event]
+pub struct RewardIncreased {
+    pub bounty: Pubkey,
+    pub maker: Pubkey,
+    pub mint: Pubkey,
+    pub amount: u64,
+    pub reward: u64,
+}
+
+/// `reward` is the maker's reward after the change
+#[event]
+pub struct RewardDecreased {
+    pub bounty: Pubkey,
+    pub maker: Pubkey,
+    pub mint: Pubkey,
+    pub amount: u64,
+    pub reward: u64,
+}
+
+#[event]
+pub struct SolutionSubmitted {
+    pub bounty: Pubkey,
+    pub submission: Pubkey,
+    pub hunter: Pubkey,
+}
+
+#[event]
+pub struct SubmissionEdited {
+    pub bounty: Pubkey,
+    pub submission: Pubkey,
+    pub hunter: Pubkey,
+    pub edits: u32,
+}
+
+#[event]
+pub struct SubmissionReviewed {
+    pub bounty: Pubkey,
+    pub submission: Pubkey,
+}
+
+#[event]
+pub struct SubmissionWithdrawn {
+    pub bounty: Pubkey,
+    pub submission: Pubkey,
+    pub hunter: Pubkey,
+}
+
+/// `bond` went to the treasury
+#[event]
+pub struct SubmissionSlashed {
+    pub bounty: Pubkey,
+    pub submission: Pubkey,
+    pub hunter: Pubkey,
+    pub bond: u64,
+}
+
+#[event]
+pub struct SubmissionClosed {
+    pub bounty: Pubkey,
+    pub submission: Pubkey,
+    pub hunter: Pubkey,
+}
+
+/// `amount` is what left the escrow, the hunter received `amount - fee`
+#[event]
+pub struct SolutionAccepted {
+    pub bounty: Pubkey,
+    pub submission: Pubkey,
+    pub hunter: Pubkey,
+    pub mint: Pubkey,
+    pub amount: u64,
+    pub fee: u64,
+}
+
+#[event]
+pub struct MilestoneApproved {
+    pub bounty: Pubkey,
+    pub submission: Pubkey,
+    pub hunter: Pubkey,
+    pub mint: Pubkey,
+    pub index: u8,
+    pub amount: u64,
+    pub fee: u64,
+}
+
+/// `refunded` is what the maker got back right away, funders claim the rest with `refund_contribution`
+#[event]
+pub struct BountyCancelled {
+    pub bounty: Pubkey,
+    pub maker: Pubkey,
+    pub mint: Pubkey,
+    pub refunded: u64,
+}
+
+#[event]
+pub struct BountyExpired {
+    pub bounty: Pubkey,
+    pub maker: Pubkey,
+    pub mint: Pubkey,
+    pub refunded: u64,
+}
+
+/// `swept` is the token excess returned with the vault, native excess leaves with the bounty rent
+#[event]
+pub struct BountyClosed {
+    pub bounty: Pubkey,
+    pub maker: Pubkey,
+    pub mint: Pubkey,
+    pub swept: u64,
+}
+
+#[event]
+pub struct BountyFunded {
+    pub bounty: Pubkey,
+    pub funder: Pubkey,
+    pub mint: Pubkey,
+    pub amount: u64,
+}
+
+#[event]
+pub struct ContributionRefunded {
+    pub bounty: Pubkey,
+    pub funder: Pubkey,
+    pub mint: Pubkey,
+    pub amount: u64,
+}
+
+#[event]
+pub struct ExcessSwept {
+    pub bounty: Pubkey,
+    pub maker: Pubkey,
+    pub mint: Pubkey,
+    pub amount: u64,
+}
+
+#[event]
+pub struct DisputeOpened {
+    pub bounty: Pubkey,
+    pub submission: Pubkey,
+    pub hunter: Pubkey,
+}
+
+/// `amount` is the payout to the hunter, zero when `award_hunter` is false
+#[event]
+pub struct DisputeResolved {
+    pub bounty: Pubkey,
+    pub submission: Pubkey,
+    pub arbiter: Pubkey,
+    pub award_hunter: bool,
+    pub amount: u64,
+}
+
+#[event]
+pub struct ConfigUpdated {
+    pub admin: Pubkey,
+    pub fee_bps: u16,
+    pub treasury: Pubkey,
+}
+
+#[event]
+pub struct MinRewardSet {
+    pub mint: Pubkey,
+    pub amount: u64,
+}
@@ -0,0 +1,136 @@
+//! Token-2022 extension handling shared by the instructions that move tokens, every helper is a
+//! no-op for legacy SPL Token mints and accounts
+
+use anchor_lang::prelude::*;
+use anchor_spl::memo::{build_memo, BuildMemo, Memo};
+use anchor_spl::token_2022::spl_token_2022::{
+    self,
+    extension::{
+        default_account_state::DefaultAccountState,
+        memo_transfer::memo_required,
+        permanent_delegate::PermanentDelegate,
+        transfer_fee::{TransferFeeAmount, TransferFeeConfig},
+        transfer_hook::TransferHook,
+        BaseStateWithExtensions, ExtensionType, StateWithExtensions,
+    },
+    state::AccountState,
+};
+use anchor_spl::token_2022_extensions::transfer_fee::{
+    harvest_withheld_tokens_to_mint, HarvestWithheldTokensToMint,
+};
+use anchor_spl::token_interface::{Mint, TokenInterface};
+
+use crate::error::BountyHunterErrors;
+
+/// rejects mints whose tokens could get stuck in, or be taken out of, a bounty vault
+pub fn check_mint(mint: &InterfaceAccount<Mint>) -> Result<()> {
+    let info = mint.to_account_info();
+    if info.owner != &spl_token_2022::ID {
+        return Ok(());
+    }
+    let data = info.try_borrow_data()?;
+    let mint = StateWithExtensions::<spl_token_2022::state::Mint>::unpack(&data)?;
+
+    for extension in mint.get_extension_types()? {
+        let supported = match extension {
+            // can never leave the vault
+            ExtensionType::NonTransferable => false,
+            // its holder can move tokens out of the vault without the bounty signing
+            ExtensionType::PermanentDelegate => mint
+                .get_extension::<PermanentDelegate>()
+                .map(|delegate| Option::<Pubkey>::from(delegate.delegate).is_none())?,
+            // hooks need extra accounts the instructions don't pass
+            ExtensionType::TransferHook => mint
+                .get_extension::<TransferHook>()
+                .map(|hook| Option::<Pubkey>::from(hook.program_id).is_none())?,
+            // the vault would be created frozen
+            ExtensionType::DefaultAccountState => mint
+                .get_extension::<DefaultAccountState>()
+                .map(|default| default.state != AccountState::Frozen as u8)?,
+            _ => true,
+        };
+        require!(supported, BountyHunterErrors::UnsupportedMint);
+    }
+    Ok(())
+}
+
+/// what has to be sent for the recipient to end up with `amount` once the transfer fee is withheld
+pub fn gross_amount(mint: &InterfaceAccount<Mint>, amount: u64) -> Result<u64> {
+    let info = mint.to_account_info();
+    if info.owner != &spl_token_2022::ID {
+        return Ok(amount);
+    }
+    let data = info.try_borrow_data()?;
+    let mint = StateWithExtensions::<spl_token_2022::state::Mint>::unpack(&data)?;
+    let Ok(config) = mint.get_extension::<TransferFeeConfig>() else {
+        return Ok(amount);
+    };
+
+    let gross = config
+        .get_epoch_fee(Clock::get()?.epoch)
+        .calculate_pre_fee_amount(amount)
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+    Ok(gross)
+}
+
+/// token accounts with `MemoTransfer` refuse incoming transfers unless a memo comes right before
+pub fn memo_if_required<'info>(
+    memo_program: &Option<Program<'info, Memo>>,
+    to: &impl ToAccountInfo<'info>,
+) -> Result<()> {
+    let info = to.to_account_info();
+    if info.owner != &spl_token_2022::ID {
+        return Ok(());
+    }
+    let required = {
+        let data = info.try_borrow_data()?;
+        let account = StateWithExtensions::<spl_token_2022::state::Account>::unpack(&data)?;
+        memo_required(&account)
+    };
+    if !required {
+        return Ok(());
+    }
+
+    let Some(memo_program) = memo_program else {
+        return err!(BountyHunterErrors::MissingMemoProgram);
+    };
+    build_memo(
+        CpiContext::new(memo_program.to_account_info(), BuildMemo {}),
+        b"bounty-hunter",
+    )
+}
+
+/// Token-2022 won't close an account still holding withheld transfer fees, harvesting them to the
+/// mint is permissionless
+pub fn harvest_withheld<'info>(
+    token_program: &Interface<'info, TokenInterface>,
+    mint: &InterfaceAccount<'info, Mint>,
+    vault: &impl ToAccountInfo<'info>,
+) -> Result<()> {
+    let info = vault.to_account_info();
+    if info.owner != &spl_token_2022::ID {
+        return Ok(());
+    }
+    let withheld = {
+        let data = info.try_borrow_data()?;
+        let account = StateWithExtensions::<spl_token_2022::state::Account>::unpack(&data)?;
+        account
+            .get_extension::<TransferFeeAmount>()
+            .map(|fee| u64::from(fee.withheld_amount))
+            .unwrap_or_default()
+    };
+    if withheld == 0 {
+        return Ok(());
+    }
+
+    harvest_withheld_tokens_to_mint(
+        CpiContext::new(
+            token_program.to_account_info(),
+            HarvestWithheldTokensToMint {
+                token_program_id: token_program.to_account_info(),
+                mint: mint.to_account_info(),
+            },
+        ),
+        vec![info],
+    )
+}
@@ -1,14 +1,19 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token::{close_account, transfer_checked, CloseAccount, TransferChecked};
+use anchor_spl::memo::Memo;
 use anchor_spl::token_interface::Mint;
 use anchor_spl::token_interface::TokenAccount;
 use anchor_spl::{associated_token::AssociatedToken, token_interface::TokenInterface};
 
 use crate::{
     error::BountyHunterErrors,
-    state::{Bounty, Submission},
+    escrow,
+    events::SolutionAccepted,
+    reward_legs,
+    state::{Bounty, Config, HunterProfile, MakerProfile, Receipt, Submission, SubmissionStatus},
 };
 
+/// Reward legs are paid from the remaining accounts, see `reward_legs::PAYOUT_ACCOUNTS`
+#[event_cpi]
 #[derive(Accounts)]
 pub struct AcceptSolution<'info> {
     #[account(mut)]
@@ -16,83 +21,181 @@ pub struct AcceptSolution<'info> {
     #[account(
         mut,
         has_one = maker @ BountyHunterErrors::InvalidBountyAuthority,      //alternativa constraint = bounty.maker == maker.key() @ BountyHunterErrors::InvalidBountyAuthority
-        has_one = mint @ BountyHunterErrors::InvalidMint,
-        constraint = bounty.accepted_submission == Pubkey::default() @ BountyHunterErrors::BountyClosed
+        constraint = bounty.mint == mint.as_ref().map(|mint| mint.key()).unwrap_or_default() @ BountyHunterErrors::InvalidMint,
+        constraint = bounty.is_open() @ BountyHunterErrors::BountyClosed,
+        constraint = bounty.milestones.is_empty() @ BountyHunterErrors::BountyHasMilestones,
+        constraint = !bounty.is_expired(Clock::get()?.unix_timestamp) @ BountyHunterErrors::BountyExpired
     )]
     pub bounty: Account<'info, Bounty>,
     #[account(
+        mut,
         has_one = bounty @ BountyHunterErrors::BountyAndSubmissionMismatch, //only works for pubkeys
         //alternativa constraint = submission.bounty == bounty.key() @ BountyHunterErrors::BountyAndSubmissionMismatch
+        has_one = hunter @ BountyHunterErrors::BountyAndSubmissionMismatch,
+        constraint = submission.status == SubmissionStatus::InReview @ BountyHunterErrors::SubmissionNotInReview,
+        constraint = !bounty.accepted_submissions.contains(&submission.key()) @ BountyHunterErrors::SubmissionAlreadyAccepted
     )]
     pub submission: Account<'info, Submission>,
+    #[account(
+        init,
+        payer = maker,
+        space = Receipt::INIT_SPACE + Receipt::DISCRIMINATOR.len(),
+        seeds = [b"receipt", bounty.key().as_ref(), &[bounty.payout_index()]],
+        bump,
+    )]
+    pub receipt: Account<'info, Receipt>,
+    #[account(
+        mut,
+        seeds = [b"maker_profile", maker.key().as_ref()],
+        bump = maker_profile.bump,
+    )]
+    pub maker_profile: Account<'info, MakerProfile>,
+    // created at the maker's expense on the hunter's first win
+    #[account(
+        init_if_needed,
+        payer = maker,
+        space = HunterProfile::INIT_SPACE + HunterProfile::DISCRIMINATOR.len(),
+        seeds = [b"hunter_profile", hunter.key().as_ref()],
+        bump,
+    )]
+    pub hunter_profile: Account<'info, HunterProfile>,
     #[account(
         mut,
         associated_token::mint = mint,
         associated_token::authority = bounty,
         associated_token::token_program = token_program,
     )]
-    pub vault: InterfaceAccount<'info, TokenAccount>,
+    pub vault: Option<InterfaceAccount<'info, TokenAccount>>,
 
+    #[account(mut)]
     pub hunter: SystemAccount<'info>,
 
-    pub mint: InterfaceAccount<'info, Mint>,
+    // writable so Token-2022 withheld fees can be harvested to it before the vault is closed
+    #[account(mut)]
+    pub mint: Option<InterfaceAccount<'info, Mint>>,
 
+    // created at the maker's expense when the hunter has never held the mint
     #[account(
-        mut,
+        init_if_needed,
+        payer = maker,
         associated_token::mint = mint,
         associated_token::authority = hunter,
         associated_token::token_program = token_program,
     )]
-    pub hunter_token_account: InterfaceAccount<'info, TokenAccount>,
+    pub hunter_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        mut,
+        address = config.treasury @ BountyHunterErrors::InvalidTreasury,
+    )]
+    pub treasury: SystemAccount<'info>,
+
+    // only needed while a fee is charged, created at the maker's expense the first time a mint pays out
+    #[account(
+        init_if_needed,
+        payer = maker,
+        associated_token::mint = mint,
+        associated_token::authority = treasury,
+        associated_token::token_program = token_program,
+    )]
+    pub treasury_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
 
-    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
 
-    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+
+    pub associated_token_program: Option<Program<'info, AssociatedToken>>,
+
+    // only needed when a recipient token account requires memos on incoming transfers
+    pub memo_program: Option<Program<'info, Memo>>,
 }
 
-impl AcceptSolution<'_> {
-    pub fn handler(ctx: Context<AcceptSolution>) -> Result<()> {
+impl<'info> AcceptSolution<'info> {
+    pub fn handler(ctx: Context<'_, '_, '_, 'info, AcceptSolution<'info>>) -> Result<()> {
         //alternativas 'as de cima:
         //require!(ctx.accounts.bounty.maker == ctx.accounts.maker.key(), BountyHunterErrors::InvalidBountyAuthority);
         //require!(ctx.accounts.submission.bounty == ctx.accounts.bounty.key(), BountyHunterErrors::BountyAndSubmissionMismatch);
 
-        ctx.accounts.bounty.accepted_submission = ctx.accounts.submission.key();
-        ctx.accounts.transfer_reward()?;
+        let prize = ctx.accounts.bounty.next_prize()?;
+        ctx.accounts.bounty.release(prize)?;
+        let submission = ctx.accounts.submission.key();
+        ctx.accounts.receipt.set_inner(Receipt {
+            bounty: ctx.accounts.bounty.key(),
+            submission,
+            hunter: ctx.accounts.hunter.key(),
+            mint: ctx.accounts.bounty.mint,
+            index: ctx.accounts.bounty.payout_index(),
+            amount: prize,
+            fee: ctx.accounts.config.fee(prize)?,
+            paid_at: Clock::get()?.unix_timestamp,
+            bump: ctx.bumps.receipt,
+        });
+        ctx.accounts.maker_profile.payouts += 1;
+        ctx.accounts.hunter_profile.hunter = ctx.accounts.hunter.key();
+        ctx.accounts.hunter_profile.bump = ctx.bumps.hunter_profile;
+        ctx.accounts.hunter_profile.record_win(
+            ctx.accounts.bounty.mint,
+            prize - ctx.accounts.config.fee(prize)?,
+            Clock::get()?.unix_timestamp,
+        );
+        // paid before the prize tier moves on, every leg is split like the main reward
+        let maker = ctx.accounts.maker.to_account_info();
+        reward_legs::pay(
+            &mut ctx.accounts.bounty,
+            ctx.remaining_accounts,
+            ctx.accounts.hunter.key,
+            &mut ctx.accounts.hunter_profile,
+            &ctx.accounts.config,
+            &maker,
+            &ctx.accounts.memo_program,
+        )?;
+        ctx.accounts.bounty.accepted_submissions.push(submission);
+        ctx.accounts.submission.status = SubmissionStatus::Accepted;
+        // the maker gave in, nothing left for the arbiter to settle
+        if ctx.accounts.bounty.dispute == Some(submission) {
+            ctx.accounts.bounty.dispute = None;
+        }
+        let status = ctx.accounts.bounty.status_after_payout();
+        ctx.accounts.bounty.transition(status)?;
+        ctx.accounts.transfer_reward(prize)?;
+
+        emit_cpi!(SolutionAccepted {
+            bounty: ctx.accounts.bounty.key(),
+            submission,
+            hunter: ctx.accounts.hunter.key(),
+            mint: ctx.accounts.bounty.mint,
+            amount: prize,
+            fee: ctx.accounts.config.fee(prize)?,
+        });
         Ok(())
     }
 
-    pub fn transfer_reward(&self) -> Result<()> {
-        let bounty_seeds = [
-            b"bounty",
-            self.maker.key.as_ref(),
-            &self.bounty.seed.to_le_bytes(),
-            &[self.bounty.bump],
-        ];
-        let signer_seeds = [bounty_seeds.as_ref()];
-        transfer_checked(
-            CpiContext::new_with_signer(
-                self.token_program.to_account_info(),
-                TransferChecked {
-                    from: self.vault.to_account_info(),
-                    mint: self.mint.to_account_info(),
-                    to: self.hunter_token_account.to_account_info(),
-                    authority: self.bounty.to_account_info(),
-                },
-                signer_seeds.as_ref(),
-            ),
-            self.vault.amount,
-            self.mint.decimals,
+    pub fn transfer_reward(&mut self, amount: u64) -> Result<()> {
+        let vault = escrow::Vault::of(
+            &self.bounty,
+            &self.vault,
+            &self.mint,
+            &self.token_program,
+            &self.memo_program,
         )?;
-
-        close_account(CpiContext::new_with_signer(
-            self.token_program.to_account_info(),
-            CloseAccount {
-                account: self.vault.to_account_info(),
-                destination: self.maker.to_account_info(),
-                authority: self.bounty.to_account_info(),
+        // the protocol fee comes out of the hunter's payout
+        escrow::pay(
+            &mut self.bounty,
+            vault.as_ref(),
+            &self.config,
+            amount,
+            escrow::Recipient {
+                wallet: &self.hunter.to_account_info(),
+                token_account: self.hunter_token_account.as_ref(),
             },
-            signer_seeds.as_ref(),
-        ))?;
-        Ok(())
+            escrow::Recipient {
+                wallet: &self.treasury.to_account_info(),
+                token_account: self.treasury_token_account.as_ref(),
+            },
+            &self.maker.to_account_info(),
+        )
     }
 }
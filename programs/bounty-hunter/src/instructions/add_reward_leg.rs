@@ -0,0 +1,99 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::Mint;
+use anchor_spl::token_interface::TokenAccount;
+use anchor_spl::token_interface::{transfer_checked, TransferChecked};
+use anchor_spl::{associated_token::AssociatedToken, token_interface::TokenInterface};
+
+use crate::{
+    constants::MAX_REWARD_LEGS,
+    error::BountyHunterErrors,
+    events::RewardLegAdded,
+    extensions,
+    state::{Bounty, RewardLeg},
+};
+
+/// The maker escrows a second mint next to the main reward, either in the same transaction as
+/// `create_bounty` or later, as long as nothing has been paid out yet
+#[event_cpi]
+#[derive(Accounts)]
+pub struct AddRewardLeg<'info> {
+    #[account(mut)]
+    pub maker: Signer<'info>,
+
+    #[account(
+        mut,
+        has_one = maker @ BountyHunterErrors::InvalidBountyAuthority,
+        constraint = bounty.is_open() @ BountyHunterErrors::BountyClosed,
+        constraint = !bounty.is_expired(Clock::get()?.unix_timestamp) @ BountyHunterErrors::BountyExpired,
+        // legs follow the prize tiers, milestones would need a split of their own
+        constraint = bounty.milestones.is_empty() && bounty.accepted_submissions.is_empty() @ BountyHunterErrors::RewardLegsLocked,
+        constraint = bounty.reward_legs.len() < MAX_REWARD_LEGS @ BountyHunterErrors::TooManyRewardLegs,
+        constraint = bounty.mint != mint.key() && bounty.reward_legs.iter().all(|leg| leg.mint != mint.key()) @ BountyHunterErrors::DuplicateRewardMint,
+    )]
+    pub bounty: Account<'info, Bounty>,
+
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = maker,
+        associated_token::token_program = token_program,
+    )]
+    pub maker_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        init,
+        payer = maker,
+        associated_token::mint = mint,
+        associated_token::authority = bounty,
+        associated_token::token_program = token_program,
+    )]
+    pub vault: InterfaceAccount<'info, TokenAccount>,
+
+    pub system_program: Program<'info, System>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+
+    pub associated_token_program: Program<'info, AssociatedToken>,
+}
+
+impl AddRewardLeg<'_> {
+    pub fn handler(ctx: Context<AddRewardLeg>, amount: u64) -> Result<()> {
+        require!(amount > 0, BountyHunterErrors::ZeroReward);
+        extensions::check_mint(&ctx.accounts.mint)?;
+        // legs carry no fee reserve, so their mint can't charge transfer fees
+        require!(
+            extensions::gross_amount(&ctx.accounts.mint, amount)? == amount,
+            BountyHunterErrors::UnsupportedMint
+        );
+
+        ctx.accounts.bounty.reward_legs.push(RewardLeg {
+            mint: ctx.accounts.mint.key(),
+            amount,
+            escrowed: amount,
+        });
+
+        transfer_checked(
+            CpiContext::new(
+                ctx.accounts.token_program.to_account_info(),
+                TransferChecked {
+                    from: ctx.accounts.maker_token_account.to_account_info(),
+                    mint: ctx.accounts.mint.to_account_info(),
+                    to: ctx.accounts.vault.to_account_info(),
+                    authority: ctx.accounts.maker.to_account_info(),
+                },
+            ),
+            amount,
+            ctx.accounts.mint.decimals,
+        )?;
+
+        emit_cpi!(RewardLegAdded {
+            bounty: ctx.accounts.bounty.key(),
+            maker: ctx.accounts.maker.key(),
+            mint: ctx.accounts.mint.key(),
+            amount,
+        });
+        Ok(())
+    }
+}
@@ -0,0 +1,202 @@
+use anchor_lang::prelude::*;
+use anchor_spl::memo::Memo;
+use anchor_spl::token_interface::Mint;
+use anchor_spl::token_interface::TokenAccount;
+use anchor_spl::{associated_token::AssociatedToken, token_interface::TokenInterface};
+
+use crate::{
+    error::BountyHunterErrors,
+    escrow,
+    events::MilestoneApproved,
+    state::{Bounty, Config, HunterProfile, MakerProfile, Receipt, Submission, SubmissionStatus},
+};
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(index: u8)]
+pub struct ApproveMilestone<'info> {
+    #[account(mut)]
+    pub maker: Signer<'info>,
+    #[account(
+        mut,
+        has_one = maker @ BountyHunterErrors::InvalidBountyAuthority,
+        constraint = bounty.mint == mint.as_ref().map(|mint| mint.key()).unwrap_or_default() @ BountyHunterErrors::InvalidMint,
+        constraint = bounty.is_open() @ BountyHunterErrors::BountyClosed,
+        constraint = !bounty.is_expired(Clock::get()?.unix_timestamp) @ BountyHunterErrors::BountyExpired
+    )]
+    pub bounty: Account<'info, Bounty>,
+    #[account(
+        mut,
+        has_one = bounty @ BountyHunterErrors::BountyAndSubmissionMismatch,
+        has_one = hunter @ BountyHunterErrors::BountyAndSubmissionMismatch,
+        // a submission already paid for an earlier milestone stays locked and can take the next one
+        constraint = matches!(submission.status, SubmissionStatus::InReview | SubmissionStatus::Accepted) @ BountyHunterErrors::SubmissionNotInReview,
+    )]
+    pub submission: Account<'info, Submission>,
+    #[account(
+        init,
+        payer = maker,
+        space = Receipt::INIT_SPACE + Receipt::DISCRIMINATOR.len(),
+        seeds = [b"receipt", bounty.key().as_ref(), &[index]],
+        bump,
+    )]
+    pub receipt: Account<'info, Receipt>,
+    #[account(
+        mut,
+        seeds = [b"maker_profile", maker.key().as_ref()],
+        bump = maker_profile.bump,
+    )]
+    pub maker_profile: Account<'info, MakerProfile>,
+    // created at the maker's expense on the hunter's first win
+    #[account(
+        init_if_needed,
+        payer = maker,
+        space = HunterProfile::INIT_SPACE + HunterProfile::DISCRIMINATOR.len(),
+        seeds = [b"hunter_profile", hunter.key().as_ref()],
+        bump,
+    )]
+    pub hunter_profile: Account<'info, HunterProfile>,
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = bounty,
+        associated_token::token_program = token_program,
+    )]
+    pub vault: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(mut)]
+    pub hunter: SystemAccount<'info>,
+
+    // writable so Token-2022 withheld fees can be harvested to it before the vault is closed
+    #[account(mut)]
+    pub mint: Option<InterfaceAccount<'info, Mint>>,
+
+    // created at the maker's expense when the hunter has never held the mint
+    #[account(
+        init_if_needed,
+        payer = maker,
+        associated_token::mint = mint,
+        associated_token::authority = hunter,
+        associated_token::token_program = token_program,
+    )]
+    pub hunter_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        mut,
+        address = config.treasury @ BountyHunterErrors::InvalidTreasury,
+    )]
+    pub treasury: SystemAccount<'info>,
+
+    // only needed while a fee is charged, created at the maker's expense the first time a mint pays out
+    #[account(
+        init_if_needed,
+        payer = maker,
+        associated_token::mint = mint,
+        associated_token::authority = treasury,
+        associated_token::token_program = token_program,
+    )]
+    pub treasury_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    pub system_program: Program<'info, System>,
+
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+
+    pub associated_token_program: Option<Program<'info, AssociatedToken>>,
+
+    // only needed when a recipient token account requires memos on incoming transfers
+    pub memo_program: Option<Program<'info, Memo>>,
+}
+
+impl ApproveMilestone<'_> {
+    pub fn handler(ctx: Context<ApproveMilestone>, index: u8) -> Result<()> {
+        let milestones = &ctx.accounts.bounty.milestones;
+        let milestone = milestones
+            .get(index as usize)
+            .ok_or(BountyHunterErrors::InvalidMilestone)?;
+        require!(
+            milestone.approved_submission.is_none(),
+            BountyHunterErrors::MilestoneAlreadyApproved
+        );
+
+        // the last milestone releases whatever is left in escrow
+        let unpaid = milestones
+            .iter()
+            .filter(|milestone| milestone.approved_submission.is_none())
+            .count();
+        let amount = if unpaid == 1 {
+            ctx.accounts.bounty.escrowed
+        } else {
+            milestone.amount
+        };
+        ctx.accounts.bounty.release(amount)?;
+
+        let submission = ctx.accounts.submission.key();
+        ctx.accounts.receipt.set_inner(Receipt {
+            bounty: ctx.accounts.bounty.key(),
+            submission,
+            hunter: ctx.accounts.hunter.key(),
+            mint: ctx.accounts.bounty.mint,
+            index,
+            amount,
+            fee: ctx.accounts.config.fee(amount)?,
+            paid_at: Clock::get()?.unix_timestamp,
+            bump: ctx.bumps.receipt,
+        });
+        ctx.accounts.maker_profile.payouts += 1;
+        ctx.accounts.hunter_profile.hunter = ctx.accounts.hunter.key();
+        ctx.accounts.hunter_profile.bump = ctx.bumps.hunter_profile;
+        ctx.accounts.hunter_profile.record_win(
+            ctx.accounts.bounty.mint,
+            amount - ctx.accounts.config.fee(amount)?,
+            Clock::get()?.unix_timestamp,
+        );
+        ctx.accounts.bounty.milestones[index as usize].approved_submission = Some(submission);
+        ctx.accounts.submission.status = SubmissionStatus::Accepted;
+        if ctx.accounts.bounty.dispute == Some(submission) {
+            ctx.accounts.bounty.dispute = None;
+        }
+        let status = ctx.accounts.bounty.status_after_payout();
+        ctx.accounts.bounty.transition(status)?;
+        ctx.accounts.transfer_reward(amount)?;
+
+        emit_cpi!(MilestoneApproved {
+            bounty: ctx.accounts.bounty.key(),
+            submission,
+            hunter: ctx.accounts.hunter.key(),
+            mint: ctx.accounts.bounty.mint,
+            index,
+            amount,
+            fee: ctx.accounts.config.fee(amount)?,
+        });
+        Ok(())
+    }
+
+    pub fn transfer_reward(&mut self, amount: u64) -> Result<()> {
+        let vault = escrow::Vault::of(
+            &self.bounty,
+            &self.vault,
+            &self.mint,
+            &self.token_program,
+            &self.memo_program,
+        )?;
+        // the protocol fee comes out of the hunter's payout
+        escrow::pay(
+            &mut self.bounty,
+            vault.as_ref(),
+            &self.config,
+            amount,
+            escrow::Recipient {
+                wallet: &self.hunter.to_account_info(),
+                token_account: self.hunter_token_account.as_ref(),
+            },
+            escrow::Recipient {
+                wallet: &self.treasury.to_account_info(),
+                token_account: self.treasury_token_account.as_ref(),
+            },
+            &self.maker.to_account_info(),
+        )
+    }
+}
@@ -1,11 +1,19 @@
 use anchor_lang::prelude::*;
 
-use crate::{error::BountyHunterErrors, state::Bounty};
-use anchor_spl::token::{close_account, transfer_checked, CloseAccount, TransferChecked};
+use crate::{
+    error::BountyHunterErrors,
+    escrow,
+    events::BountyCancelled,
+    reward_legs,
+    state::{Bounty, BountyStatus, MakerProfile},
+};
+use anchor_spl::memo::Memo;
 use anchor_spl::token_interface::Mint;
 use anchor_spl::token_interface::TokenAccount;
 use anchor_spl::{associated_token::AssociatedToken, token_interface::TokenInterface};
 
+/// Reward legs are refunded from the remaining accounts, see `reward_legs::REFUND_ACCOUNTS`
+#[event_cpi]
 #[derive(Accounts)]
 pub struct CancelBounty<'info> {
     #[account(mut)]
@@ -13,21 +21,32 @@ pub struct CancelBounty<'info> {
 
     #[account(
         mut,
-        close = maker,
         has_one = maker @ BountyHunterErrors::InvalidBountyAuthority,
-        has_one = mint @ BountyHunterErrors::InvalidMint,
+        constraint = bounty.mint == mint.as_ref().map(|mint| mint.key()).unwrap_or_default() @ BountyHunterErrors::InvalidMint,
+        constraint = bounty.submissions == 0 @ BountyHunterErrors::BountyHasSubmissions,
+        constraint = bounty.is_open() @ BountyHunterErrors::BountyClosed,
+        constraint = bounty.dispute.is_none() @ BountyHunterErrors::BountyDisputed,
     )]
     pub bounty: Account<'info, Bounty>,
 
+    #[account(
+        mut,
+        seeds = [b"maker_profile", maker.key().as_ref()],
+        bump = maker_profile.bump,
+    )]
+    pub maker_profile: Account<'info, MakerProfile>,
+
     #[account(
         mut,
         associated_token::mint = mint,
         associated_token::authority = bounty,
         associated_token::token_program = token_program,
     )]
-    pub vault: InterfaceAccount<'info, TokenAccount>,
+    pub vault: Option<InterfaceAccount<'info, TokenAccount>>,
 
-    pub mint: InterfaceAccount<'info, Mint>,
+    // writable so Token-2022 withheld fees can be harvested to it before the vault is closed
+    #[account(mut)]
+    pub mint: Option<InterfaceAccount<'info, Mint>>,
 
     #[account(
         mut,
@@ -35,53 +54,53 @@ pub struct CancelBounty<'info> {
         associated_token::authority = maker,
         associated_token::token_program = token_program,
     )]
-    pub maker_token_account: InterfaceAccount<'info, TokenAccount>,
+    pub maker_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Option<Interface<'info, TokenInterface>>,
 
-    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Option<Program<'info, AssociatedToken>>,
 
-    pub associated_token_program: Program<'info, AssociatedToken>,
+    // only needed when a recipient token account requires memos on incoming transfers
+    pub memo_program: Option<Program<'info, Memo>>,
 }
 
-impl CancelBounty<'_> {
-    pub fn handler(ctx: Context<CancelBounty>) -> Result<()> {
-        ctx.accounts.refund_tokens()?;
+impl<'info> CancelBounty<'info> {
+    pub fn handler(ctx: Context<'_, '_, '_, 'info, CancelBounty<'info>>) -> Result<()> {
+        ctx.accounts.bounty.transition(BountyStatus::Cancelled)?;
+        ctx.accounts.maker_profile.bounties_cancelled += 1;
+        let refunded = ctx.accounts.refund_maker()?;
+        let maker = ctx.accounts.maker.to_account_info();
+        reward_legs::refund(
+            &mut ctx.accounts.bounty,
+            ctx.remaining_accounts,
+            &maker,
+            &ctx.accounts.memo_program,
+        )?;
+
+        emit_cpi!(BountyCancelled {
+            bounty: ctx.accounts.bounty.key(),
+            maker: ctx.accounts.maker.key(),
+            mint: ctx.accounts.bounty.mint,
+            refunded,
+        });
         Ok(())
     }
 
-    fn refund_tokens(&self) -> Result<()> {
-        let bounty_seeds = [
-            b"bounty",
-            self.maker.key.as_ref(),
-            &self.bounty.seed.to_le_bytes(),
-            &[self.bounty.bump],
-        ];
-        let signer_seeds = [bounty_seeds.as_ref()];
-
-        transfer_checked(
-            CpiContext::new_with_signer(
-                self.token_program.to_account_info(),
-                TransferChecked {
-                    from: self.vault.to_account_info(),
-                    mint: self.mint.to_account_info(),
-                    to: self.maker_token_account.to_account_info(),
-                    authority: self.bounty.to_account_info(),
-                },
-                signer_seeds.as_ref(),
-            ),
-            self.vault.amount,
-            self.mint.decimals,
+    fn refund_maker(&mut self) -> Result<u64> {
+        let vault = escrow::Vault::of(
+            &self.bounty,
+            &self.vault,
+            &self.mint,
+            &self.token_program,
+            &self.memo_program,
         )?;
-
-        close_account(CpiContext::new_with_signer(
-            self.token_program.to_account_info(),
-            CloseAccount {
-                account: self.vault.to_account_info(),
-                destination: self.maker.to_account_info(),
-                authority: self.bounty.to_account_info(),
+        escrow::refund_maker(
+            &mut self.bounty,
+            vault.as_ref(),
+            escrow::Recipient {
+                wallet: &self.maker.to_account_info(),
+                token_account: self.maker_token_account.as_ref(),
             },
-            signer_seeds.as_ref(),
-        ))?;
-
-        Ok(())
+        )
     }
 }
@@ -0,0 +1,145 @@
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::get_associated_token_address_with_program_id;
+use anchor_spl::memo::Memo;
+use anchor_spl::token_interface::Mint;
+use anchor_spl::token_interface::TokenAccount;
+use anchor_spl::{associated_token::AssociatedToken, token_interface::TokenInterface};
+
+use crate::{
+    error::BountyHunterErrors,
+    escrow,
+    events::{BountyClosed, ExcessSwept},
+    extensions, reward_legs,
+    state::Bounty,
+};
+
+/// Returns the rent of a finished bounty to the maker, its payouts stay on chain as `Receipt`s.
+/// Token bounties always pass their vault, one still open because of excess is swept and closed on
+/// the way out. Reward leg vaults are handled the same through the remaining accounts, see
+/// `reward_legs::REFUND_ACCOUNTS`
+#[event_cpi]
+#[derive(Accounts)]
+pub struct CloseBounty<'info> {
+    #[account(mut)]
+    pub maker: Signer<'info>,
+
+    #[account(
+        mut,
+        close = maker,
+        has_one = maker @ BountyHunterErrors::InvalidBountyAuthority,
+        constraint = bounty.mint == mint.as_ref().map(|mint| mint.key()).unwrap_or_default() @ BountyHunterErrors::InvalidMint,
+        constraint = !bounty.is_open() @ BountyHunterErrors::BountyStillOpen,
+        constraint = bounty.escrowed == 0 @ BountyHunterErrors::BountyHasEscrow,
+        constraint = bounty.reward_legs.iter().all(|leg| leg.escrowed == 0) @ BountyHunterErrors::BountyHasEscrow,
+        constraint = bounty.contributors == 0 @ BountyHunterErrors::BountyHasContributions,
+    )]
+    pub bounty: Account<'info, Bounty>,
+
+    /// CHECK: the bounty's associated token account, checked in the handler. Unchecked because the
+    /// last payout may already have closed it
+    #[account(mut)]
+    pub vault: Option<UncheckedAccount<'info>>,
+
+    // writable so Token-2022 withheld fees can be harvested to it before the vault is closed
+    #[account(mut)]
+    pub mint: Option<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = maker,
+        associated_token::token_program = token_program,
+    )]
+    pub maker_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+
+    pub associated_token_program: Option<Program<'info, AssociatedToken>>,
+
+    // only needed when a recipient token account requires memos on incoming transfers
+    pub memo_program: Option<Program<'info, Memo>>,
+}
+
+impl<'info> CloseBounty<'info> {
+    pub fn handler(ctx: Context<'_, '_, '_, 'info, CloseBounty<'info>>) -> Result<()> {
+        // native excess leaves with the bounty rent through `close = maker`
+        let swept = if ctx.accounts.bounty.is_native() {
+            0
+        } else {
+            ctx.accounts.close_vault()?
+        };
+        let maker = ctx.accounts.maker.to_account_info();
+        let legs = reward_legs::sweep(
+            &ctx.accounts.bounty,
+            ctx.remaining_accounts,
+            &maker,
+            &ctx.accounts.memo_program,
+        )?;
+        for (mint, amount) in legs {
+            emit_cpi!(ExcessSwept {
+                bounty: ctx.accounts.bounty.key(),
+                maker: ctx.accounts.maker.key(),
+                mint,
+                amount,
+            });
+        }
+
+        emit_cpi!(BountyClosed {
+            bounty: ctx.accounts.bounty.key(),
+            maker: ctx.accounts.maker.key(),
+            mint: ctx.accounts.bounty.mint,
+            swept,
+        });
+        Ok(())
+    }
+
+    /// returns the amount swept, nothing when the vault was already closed with the last payout
+    fn close_vault(&self) -> Result<u64> {
+        let (Some(vault), Some(mint), Some(token_program)) =
+            (&self.vault, &self.mint, &self.token_program)
+        else {
+            return err!(BountyHunterErrors::MissingTokenAccounts);
+        };
+        require_keys_eq!(
+            vault.key(),
+            get_associated_token_address_with_program_id(
+                &self.bounty.key(),
+                &mint.key(),
+                &token_program.key()
+            ),
+            BountyHunterErrors::InvalidVault
+        );
+        if vault.data_is_empty() {
+            return Ok(0);
+        }
+        let vault = vault.to_account_info();
+        let maker = self.maker.to_account_info();
+
+        // nothing is escrowed anymore, whatever is left is excess
+        let excess = TokenAccount::try_deserialize(&mut vault.try_borrow_data()?.as_ref())?.amount;
+        if excess > 0 {
+            let Some(maker_token_account) = &self.maker_token_account else {
+                return err!(BountyHunterErrors::MissingTokenAccounts);
+            };
+            extensions::memo_if_required(&self.memo_program, maker_token_account)?;
+            escrow::transfer(
+                &self.bounty,
+                &token_program.to_account_info(),
+                &mint.to_account_info(),
+                &vault,
+                &maker_token_account.to_account_info(),
+                excess,
+                mint.decimals,
+            )?;
+        }
+
+        extensions::harvest_withheld(token_program, mint, &vault)?;
+        escrow::close(
+            &self.bounty,
+            &token_program.to_account_info(),
+            &vault,
+            &maker,
+        )?;
+        Ok(excess)
+    }
+}
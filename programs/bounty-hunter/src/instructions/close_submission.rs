@@ -0,0 +1,47 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    error::BountyHunterErrors,
+    events::SubmissionClosed,
+    state::{Bounty, Submission},
+};
+
+/// Permissionless: returns the rent and bond of a submission to its hunter once the bounty is
+/// finished, or once the bounty account is gone altogether
+#[event_cpi]
+#[derive(Accounts)]
+pub struct CloseSubmission<'info> {
+    pub payer: Signer<'info>,
+
+    #[account(mut)]
+    pub hunter: SystemAccount<'info>,
+
+    /// CHECK: may already be closed, only deserialized in the handler while it still exists
+    pub bounty: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        close = hunter,
+        has_one = bounty @ BountyHunterErrors::BountyAndSubmissionMismatch,
+        has_one = hunter @ BountyHunterErrors::BountyAndSubmissionMismatch,
+    )]
+    pub submission: Account<'info, Submission>,
+}
+
+impl CloseSubmission<'_> {
+    pub fn handler(ctx: Context<CloseSubmission>) -> Result<()> {
+        let bounty = &ctx.accounts.bounty;
+        if bounty.owner == &crate::ID && !bounty.data_is_empty() {
+            let data = bounty.try_borrow_data()?;
+            let bounty = Bounty::try_deserialize(&mut data.as_ref())?;
+            require!(!bounty.is_open(), BountyHunterErrors::BountyStillOpen);
+        }
+
+        emit_cpi!(SubmissionClosed {
+            bounty: ctx.accounts.bounty.key(),
+            submission: ctx.accounts.submission.key(),
+            hunter: ctx.accounts.hunter.key(),
+        });
+        Ok(())
+    }
+}
@@ -1,14 +1,32 @@
-use crate::state::Bounty;
+use crate::{
+    constants::{
+        BPS_DENOMINATOR, MAX_ALLOWED_HUNTERS, MAX_MILESTONES, MAX_MILESTONE_DESCRIPTION_LEN,
+        MAX_WINNERS,
+    },
+    error::BountyHunterErrors,
+    events::BountyCreated,
+    extensions,
+    state::{Bounty, BountyAccess, BountyStatus, MakerProfile, Milestone},
+    validation,
+};
 use anchor_lang::prelude::*;
+use anchor_lang::system_program::{transfer, Transfer};
 
 //use anchor_spl::token::{Token};
-use anchor_spl::token::{transfer_checked, TransferChecked};
 use anchor_spl::token_interface::Mint;
 use anchor_spl::token_interface::TokenAccount;
+use anchor_spl::token_interface::{transfer_checked, TransferChecked};
 use anchor_spl::{associated_token::AssociatedToken, token_interface::TokenInterface};
 
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct MilestoneArgs {
+    pub description: String,
+    pub amount: u64,
+}
+
+#[event_cpi]
 #[derive(Accounts)]
-#[instruction(seed: u64)]
+#[instruction(seed: u64, description: String, link: String)]
 pub struct CreateBounty<'info> {
     #[account(mut)]
     pub maker: Signer<'info>,
@@ -16,13 +34,23 @@ pub struct CreateBounty<'info> {
     #[account(
         init,
         payer = maker,
-        space = Bounty::INIT_SPACE + Bounty::DISCRIMINATOR.len(),
+        space = Bounty::space(&description, &link),
         seeds = [b"bounty", maker.key().as_ref(), seed.to_le_bytes().as_ref()],
         bump,
     )]
     pub bounty: Account<'info, Bounty>,
 
-    pub mint: InterfaceAccount<'info, Mint>,
+    #[account(
+        init_if_needed,
+        payer = maker,
+        space = MakerProfile::INIT_SPACE + MakerProfile::DISCRIMINATOR.len(),
+        seeds = [b"maker_profile", maker.key().as_ref()],
+        bump,
+    )]
+    pub maker_profile: Account<'info, MakerProfile>,
+
+    // token accounts are left out for native SOL bounties, the reward then lives in the bounty account
+    pub mint: Option<InterfaceAccount<'info, Mint>>,
 
     // the token account associated with the maker and mint used to deposit tokens in the vault
     #[account(
@@ -31,7 +59,7 @@ pub struct CreateBounty<'info> {
         associated_token::authority = maker,
         associated_token::token_program = token_program,
     )]
-    pub maker_token_account: InterfaceAccount<'info, TokenAccount>,
+    pub maker_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
 
     // the token account associated with the escrow and mint where deposit tokens are parked
     #[account(
@@ -41,54 +69,211 @@ pub struct CreateBounty<'info> {
         associated_token::authority = bounty,
         associated_token::token_program = token_program,
     )]
-    pub vault: InterfaceAccount<'info, TokenAccount>,
+    pub vault: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    /// CHECK: the admin's minimum reward for the mint, it doesn't exist while no minimum is set
+    #[account(
+        seeds = [b"min_reward", mint.as_ref().map(|mint| mint.key()).unwrap_or_default().as_ref()],
+        bump,
+    )]
+    pub min_reward: UncheckedAccount<'info>,
 
     pub system_program: Program<'info, System>,
 
-    pub token_program: Interface<'info, TokenInterface>,
+    pub token_program: Option<Interface<'info, TokenInterface>>,
 
-    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub associated_token_program: Option<Program<'info, AssociatedToken>>,
 }
 
 impl CreateBounty<'_> {
+    #[allow(clippy::too_many_arguments)]
     pub fn handler(
         ctx: Context<CreateBounty>,
         seed: u64,
         description: String,
         link: String,
         reward: u64,
+        deadline: Option<i64>,
+        prize_bps: Vec<u16>,
+        milestones: Vec<MilestoneArgs>,
+        arbiter: Option<Pubkey>,
+        access: BountyAccess,
+        submission_bond: u64,
+        content_hash: Option<[u8; 32]>,
     ) -> Result<()> {
+        validation::check_description(&description)?;
+        validation::check_link(&link)?;
+        validation::check_reward(reward)?;
+        validation::check_min_reward(
+            validation::load_min_reward(&ctx.accounts.min_reward)?.as_ref(),
+            reward,
+        )?;
+        if let Some(deadline) = deadline {
+            require!(
+                deadline > Clock::get()?.unix_timestamp,
+                BountyHunterErrors::DeadlineInPast
+            );
+        }
+
+        // no tiers means a single winner takes the whole reward
+        let prize_bps = if prize_bps.is_empty() {
+            vec![BPS_DENOMINATOR as u16]
+        } else {
+            prize_bps
+        };
+        require!(
+            prize_bps.len() <= MAX_WINNERS
+                && prize_bps.iter().all(|bps| *bps > 0)
+                && prize_bps.iter().map(|bps| *bps as u64).sum::<u64>() == BPS_DENOMINATOR,
+            BountyHunterErrors::InvalidPrizeSplit
+        );
+
+        require!(
+            milestones.is_empty() || prize_bps.len() == 1,
+            BountyHunterErrors::MilestonesWithPrizeTiers
+        );
+        require!(
+            milestones.is_empty()
+                || (milestones.len() <= MAX_MILESTONES
+                    && milestones.iter().all(|milestone| {
+                        milestone.amount > 0
+                            && !milestone.description.is_empty()
+                            && milestone.description.len() <= MAX_MILESTONE_DESCRIPTION_LEN
+                    })
+                    && milestones
+                        .iter()
+                        .try_fold(0u64, |sum, milestone| sum.checked_add(milestone.amount))
+                        == Some(reward)),
+            BountyHunterErrors::InvalidMilestones
+        );
+        // the maker can't settle its own disputes
+        require!(
+            arbiter != Some(ctx.accounts.maker.key()),
+            BountyHunterErrors::InvalidArbiter
+        );
+        if let BountyAccess::AllowList { hunters } = &access {
+            require!(
+                hunters.len() <= MAX_ALLOWED_HUNTERS,
+                BountyHunterErrors::TooManyAllowedHunters
+            );
+        }
+
+        // Token-2022 transfer fees on the hunter payouts come out of a reserve deposited with the reward
+        let fee_reserve = match &ctx.accounts.mint {
+            Some(mint) => {
+                extensions::check_mint(mint)?;
+                extensions::gross_amount(mint, reward)? - reward
+            }
+            None => 0,
+        };
+
+        let milestones = milestones
+            .into_iter()
+            .map(|milestone| Milestone {
+                description: milestone.description,
+                amount: milestone.amount,
+                approved_submission: None,
+            })
+            .collect();
+
         ctx.accounts.bounty.set_inner(Bounty {
             seed,
             description,
             link,
-            mint: ctx.accounts.mint.key(),
+            mint: ctx
+                .accounts
+                .mint
+                .as_ref()
+                .map(|mint| mint.key())
+                .unwrap_or_default(),
             reward,
             bump: ctx.bumps.bounty,
             maker: ctx.accounts.maker.key(),
-            accepted_submission: Pubkey::default(),
+            accepted_submissions: Vec::new(),
+            deadline,
+            submissions: 0,
+            prize_bps,
+            milestones,
+            funded: 0,
+            contributors: 0,
+            refund_pool: None,
+            escrowed: reward,
+            fee_reserve,
+            arbiter,
+            dispute: None,
+            status: BountyStatus::Open,
+            access,
+            submission_bond,
+            reward_legs: Vec::new(),
+            edits: 0,
+            updated_slot: Clock::get()?.slot,
+            content_hash,
+        });
+        let maker_profile = &mut ctx.accounts.maker_profile;
+        maker_profile.maker = ctx.accounts.maker.key();
+        maker_profile.bump = ctx.bumps.maker_profile;
+        maker_profile.bounties_created += 1;
+
+        ctx.accounts.deposit_tokens(reward + fee_reserve)?; /* the ? is equivalent to
+                                                            if let Err(e) = ctx.accounts.deposit_tokens(reward) {
+                                                                return Err(e)
+                                                            } */
+
+        emit_cpi!(BountyCreated {
+            bounty: ctx.accounts.bounty.key(),
+            maker: ctx.accounts.maker.key(),
+            mint: ctx.accounts.bounty.mint,
+            reward,
+            deadline,
         });
-        ctx.accounts.deposit_tokens(reward)?; /* the ? is equivalent to
-                                              if let Err(e) = ctx.accounts.deposit_tokens(reward) {
-                                                  return Err(e)
-                                              } */
         Ok(())
     }
 
     ///deposit the tokens
     fn deposit_tokens(&self, amount: u64) -> Result<()> {
+        if self.bounty.is_native() {
+            return transfer(
+                CpiContext::new(
+                    self.system_program.to_account_info(),
+                    Transfer {
+                        from: self.maker.to_account_info(),
+                        to: self.bounty.to_account_info(),
+                    },
+                ),
+                amount,
+            );
+        }
+
+        let (
+            Some(mint),
+            Some(maker_token_account),
+            Some(vault),
+            Some(token_program),
+            Some(_associated_token_program),
+        ) = (
+            &self.mint,
+            &self.maker_token_account,
+            &self.vault,
+            &self.token_program,
+            &self.associated_token_program,
+        )
+        else {
+            return err!(BountyHunterErrors::MissingTokenAccounts);
+        };
+
+        // the vault has to end up with `amount` after the transfer fee
         transfer_checked(
             CpiContext::new(
-                self.token_program.to_account_info(),
+                token_program.to_account_info(),
                 TransferChecked {
-                    from: self.maker_token_account.to_account_info(),
-                    mint: self.mint.to_account_info(),
-                    to: self.vault.to_account_info(),
+                    from: maker_token_account.to_account_info(),
+                    mint: mint.to_account_info(),
+                    to: vault.to_account_info(),
                     authority: self.maker.to_account_info(),
                 },
             ),
-            amount,
-            self.mint.decimals,
+            extensions::gross_amount(mint, amount)?,
+            mint.decimals,
         )?;
         Ok(())
     }
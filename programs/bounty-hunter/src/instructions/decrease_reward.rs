@@ -0,0 +1,116 @@
+use anchor_lang::prelude::*;
+use anchor_spl::memo::Memo;
+use anchor_spl::token_interface::Mint;
+use anchor_spl::token_interface::TokenAccount;
+use anchor_spl::{associated_token::AssociatedToken, token_interface::TokenInterface};
+
+use crate::{
+    error::BountyHunterErrors, escrow, events::RewardDecreased, extensions, state::Bounty,
+    validation,
+};
+
+/// The maker takes part of its reward back, only before any hunter has submitted against it
+#[event_cpi]
+#[derive(Accounts)]
+pub struct DecreaseReward<'info> {
+    #[account(mut)]
+    pub maker: Signer<'info>,
+
+    #[account(
+        mut,
+        has_one = maker @ BountyHunterErrors::InvalidBountyAuthority,
+        constraint = bounty.mint == mint.as_ref().map(|mint| mint.key()).unwrap_or_default() @ BountyHunterErrors::InvalidMint,
+        constraint = bounty.is_open() @ BountyHunterErrors::BountyClosed,
+        constraint = bounty.submissions == 0 && bounty.accepted_submissions.is_empty() @ BountyHunterErrors::BountyHasSubmissions,
+        constraint = bounty.milestones.is_empty() @ BountyHunterErrors::BountyHasMilestones,
+    )]
+    pub bounty: Account<'info, Bounty>,
+
+    pub mint: Option<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = bounty,
+        associated_token::token_program = token_program,
+    )]
+    pub vault: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = maker,
+        associated_token::token_program = token_program,
+    )]
+    pub maker_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    /// CHECK: the admin's minimum reward for the mint, it doesn't exist while no minimum is set
+    #[account(
+        seeds = [b"min_reward", bounty.mint.as_ref()],
+        bump,
+    )]
+    pub min_reward: UncheckedAccount<'info>,
+
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+
+    pub associated_token_program: Option<Program<'info, AssociatedToken>>,
+
+    // only needed when a recipient token account requires memos on incoming transfers
+    pub memo_program: Option<Program<'info, Memo>>,
+}
+
+impl DecreaseReward<'_> {
+    pub fn handler(ctx: Context<DecreaseReward>, amount: u64) -> Result<()> {
+        // crowdfunded amounts aren't the maker's to take back, and some reward has to be left
+        require!(
+            amount > 0 && amount < ctx.accounts.bounty.reward,
+            BountyHunterErrors::InvalidRewardDecrease
+        );
+        validation::check_min_reward(
+            validation::load_min_reward(&ctx.accounts.min_reward)?.as_ref(),
+            ctx.accounts.bounty.reward - amount,
+        )?;
+
+        // the part of the Token-2022 fee reserve that covered the amount goes back with it
+        let fee_reserve = match &ctx.accounts.mint {
+            Some(mint) => (extensions::gross_amount(mint, amount)? - amount)
+                .min(ctx.accounts.bounty.fee_reserve),
+            None => 0,
+        };
+
+        let bounty = &mut ctx.accounts.bounty;
+        bounty.reward -= amount;
+        bounty.release(amount)?;
+        bounty.fee_reserve -= fee_reserve;
+
+        ctx.accounts.refund(amount + fee_reserve)?;
+
+        emit_cpi!(RewardDecreased {
+            bounty: ctx.accounts.bounty.key(),
+            maker: ctx.accounts.maker.key(),
+            mint: ctx.accounts.bounty.mint,
+            amount,
+            reward: ctx.accounts.bounty.reward,
+        });
+        Ok(())
+    }
+
+    fn refund(&self, amount: u64) -> Result<()> {
+        let vault = escrow::Vault::of(
+            &self.bounty,
+            &self.vault,
+            &self.mint,
+            &self.token_program,
+            &self.memo_program,
+        )?;
+        escrow::refund(
+            &self.bounty,
+            vault.as_ref(),
+            amount,
+            escrow::Recipient {
+                wallet: &self.maker.to_account_info(),
+                token_account: self.maker_token_account.as_ref(),
+            },
+        )
+    }
+}
@@ -0,0 +1,67 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    error::BountyHunterErrors,
+    events::SubmissionEdited,
+    resize::resize,
+    state::{Bounty, Submission, SubmissionStatus},
+    validation,
+};
+
+/// Only pending submissions can change, so the maker never accepts something other than what it reviewed
+#[event_cpi]
+#[derive(Accounts)]
+pub struct EditSubmission<'info> {
+    #[account(mut)]
+    pub hunter: Signer<'info>,
+
+    #[account(
+        constraint = bounty.is_open() @ BountyHunterErrors::BountyClosed,
+        constraint = !bounty.is_expired(Clock::get()?.unix_timestamp) @ BountyHunterErrors::BountyExpired
+    )]
+    pub bounty: Account<'info, Bounty>,
+
+    #[account(
+        mut,
+        has_one = bounty @ BountyHunterErrors::BountyAndSubmissionMismatch,
+        has_one = hunter @ BountyHunterErrors::BountyAndSubmissionMismatch,
+        constraint = submission.status == SubmissionStatus::Pending @ BountyHunterErrors::SubmissionLocked,
+    )]
+    pub submission: Account<'info, Submission>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl EditSubmission<'_> {
+    pub fn handler(
+        ctx: Context<EditSubmission>,
+        link: String,
+        notes: String,
+        content_hash: Option<[u8; 32]>,
+    ) -> Result<()> {
+        validation::check_link(&link)?;
+        validation::check_notes(&notes)?;
+
+        resize(
+            &ctx.accounts.submission.to_account_info(),
+            &ctx.accounts.hunter.to_account_info(),
+            &ctx.accounts.system_program,
+            Submission::space(&link, &notes),
+        )?;
+
+        let submission = &mut ctx.accounts.submission;
+        submission.link = link;
+        submission.notes = notes;
+        submission.content_hash = content_hash;
+        submission.edits += 1;
+        submission.updated_at = Clock::get()?.unix_timestamp;
+
+        emit_cpi!(SubmissionEdited {
+            bounty: ctx.accounts.bounty.key(),
+            submission: ctx.accounts.submission.key(),
+            hunter: ctx.accounts.hunter.key(),
+            edits: ctx.accounts.submission.edits,
+        });
+        Ok(())
+    }
+}
@@ -0,0 +1,111 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    error::BountyHunterErrors,
+    escrow,
+    events::BountyExpired,
+    reward_legs,
+    state::{Bounty, BountyStatus, MakerProfile},
+};
+use anchor_spl::memo::Memo;
+use anchor_spl::token_interface::Mint;
+use anchor_spl::token_interface::TokenAccount;
+use anchor_spl::{associated_token::AssociatedToken, token_interface::TokenInterface};
+
+/// Permissionless: anyone can crank an expired bounty, the funds always go back to the maker. Reward
+/// legs are refunded from the remaining accounts, see `reward_legs::REFUND_ACCOUNTS`
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ExpireBounty<'info> {
+    pub payer: Signer<'info>,
+
+    #[account(mut)]
+    pub maker: SystemAccount<'info>,
+
+    #[account(
+        mut,
+        has_one = maker @ BountyHunterErrors::InvalidBountyAuthority,
+        constraint = bounty.mint == mint.as_ref().map(|mint| mint.key()).unwrap_or_default() @ BountyHunterErrors::InvalidMint,
+        constraint = bounty.is_open() @ BountyHunterErrors::BountyClosed,
+        constraint = bounty.dispute.is_none() @ BountyHunterErrors::BountyDisputed,
+        constraint = bounty.is_expired(Clock::get()?.unix_timestamp) @ BountyHunterErrors::BountyNotExpired
+    )]
+    pub bounty: Account<'info, Bounty>,
+
+    #[account(
+        mut,
+        seeds = [b"maker_profile", maker.key().as_ref()],
+        bump = maker_profile.bump,
+    )]
+    pub maker_profile: Account<'info, MakerProfile>,
+
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = bounty,
+        associated_token::token_program = token_program,
+    )]
+    pub vault: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    // writable so Token-2022 withheld fees can be harvested to it before the vault is closed
+    #[account(mut)]
+    pub mint: Option<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = maker,
+        associated_token::token_program = token_program,
+    )]
+    pub maker_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+
+    pub associated_token_program: Option<Program<'info, AssociatedToken>>,
+
+    // only needed when a recipient token account requires memos on incoming transfers
+    pub memo_program: Option<Program<'info, Memo>>,
+}
+
+impl<'info> ExpireBounty<'info> {
+    pub fn handler(ctx: Context<'_, '_, '_, 'info, ExpireBounty<'info>>) -> Result<()> {
+        ctx.accounts.bounty.transition(BountyStatus::Expired)?;
+        if ctx.accounts.bounty.submissions > 0 {
+            ctx.accounts.maker_profile.expired_with_submissions += 1;
+        }
+        let refunded = ctx.accounts.refund_maker()?;
+        let maker = ctx.accounts.maker.to_account_info();
+        reward_legs::refund(
+            &mut ctx.accounts.bounty,
+            ctx.remaining_accounts,
+            &maker,
+            &ctx.accounts.memo_program,
+        )?;
+
+        emit_cpi!(BountyExpired {
+            bounty: ctx.accounts.bounty.key(),
+            maker: ctx.accounts.maker.key(),
+            mint: ctx.accounts.bounty.mint,
+            refunded,
+        });
+        Ok(())
+    }
+
+    fn refund_maker(&mut self) -> Result<u64> {
+        let vault = escrow::Vault::of(
+            &self.bounty,
+            &self.vault,
+            &self.mint,
+            &self.token_program,
+            &self.memo_program,
+        )?;
+        escrow::refund_maker(
+            &mut self.bounty,
+            vault.as_ref(),
+            escrow::Recipient {
+                wallet: &self.maker.to_account_info(),
+                token_account: self.maker_token_account.as_ref(),
+            },
+        )
+    }
+}
@@ -0,0 +1,160 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{transfer, Transfer};
+use anchor_spl::token_interface::Mint;
+use anchor_spl::token_interface::TokenAccount;
+use anchor_spl::token_interface::{transfer_checked, TransferChecked};
+use anchor_spl::{associated_token::AssociatedToken, token_interface::TokenInterface};
+
+use crate::{
+    error::BountyHunterErrors,
+    events::BountyFunded,
+    extensions,
+    state::{Bounty, Contribution},
+};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct FundBounty<'info> {
+    #[account(mut)]
+    pub funder: Signer<'info>,
+
+    #[account(
+        mut,
+        constraint = bounty.mint == mint.as_ref().map(|mint| mint.key()).unwrap_or_default() @ BountyHunterErrors::InvalidMint,
+        constraint = bounty.is_open() @ BountyHunterErrors::BountyClosed,
+        constraint = bounty.milestones.is_empty() @ BountyHunterErrors::BountyHasMilestones,
+        constraint = !bounty.is_expired(Clock::get()?.unix_timestamp) @ BountyHunterErrors::BountyExpired
+    )]
+    pub bounty: Account<'info, Bounty>,
+
+    #[account(
+        init_if_needed,
+        payer = funder,
+        space = Contribution::INIT_SPACE + Contribution::DISCRIMINATOR.len(),
+        seeds = [b"contribution", bounty.key().as_ref(), funder.key().as_ref()],
+        bump,
+    )]
+    pub contribution: Account<'info, Contribution>,
+
+    pub mint: Option<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = funder,
+        associated_token::token_program = token_program,
+    )]
+    pub funder_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = bounty,
+        associated_token::token_program = token_program,
+    )]
+    pub vault: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    pub system_program: Program<'info, System>,
+
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+
+    pub associated_token_program: Option<Program<'info, AssociatedToken>>,
+}
+
+impl FundBounty<'_> {
+    pub fn handler(ctx: Context<FundBounty>, amount: u64) -> Result<()> {
+        // an empty contribution would still count as a contributor to refund before closing
+        require!(amount > 0, BountyHunterErrors::ZeroContribution);
+
+        // a funder topping up again keeps a single contribution account
+        if ctx.accounts.contribution.bounty == Pubkey::default() {
+            ctx.accounts.contribution.set_inner(Contribution {
+                bounty: ctx.accounts.bounty.key(),
+                funder: ctx.accounts.funder.key(),
+                amount: 0,
+                bump: ctx.bumps.contribution,
+            });
+            ctx.accounts.bounty.contributors += 1;
+        }
+
+        ctx.accounts.contribution.amount = ctx
+            .accounts
+            .contribution
+            .amount
+            .checked_add(amount)
+            .ok_or(ProgramError::ArithmeticOverflow)?;
+        ctx.accounts.bounty.funded = ctx
+            .accounts
+            .bounty
+            .funded
+            .checked_add(amount)
+            .ok_or(ProgramError::ArithmeticOverflow)?;
+        ctx.accounts.bounty.escrowed = ctx
+            .accounts
+            .bounty
+            .escrowed
+            .checked_add(amount)
+            .ok_or(ProgramError::ArithmeticOverflow)?;
+
+        // the contribution brings its own share of the Token-2022 fee reserve
+        let fee_reserve = match &ctx.accounts.mint {
+            Some(mint) => extensions::gross_amount(mint, amount)? - amount,
+            None => 0,
+        };
+        ctx.accounts.bounty.fee_reserve = ctx
+            .accounts
+            .bounty
+            .fee_reserve
+            .checked_add(fee_reserve)
+            .ok_or(ProgramError::ArithmeticOverflow)?;
+
+        ctx.accounts.deposit(amount + fee_reserve)?;
+
+        emit_cpi!(BountyFunded {
+            bounty: ctx.accounts.bounty.key(),
+            funder: ctx.accounts.funder.key(),
+            mint: ctx.accounts.bounty.mint,
+            amount,
+        });
+        Ok(())
+    }
+
+    fn deposit(&self, amount: u64) -> Result<()> {
+        if self.bounty.is_native() {
+            return transfer(
+                CpiContext::new(
+                    self.system_program.to_account_info(),
+                    Transfer {
+                        from: self.funder.to_account_info(),
+                        to: self.bounty.to_account_info(),
+                    },
+                ),
+                amount,
+            );
+        }
+
+        let (Some(mint), Some(funder_token_account), Some(vault), Some(token_program)) = (
+            &self.mint,
+            &self.funder_token_account,
+            &self.vault,
+            &self.token_program,
+        ) else {
+            return err!(BountyHunterErrors::MissingTokenAccounts);
+        };
+
+        transfer_checked(
+            CpiContext::new(
+                token_program.to_account_info(),
+                TransferChecked {
+                    from: funder_token_account.to_account_info(),
+                    mint: mint.to_account_info(),
+                    to: vault.to_account_info(),
+                    authority: self.funder.to_account_info(),
+                },
+            ),
+            extensions::gross_amount(mint, amount)?,
+            mint.decimals,
+        )?;
+        Ok(())
+    }
+}
@@ -0,0 +1,125 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{transfer, Transfer};
+use anchor_spl::token_interface::Mint;
+use anchor_spl::token_interface::TokenAccount;
+use anchor_spl::token_interface::{transfer_checked, TransferChecked};
+use anchor_spl::{associated_token::AssociatedToken, token_interface::TokenInterface};
+
+use crate::{error::BountyHunterErrors, events::RewardIncreased, extensions, state::Bounty};
+
+/// The maker deposits more into its own reward, the bounty keeps its address and submissions
+#[event_cpi]
+#[derive(Accounts)]
+pub struct IncreaseReward<'info> {
+    #[account(mut)]
+    pub maker: Signer<'info>,
+
+    #[account(
+        mut,
+        has_one = maker @ BountyHunterErrors::InvalidBountyAuthority,
+        constraint = bounty.mint == mint.as_ref().map(|mint| mint.key()).unwrap_or_default() @ BountyHunterErrors::InvalidMint,
+        constraint = bounty.is_open() @ BountyHunterErrors::BountyClosed,
+        constraint = bounty.milestones.is_empty() @ BountyHunterErrors::BountyHasMilestones,
+        constraint = !bounty.is_expired(Clock::get()?.unix_timestamp) @ BountyHunterErrors::BountyExpired
+    )]
+    pub bounty: Account<'info, Bounty>,
+
+    pub mint: Option<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = maker,
+        associated_token::token_program = token_program,
+    )]
+    pub maker_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = bounty,
+        associated_token::token_program = token_program,
+    )]
+    pub vault: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    pub system_program: Program<'info, System>,
+
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+
+    pub associated_token_program: Option<Program<'info, AssociatedToken>>,
+}
+
+impl IncreaseReward<'_> {
+    pub fn handler(ctx: Context<IncreaseReward>, amount: u64) -> Result<()> {
+        require!(amount > 0, BountyHunterErrors::ZeroReward);
+        let bounty = &mut ctx.accounts.bounty;
+        bounty.reward = bounty
+            .reward
+            .checked_add(amount)
+            .ok_or(ProgramError::ArithmeticOverflow)?;
+        bounty.escrowed = bounty
+            .escrowed
+            .checked_add(amount)
+            .ok_or(ProgramError::ArithmeticOverflow)?;
+
+        // the increase brings its own share of the Token-2022 fee reserve
+        let fee_reserve = match &ctx.accounts.mint {
+            Some(mint) => extensions::gross_amount(mint, amount)? - amount,
+            None => 0,
+        };
+        bounty.fee_reserve = bounty
+            .fee_reserve
+            .checked_add(fee_reserve)
+            .ok_or(ProgramError::ArithmeticOverflow)?;
+
+        ctx.accounts.deposit(amount + fee_reserve)?;
+
+        emit_cpi!(RewardIncreased {
+            bounty: ctx.accounts.bounty.key(),
+            maker: ctx.accounts.maker.key(),
+            mint: ctx.accounts.bounty.mint,
+            amount,
+            reward: ctx.accounts.bounty.reward,
+        });
+        Ok(())
+    }
+
+    fn deposit(&self, amount: u64) -> Result<()> {
+        if self.bounty.is_native() {
+            return transfer(
+                CpiContext::new(
+                    self.system_program.to_account_info(),
+                    Transfer {
+                        from: self.maker.to_account_info(),
+                        to: self.bounty.to_account_info(),
+                    },
+                ),
+                amount,
+            );
+        }
+
+        let (Some(mint), Some(maker_token_account), Some(vault), Some(token_program)) = (
+            &self.mint,
+            &self.maker_token_account,
+            &self.vault,
+            &self.token_program,
+        ) else {
+            return err!(BountyHunterErrors::MissingTokenAccounts);
+        };
+
+        transfer_checked(
+            CpiContext::new(
+                token_program.to_account_info(),
+                TransferChecked {
+                    from: maker_token_account.to_account_info(),
+                    mint: mint.to_account_info(),
+                    to: vault.to_account_info(),
+                    authority: self.maker.to_account_info(),
+                },
+            ),
+            extensions::gross_amount(mint, amount)?,
+            mint.decimals,
+        )?;
+        Ok(())
+    }
+}
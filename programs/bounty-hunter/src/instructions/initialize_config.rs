@@ -0,0 +1,55 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::bpf_loader_upgradeable;
+
+use crate::{
+    constants::MAX_FEE_BPS, error::BountyHunterErrors, events::ConfigUpdated, state::Config,
+};
+
+/// The program's upgrade authority initializes the config and becomes its admin. Run it right after
+/// deploying, nothing can be paid out or slashed before it exists, even without a fee
+#[event_cpi]
+#[derive(Accounts)]
+pub struct InitializeConfig<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        init,
+        payer = admin,
+        space = Config::INIT_SPACE + Config::DISCRIMINATOR.len(),
+        seeds = [b"config"],
+        bump,
+    )]
+    pub config: Account<'info, Config>,
+
+    // keeps anyone but the deployer from claiming the config before it's initialized
+    #[account(
+        seeds = [crate::ID.as_ref()],
+        bump,
+        seeds::program = bpf_loader_upgradeable::ID,
+        constraint = program_data.upgrade_authority_address == Some(admin.key()) @ BountyHunterErrors::InvalidConfigAuthority,
+    )]
+    pub program_data: Account<'info, ProgramData>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl InitializeConfig<'_> {
+    pub fn handler(ctx: Context<InitializeConfig>, fee_bps: u16, treasury: Pubkey) -> Result<()> {
+        require!(fee_bps <= MAX_FEE_BPS, BountyHunterErrors::InvalidFee);
+
+        ctx.accounts.config.set_inner(Config {
+            admin: ctx.accounts.admin.key(),
+            fee_bps,
+            treasury,
+            bump: ctx.bumps.config,
+        });
+
+        emit_cpi!(ConfigUpdated {
+            admin: ctx.accounts.admin.key(),
+            fee_bps,
+            treasury,
+        });
+        Ok(())
+    }
+}
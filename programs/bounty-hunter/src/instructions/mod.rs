@@ -1,9 +1,49 @@
 pub mod accept_solution;
+pub mod add_reward_leg;
+pub mod approve_milestone;
 pub mod cancel_bounty;
+pub mod close_bounty;
+pub mod close_submission;
 pub mod create_bounty;
+pub mod decrease_reward;
+pub mod edit_submission;
+pub mod expire_bounty;
+pub mod fund_bounty;
+pub mod increase_reward;
+pub mod initialize_config;
+pub mod open_dispute;
+pub mod refund_contribution;
+pub mod resolve_dispute;
+pub mod review_submission;
+pub mod set_min_reward;
+pub mod slash_submission;
 pub mod submit_solution;
+pub mod sweep_excess;
+pub mod update_bounty;
+pub mod update_config;
+pub mod withdraw_submission;
 
 pub use accept_solution::*;
+pub use add_reward_leg::*;
+pub use approve_milestone::*;
 pub use cancel_bounty::*;
+pub use close_bounty::*;
+pub use close_submission::*;
 pub use create_bounty::*;
+pub use decrease_reward::*;
+pub use edit_submission::*;
+pub use expire_bounty::*;
+pub use fund_bounty::*;
+pub use increase_reward::*;
+pub use initialize_config::*;
+pub use open_dispute::*;
+pub use refund_contribution::*;
+pub use resolve_dispute::*;
+pub use review_submission::*;
+pub use set_min_reward::*;
+pub use slash_submission::*;
 pub use submit_solution::*;
+pub use sweep_excess::*;
+pub use update_bounty::*;
+pub use update_config::*;
+pub use withdraw_submission::*;
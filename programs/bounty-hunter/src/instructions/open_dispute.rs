@@ -0,0 +1,44 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    error::BountyHunterErrors,
+    events::DisputeOpened,
+    state::{Bounty, BountyStatus, Submission, SubmissionStatus},
+};
+
+/// A hunter escalates their submission to the bounty's arbiter, e.g. when the maker never accepts it
+#[event_cpi]
+#[derive(Accounts)]
+pub struct OpenDispute<'info> {
+    pub hunter: Signer<'info>,
+
+    #[account(
+        mut,
+        constraint = bounty.arbiter.is_some() @ BountyHunterErrors::NoArbiter,
+        constraint = bounty.is_open() @ BountyHunterErrors::BountyClosed,
+        constraint = bounty.dispute.is_none() @ BountyHunterErrors::DisputeAlreadyOpen,
+    )]
+    pub bounty: Account<'info, Bounty>,
+
+    #[account(
+        has_one = bounty @ BountyHunterErrors::BountyAndSubmissionMismatch,
+        has_one = hunter @ BountyHunterErrors::BountyAndSubmissionMismatch,
+        constraint = !bounty.accepted_submissions.contains(&submission.key()) @ BountyHunterErrors::SubmissionAlreadyAccepted,
+        constraint = submission.status != SubmissionStatus::Rejected @ BountyHunterErrors::SubmissionRejected,
+    )]
+    pub submission: Account<'info, Submission>,
+}
+
+impl OpenDispute<'_> {
+    pub fn handler(ctx: Context<OpenDispute>) -> Result<()> {
+        ctx.accounts.bounty.transition(BountyStatus::Disputed)?;
+        ctx.accounts.bounty.dispute = Some(ctx.accounts.submission.key());
+
+        emit_cpi!(DisputeOpened {
+            bounty: ctx.accounts.bounty.key(),
+            submission: ctx.accounts.submission.key(),
+            hunter: ctx.accounts.hunter.key(),
+        });
+        Ok(())
+    }
+}
@@ -0,0 +1,127 @@
+use anchor_lang::prelude::*;
+use anchor_spl::memo::Memo;
+use anchor_spl::token_interface::Mint;
+use anchor_spl::token_interface::TokenAccount;
+use anchor_spl::{associated_token::AssociatedToken, token_interface::TokenInterface};
+
+use crate::{
+    error::BountyHunterErrors,
+    escrow,
+    events::ContributionRefunded,
+    state::{Bounty, Contribution},
+};
+
+/// Permissionless: refunds one funder of a cancelled or expired bounty, or just returns the
+/// contribution rent once the bounty has been paid out
+#[event_cpi]
+#[derive(Accounts)]
+pub struct RefundContribution<'info> {
+    pub payer: Signer<'info>,
+
+    #[account(mut)]
+    pub funder: SystemAccount<'info>,
+
+    #[account(mut)]
+    pub maker: SystemAccount<'info>,
+
+    #[account(
+        mut,
+        has_one = maker @ BountyHunterErrors::InvalidBountyAuthority,
+        constraint = bounty.mint == mint.as_ref().map(|mint| mint.key()).unwrap_or_default() @ BountyHunterErrors::InvalidMint,
+        constraint = !bounty.is_open() @ BountyHunterErrors::BountyNotRefunding,
+    )]
+    pub bounty: Account<'info, Bounty>,
+
+    #[account(
+        mut,
+        close = funder,
+        has_one = bounty @ BountyHunterErrors::InvalidContribution,
+        has_one = funder @ BountyHunterErrors::InvalidContribution,
+    )]
+    pub contribution: Account<'info, Contribution>,
+
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = bounty,
+        associated_token::token_program = token_program,
+    )]
+    pub vault: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    // writable so Token-2022 withheld fees can be harvested to it before the vault is closed
+    #[account(mut)]
+    pub mint: Option<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = funder,
+        associated_token::token_program = token_program,
+    )]
+    pub funder_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+
+    pub associated_token_program: Option<Program<'info, AssociatedToken>>,
+
+    // only needed when a recipient token account requires memos on incoming transfers
+    pub memo_program: Option<Program<'info, Memo>>,
+}
+
+impl RefundContribution<'_> {
+    pub fn handler(ctx: Context<RefundContribution>) -> Result<()> {
+        ctx.accounts.bounty.contributors -= 1;
+
+        // paid out bounties have nothing left to refund
+        let Some(pool) = ctx.accounts.bounty.refund_pool else {
+            return Ok(());
+        };
+
+        let last = ctx.accounts.bounty.contributors == 0;
+
+        // the last funder also takes the rounding dust
+        let amount = if last {
+            ctx.accounts.bounty.escrowed
+        } else {
+            ctx.accounts
+                .bounty
+                .refund_share(pool, ctx.accounts.contribution.amount)?
+        };
+        ctx.accounts.bounty.release(amount)?;
+
+        ctx.accounts.refund(amount, last)?;
+
+        emit_cpi!(ContributionRefunded {
+            bounty: ctx.accounts.bounty.key(),
+            funder: ctx.accounts.funder.key(),
+            mint: ctx.accounts.bounty.mint,
+            amount,
+        });
+        Ok(())
+    }
+
+    fn refund(&self, amount: u64, last: bool) -> Result<()> {
+        let vault = escrow::Vault::of(
+            &self.bounty,
+            &self.vault,
+            &self.mint,
+            &self.token_program,
+            &self.memo_program,
+        )?;
+        escrow::refund(
+            &self.bounty,
+            vault.as_ref(),
+            amount,
+            escrow::Recipient {
+                wallet: &self.funder.to_account_info(),
+                token_account: self.funder_token_account.as_ref(),
+            },
+        )?;
+
+        // excess sent to the vault keeps it open until the maker sweeps it
+        if let Some(vault) = vault.filter(|vault| last && vault.vault.amount == amount) {
+            escrow::close_vault(&self.bounty, &vault, &self.maker.to_account_info())?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,265 @@
+use anchor_lang::prelude::*;
+use anchor_spl::memo::Memo;
+use anchor_spl::token_interface::Mint;
+use anchor_spl::token_interface::TokenAccount;
+use anchor_spl::{associated_token::AssociatedToken, token_interface::TokenInterface};
+
+use crate::{
+    error::BountyHunterErrors,
+    escrow,
+    events::DisputeResolved,
+    reward_legs,
+    state::{
+        Bounty, BountyStatus, Config, HunterProfile, MakerProfile, Receipt, Submission,
+        SubmissionStatus,
+    },
+};
+
+/// The arbiter either accepts the disputed submission on the maker's behalf or sides with the maker,
+/// in which case only that submission is rejected and the bounty goes back to review. Reward legs
+/// come in the remaining accounts when the hunter is awarded, laid out as
+/// `reward_legs::PAYOUT_ACCOUNTS`
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ResolveDispute<'info> {
+    #[account(mut)]
+    pub arbiter: Signer<'info>,
+
+    #[account(mut)]
+    pub maker: SystemAccount<'info>,
+
+    #[account(
+        mut,
+        has_one = maker @ BountyHunterErrors::InvalidBountyAuthority,
+        constraint = bounty.mint == mint.as_ref().map(|mint| mint.key()).unwrap_or_default() @ BountyHunterErrors::InvalidMint,
+        constraint = bounty.arbiter == Some(arbiter.key()) @ BountyHunterErrors::InvalidArbiter,
+        constraint = bounty.is_open() @ BountyHunterErrors::BountyClosed,
+    )]
+    pub bounty: Account<'info, Bounty>,
+
+    #[account(
+        mut,
+        has_one = bounty @ BountyHunterErrors::BountyAndSubmissionMismatch,
+        has_one = hunter @ BountyHunterErrors::BountyAndSubmissionMismatch,
+        constraint = bounty.dispute == Some(submission.key()) @ BountyHunterErrors::NotDisputed,
+    )]
+    pub submission: Account<'info, Submission>,
+
+    // only written when the arbiter awards the hunter
+    #[account(
+        init,
+        payer = arbiter,
+        space = Receipt::INIT_SPACE + Receipt::DISCRIMINATOR.len(),
+        seeds = [b"receipt", bounty.key().as_ref(), &[bounty.payout_index()]],
+        bump,
+    )]
+    pub receipt: Option<Account<'info, Receipt>>,
+    #[account(
+        mut,
+        seeds = [b"maker_profile", maker.key().as_ref()],
+        bump = maker_profile.bump,
+    )]
+    pub maker_profile: Account<'info, MakerProfile>,
+    // only written when the arbiter awards the hunter, created at the arbiter's expense on the
+    // hunter's first win
+    #[account(
+        init_if_needed,
+        payer = arbiter,
+        space = HunterProfile::INIT_SPACE + HunterProfile::DISCRIMINATOR.len(),
+        seeds = [b"hunter_profile", hunter.key().as_ref()],
+        bump,
+    )]
+    pub hunter_profile: Option<Account<'info, HunterProfile>>,
+
+    #[account(mut)]
+    pub hunter: SystemAccount<'info>,
+
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = bounty,
+        associated_token::token_program = token_program,
+    )]
+    pub vault: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    // writable so Token-2022 withheld fees can be harvested to it before the vault is closed
+    #[account(mut)]
+    pub mint: Option<InterfaceAccount<'info, Mint>>,
+
+    // created at the arbiter's expense when the hunter has never held the mint
+    #[account(
+        init_if_needed,
+        payer = arbiter,
+        associated_token::mint = mint,
+        associated_token::authority = hunter,
+        associated_token::token_program = token_program,
+    )]
+    pub hunter_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        mut,
+        address = config.treasury @ BountyHunterErrors::InvalidTreasury,
+    )]
+    pub treasury: SystemAccount<'info>,
+
+    #[account(
+        init_if_needed,
+        payer = arbiter,
+        associated_token::mint = mint,
+        associated_token::authority = treasury,
+        associated_token::token_program = token_program,
+    )]
+    pub treasury_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    pub system_program: Program<'info, System>,
+
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+
+    pub associated_token_program: Option<Program<'info, AssociatedToken>>,
+
+    // only needed when a recipient token account requires memos on incoming transfers
+    pub memo_program: Option<Program<'info, Memo>>,
+}
+
+impl<'info> ResolveDispute<'info> {
+    pub fn handler(
+        ctx: Context<'_, '_, '_, 'info, ResolveDispute<'info>>,
+        award_hunter: bool,
+    ) -> Result<()> {
+        ctx.accounts.bounty.dispute = None;
+
+        if award_hunter {
+            let index = ctx.accounts.bounty.payout_index();
+            let maker = ctx.accounts.maker.to_account_info();
+            reward_legs::pay(
+                &mut ctx.accounts.bounty,
+                ctx.remaining_accounts,
+                ctx.accounts.hunter.key,
+                ctx.accounts
+                    .hunter_profile
+                    .as_mut()
+                    .ok_or(BountyHunterErrors::MissingHunterProfile)?,
+                &ctx.accounts.config,
+                &maker,
+                &ctx.accounts.memo_program,
+            )?;
+            let amount = ctx.accounts.accept_submission()?;
+            let fee = ctx.accounts.config.fee(amount)?;
+            let receipt = Receipt {
+                bounty: ctx.accounts.bounty.key(),
+                submission: ctx.accounts.submission.key(),
+                hunter: ctx.accounts.hunter.key(),
+                mint: ctx.accounts.bounty.mint,
+                index,
+                amount,
+                fee,
+                paid_at: Clock::get()?.unix_timestamp,
+                bump: ctx.bumps.receipt.unwrap_or_default(),
+            };
+            let Some(receipt_account) = ctx.accounts.receipt.as_mut() else {
+                return err!(BountyHunterErrors::MissingReceipt);
+            };
+            receipt_account.set_inner(receipt);
+            ctx.accounts.maker_profile.payouts += 1;
+            let hunter = ctx.accounts.hunter.key();
+            let Some(hunter_profile) = ctx.accounts.hunter_profile.as_mut() else {
+                return err!(BountyHunterErrors::MissingHunterProfile);
+            };
+            hunter_profile.hunter = hunter;
+            hunter_profile.bump = ctx.bumps.hunter_profile.unwrap_or_default();
+            hunter_profile.record_win(
+                ctx.accounts.bounty.mint,
+                amount - fee,
+                Clock::get()?.unix_timestamp,
+            );
+            ctx.accounts.submission.status = SubmissionStatus::Accepted;
+            let status = ctx.accounts.bounty.status_after_payout();
+            ctx.accounts.bounty.transition(status)?;
+            ctx.accounts.transfer_reward(amount)?;
+
+            emit_cpi!(DisputeResolved {
+                bounty: ctx.accounts.bounty.key(),
+                submission: ctx.accounts.submission.key(),
+                arbiter: ctx.accounts.arbiter.key(),
+                award_hunter,
+                amount,
+            });
+            return Ok(());
+        }
+
+        // siding with the maker turns down the disputed submission, everyone else's stays in review
+        // and the bounty keeps its escrow
+        ctx.accounts.submission.status = SubmissionStatus::Rejected;
+        ctx.accounts.bounty.transition(BountyStatus::InReview)?;
+
+        emit_cpi!(DisputeResolved {
+            bounty: ctx.accounts.bounty.key(),
+            submission: ctx.accounts.submission.key(),
+            arbiter: ctx.accounts.arbiter.key(),
+            award_hunter,
+            amount: 0,
+        });
+        Ok(())
+    }
+
+    /// records the disputed submission as the next prize tier, or against the next pending milestone,
+    /// and returns what it is owed
+    fn accept_submission(&mut self) -> Result<u64> {
+        let submission = self.submission.key();
+
+        if self.bounty.milestones.is_empty() {
+            let prize = self.bounty.next_prize()?;
+            self.bounty.release(prize)?;
+            self.bounty.accepted_submissions.push(submission);
+            return Ok(prize);
+        }
+
+        let milestones = &self.bounty.milestones;
+        let unpaid = milestones
+            .iter()
+            .filter(|milestone| milestone.approved_submission.is_none())
+            .count();
+        let index = milestones
+            .iter()
+            .position(|milestone| milestone.approved_submission.is_none())
+            .ok_or(BountyHunterErrors::BountyClosed)?;
+        // the last milestone releases whatever is left in escrow
+        let amount = if unpaid == 1 {
+            self.bounty.escrowed
+        } else {
+            milestones[index].amount
+        };
+        self.bounty.release(amount)?;
+        self.bounty.milestones[index].approved_submission = Some(submission);
+        Ok(amount)
+    }
+
+    fn transfer_reward(&mut self, amount: u64) -> Result<()> {
+        let vault = escrow::Vault::of(
+            &self.bounty,
+            &self.vault,
+            &self.mint,
+            &self.token_program,
+            &self.memo_program,
+        )?;
+        // the protocol fee comes out of the hunter's payout
+        escrow::pay(
+            &mut self.bounty,
+            vault.as_ref(),
+            &self.config,
+            amount,
+            escrow::Recipient {
+                wallet: &self.hunter.to_account_info(),
+                token_account: self.hunter_token_account.as_ref(),
+            },
+            escrow::Recipient {
+                wallet: &self.treasury.to_account_info(),
+                token_account: self.treasury_token_account.as_ref(),
+            },
+            &self.maker.to_account_info(),
+        )
+    }
+}
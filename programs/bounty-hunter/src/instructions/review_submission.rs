@@ -0,0 +1,39 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    error::BountyHunterErrors,
+    events::SubmissionReviewed,
+    state::{Bounty, Submission, SubmissionStatus},
+};
+
+/// The maker locks a submission before reviewing it, the hunter can't edit it afterwards
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ReviewSubmission<'info> {
+    pub maker: Signer<'info>,
+
+    #[account(
+        has_one = maker @ BountyHunterErrors::InvalidBountyAuthority,
+        constraint = bounty.is_open() @ BountyHunterErrors::BountyClosed,
+    )]
+    pub bounty: Account<'info, Bounty>,
+
+    #[account(
+        mut,
+        has_one = bounty @ BountyHunterErrors::BountyAndSubmissionMismatch,
+        constraint = submission.status == SubmissionStatus::Pending @ BountyHunterErrors::SubmissionLocked,
+    )]
+    pub submission: Account<'info, Submission>,
+}
+
+impl ReviewSubmission<'_> {
+    pub fn handler(ctx: Context<ReviewSubmission>) -> Result<()> {
+        ctx.accounts.submission.status = SubmissionStatus::InReview;
+
+        emit_cpi!(SubmissionReviewed {
+            bounty: ctx.accounts.bounty.key(),
+            submission: ctx.accounts.submission.key(),
+        });
+        Ok(())
+    }
+}
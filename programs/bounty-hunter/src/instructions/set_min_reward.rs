@@ -0,0 +1,47 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    error::BountyHunterErrors,
+    events::MinRewardSet,
+    state::{Config, MinReward},
+};
+
+/// The admin sets the smallest reward new bounties in `mint` can offer, zero lifts the minimum
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(mint: Pubkey)]
+pub struct SetMinReward<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = admin @ BountyHunterErrors::InvalidConfigAuthority,
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = MinReward::INIT_SPACE + MinReward::DISCRIMINATOR.len(),
+        seeds = [b"min_reward", mint.as_ref()],
+        bump,
+    )]
+    pub min_reward: Account<'info, MinReward>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl SetMinReward<'_> {
+    pub fn handler(ctx: Context<SetMinReward>, mint: Pubkey, amount: u64) -> Result<()> {
+        ctx.accounts.min_reward.set_inner(MinReward {
+            mint,
+            amount,
+            bump: ctx.bumps.min_reward,
+        });
+
+        emit_cpi!(MinRewardSet { mint, amount });
+        Ok(())
+    }
+}
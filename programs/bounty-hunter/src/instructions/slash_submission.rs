@@ -0,0 +1,62 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    error::BountyHunterErrors,
+    events::SubmissionSlashed,
+    state::{Bounty, Config, Submission, SubmissionStatus},
+};
+
+/// The maker throws out a spam submission, its bond goes to the treasury rather than the maker so
+/// slashing never pays and the rent goes back to the hunter. It still counts in `Bounty.submissions`,
+/// so slashing can't clear the way to cancelling the bounty or decreasing its reward
+#[event_cpi]
+#[derive(Accounts)]
+pub struct SlashSubmission<'info> {
+    pub maker: Signer<'info>,
+
+    #[account(
+        mut,
+        has_one = maker @ BountyHunterErrors::InvalidBountyAuthority,
+        constraint = bounty.is_open() @ BountyHunterErrors::BountyClosed,
+        constraint = bounty.submission_bond > 0 @ BountyHunterErrors::NoSubmissionBond,
+        constraint = bounty.dispute != Some(submission.key()) @ BountyHunterErrors::BountyDisputed,
+    )]
+    pub bounty: Account<'info, Bounty>,
+
+    #[account(
+        mut,
+        close = hunter,
+        has_one = bounty @ BountyHunterErrors::BountyAndSubmissionMismatch,
+        has_one = hunter @ BountyHunterErrors::BountyAndSubmissionMismatch,
+        constraint = submission.status != SubmissionStatus::Accepted @ BountyHunterErrors::SubmissionAlreadyAccepted,
+    )]
+    pub submission: Account<'info, Submission>,
+
+    #[account(mut)]
+    pub hunter: SystemAccount<'info>,
+
+    #[account(seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        mut,
+        address = config.treasury @ BountyHunterErrors::InvalidTreasury,
+    )]
+    pub treasury: SystemAccount<'info>,
+}
+
+impl SlashSubmission<'_> {
+    pub fn handler(ctx: Context<SlashSubmission>) -> Result<()> {
+        let bond = ctx.accounts.submission.bond;
+        ctx.accounts.submission.sub_lamports(bond)?;
+        ctx.accounts.treasury.add_lamports(bond)?;
+
+        emit_cpi!(SubmissionSlashed {
+            bounty: ctx.accounts.bounty.key(),
+            submission: ctx.accounts.submission.key(),
+            hunter: ctx.accounts.hunter.key(),
+            bond,
+        });
+        Ok(())
+    }
+}
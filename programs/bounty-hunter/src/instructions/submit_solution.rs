@@ -1,16 +1,28 @@
-use crate::state::{Bounty, Submission};
+use crate::{
+    error::BountyHunterErrors,
+    events::SolutionSubmitted,
+    state::{Bounty, BountyStatus, Submission, SubmissionStatus},
+    validation,
+};
 use anchor_lang::prelude::*;
+use anchor_lang::system_program::{transfer, Transfer};
 
+#[event_cpi]
 #[derive(Accounts)]
+#[instruction(link: String, notes: String)]
 pub struct SubmitSolution<'info> {
     #[account(mut)]
     pub hunter: Signer<'info>,
-    #[account()]
+    #[account(
+        mut,
+        constraint = bounty.is_open() @ BountyHunterErrors::BountyClosed,
+        constraint = !bounty.is_expired(Clock::get()?.unix_timestamp) @ BountyHunterErrors::BountyExpired
+    )]
     pub bounty: Account<'info, Bounty>,
     #[account(
-        init_if_needed,
+        init,
         payer = hunter,
-        space = Submission::INIT_SPACE + Submission::DISCRIMINATOR.len(),
+        space = Submission::space(&link, &notes),
         seeds = [b"submission", hunter.key().as_ref(), bounty.key().as_ref()],
         bump,
     )]
@@ -20,12 +32,59 @@ pub struct SubmitSolution<'info> {
 }
 
 impl SubmitSolution<'_> {
-    pub fn handler(ctx: Context<SubmitSolution>, link: String, notes: String) -> Result<()> {
+    pub fn handler(
+        ctx: Context<SubmitSolution>,
+        link: String,
+        notes: String,
+        proof: Vec<[u8; 32]>,
+        content_hash: Option<[u8; 32]>,
+    ) -> Result<()> {
+        validation::check_link(&link)?;
+        validation::check_notes(&notes)?;
+        require!(
+            ctx.accounts
+                .bounty
+                .access
+                .allows(&ctx.accounts.hunter.key(), &proof),
+            BountyHunterErrors::HunterNotAllowed
+        );
+
+        ctx.accounts.bounty.submissions += 1;
+        // an open dispute stays open while other hunters keep submitting
+        if ctx.accounts.bounty.status == BountyStatus::Open {
+            ctx.accounts.bounty.transition(BountyStatus::InReview)?;
+        }
+
         ctx.accounts.submission.set_inner(Submission {
             bounty: ctx.accounts.bounty.key(),
             link,
             hunter: ctx.accounts.hunter.key(),
             notes,
+            status: SubmissionStatus::Pending,
+            edits: 0,
+            updated_at: Clock::get()?.unix_timestamp,
+            bond: ctx.accounts.bounty.submission_bond,
+            content_hash,
+        });
+
+        // held by the submission itself, closing it returns the bond along with the rent
+        if ctx.accounts.bounty.submission_bond > 0 {
+            transfer(
+                CpiContext::new(
+                    ctx.accounts.system_program.to_account_info(),
+                    Transfer {
+                        from: ctx.accounts.hunter.to_account_info(),
+                        to: ctx.accounts.submission.to_account_info(),
+                    },
+                ),
+                ctx.accounts.bounty.submission_bond,
+            )?;
+        }
+
+        emit_cpi!(SolutionSubmitted {
+            bounty: ctx.accounts.bounty.key(),
+            submission: ctx.accounts.submission.key(),
+            hunter: ctx.accounts.hunter.key(),
         });
         Ok(())
     }
@@ -0,0 +1,136 @@
+use anchor_lang::prelude::*;
+use anchor_spl::memo::Memo;
+use anchor_spl::token_interface::Mint;
+use anchor_spl::token_interface::TokenAccount;
+use anchor_spl::{associated_token::AssociatedToken, token_interface::TokenInterface};
+
+use crate::{error::BountyHunterErrors, escrow, events::ExcessSwept, reward_legs, state::Bounty};
+
+/// Sends anything held above `Bounty.escrowed` back to the maker. Reward leg vaults are swept from
+/// the remaining accounts, see `reward_legs::REFUND_ACCOUNTS`
+#[event_cpi]
+#[derive(Accounts)]
+pub struct SweepExcess<'info> {
+    #[account(mut)]
+    pub maker: Signer<'info>,
+
+    #[account(
+        mut,
+        has_one = maker @ BountyHunterErrors::InvalidBountyAuthority,
+        constraint = bounty.mint == mint.as_ref().map(|mint| mint.key()).unwrap_or_default() @ BountyHunterErrors::InvalidMint,
+    )]
+    pub bounty: Account<'info, Bounty>,
+
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = bounty,
+        associated_token::token_program = token_program,
+    )]
+    pub vault: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    // writable so Token-2022 withheld fees can be harvested to it before the vault is closed
+    #[account(mut)]
+    pub mint: Option<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = maker,
+        associated_token::token_program = token_program,
+    )]
+    pub maker_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+
+    pub associated_token_program: Option<Program<'info, AssociatedToken>>,
+
+    // only needed when a recipient token account requires memos on incoming transfers
+    pub memo_program: Option<Program<'info, Memo>>,
+}
+
+impl<'info> SweepExcess<'info> {
+    pub fn handler(ctx: Context<'_, '_, '_, 'info, SweepExcess<'info>>) -> Result<()> {
+        let amount = ctx.accounts.sweep()?;
+        let maker = ctx.accounts.maker.to_account_info();
+        let legs = reward_legs::sweep(
+            &ctx.accounts.bounty,
+            ctx.remaining_accounts,
+            &maker,
+            &ctx.accounts.memo_program,
+        )?;
+        require!(
+            amount > 0 || !legs.is_empty(),
+            BountyHunterErrors::NothingToSweep
+        );
+
+        if amount > 0 {
+            emit_cpi!(ExcessSwept {
+                bounty: ctx.accounts.bounty.key(),
+                maker: ctx.accounts.maker.key(),
+                mint: ctx.accounts.bounty.mint,
+                amount,
+            });
+        }
+        for (mint, amount) in legs {
+            emit_cpi!(ExcessSwept {
+                bounty: ctx.accounts.bounty.key(),
+                maker: ctx.accounts.maker.key(),
+                mint,
+                amount,
+            });
+        }
+        Ok(())
+    }
+
+    /// returns the amount swept
+    fn sweep(&mut self) -> Result<u64> {
+        if !self.bounty.is_native() && self.vault.is_none() {
+            // closed with the last payout, only reward legs can still hold excess
+            return Ok(0);
+        }
+        let vault = escrow::Vault::of(
+            &self.bounty,
+            &self.vault,
+            &self.mint,
+            &self.token_program,
+            &self.memo_program,
+        )?;
+        let maker = self.maker.to_account_info();
+        let recipient = escrow::Recipient {
+            wallet: &maker,
+            token_account: self.maker_token_account.as_ref(),
+        };
+
+        let Some(vault) = vault else {
+            let bounty = self.bounty.to_account_info();
+            let rent = Rent::get()?.minimum_balance(bounty.data_len());
+            let excess = bounty
+                .lamports()
+                .saturating_sub(rent)
+                .saturating_sub(self.bounty.escrowed);
+            if excess > 0 {
+                escrow::refund(&self.bounty, None, excess, recipient)?;
+            }
+            return Ok(excess);
+        };
+
+        // the fee reserve is only held back while hunters can still be paid
+        if !self.bounty.is_open() {
+            self.bounty.fee_reserve = 0;
+        }
+        let owed = self.bounty.escrowed + self.bounty.fee_reserve;
+        let excess = vault.vault.amount.saturating_sub(owed);
+        if excess == 0 {
+            return Ok(0);
+        }
+
+        escrow::refund(&self.bounty, Some(&vault), excess, recipient)?;
+
+        // close the vault once nothing is owed from it anymore
+        if self.bounty.escrowed == 0 && !self.bounty.is_open() {
+            escrow::close_vault(&self.bounty, &vault, &maker)?;
+        }
+        Ok(excess)
+    }
+}
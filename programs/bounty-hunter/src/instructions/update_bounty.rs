@@ -0,0 +1,57 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    error::BountyHunterErrors, events::BountyUpdated, resize::resize, state::Bounty, validation,
+};
+
+/// The maker amends `description` and `link` until the first payout, the bounty is resized to fit
+#[event_cpi]
+#[derive(Accounts)]
+pub struct UpdateBounty<'info> {
+    #[account(mut)]
+    pub maker: Signer<'info>,
+
+    #[account(
+        mut,
+        has_one = maker @ BountyHunterErrors::InvalidBountyAuthority,
+        constraint = bounty.is_open() @ BountyHunterErrors::BountyClosed,
+        constraint = bounty.dispute.is_none() @ BountyHunterErrors::BountyDisputed,
+        constraint = !bounty.has_payouts() @ BountyHunterErrors::BountyHasPayouts,
+    )]
+    pub bounty: Account<'info, Bounty>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl UpdateBounty<'_> {
+    pub fn handler(
+        ctx: Context<UpdateBounty>,
+        description: String,
+        link: String,
+        content_hash: Option<[u8; 32]>,
+    ) -> Result<()> {
+        validation::check_description(&description)?;
+        validation::check_link(&link)?;
+
+        resize(
+            &ctx.accounts.bounty.to_account_info(),
+            &ctx.accounts.maker.to_account_info(),
+            &ctx.accounts.system_program,
+            Bounty::space(&description, &link),
+        )?;
+
+        let bounty = &mut ctx.accounts.bounty;
+        bounty.description = description;
+        bounty.link = link;
+        bounty.content_hash = content_hash;
+        bounty.edits += 1;
+        bounty.updated_slot = Clock::get()?.slot;
+
+        emit_cpi!(BountyUpdated {
+            bounty: ctx.accounts.bounty.key(),
+            maker: ctx.accounts.maker.key(),
+            edits: ctx.accounts.bounty.edits,
+        });
+        Ok(())
+    }
+}
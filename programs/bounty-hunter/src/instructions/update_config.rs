@@ -0,0 +1,42 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::MAX_FEE_BPS, error::BountyHunterErrors, events::ConfigUpdated, state::Config,
+};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct UpdateConfig<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = admin @ BountyHunterErrors::InvalidConfigAuthority,
+    )]
+    pub config: Account<'info, Config>,
+}
+
+impl UpdateConfig<'_> {
+    pub fn handler(
+        ctx: Context<UpdateConfig>,
+        admin: Pubkey,
+        fee_bps: u16,
+        treasury: Pubkey,
+    ) -> Result<()> {
+        require!(fee_bps <= MAX_FEE_BPS, BountyHunterErrors::InvalidFee);
+
+        let config = &mut ctx.accounts.config;
+        config.admin = admin;
+        config.fee_bps = fee_bps;
+        config.treasury = treasury;
+
+        emit_cpi!(ConfigUpdated {
+            admin,
+            fee_bps,
+            treasury,
+        });
+        Ok(())
+    }
+}
@@ -0,0 +1,51 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    error::BountyHunterErrors,
+    events::SubmissionWithdrawn,
+    state::{Bounty, BountyStatus, Submission, SubmissionStatus},
+};
+
+/// The hunter pulls a submission that hasn't been paid and gets its rent and bond back
+#[event_cpi]
+#[derive(Accounts)]
+pub struct WithdrawSubmission<'info> {
+    #[account(mut)]
+    pub hunter: Signer<'info>,
+
+    #[account(
+        mut,
+        constraint = bounty.is_open() @ BountyHunterErrors::BountyClosed,
+        constraint = bounty.dispute != Some(submission.key()) @ BountyHunterErrors::BountyDisputed,
+    )]
+    pub bounty: Account<'info, Bounty>,
+
+    #[account(
+        mut,
+        close = hunter,
+        has_one = bounty @ BountyHunterErrors::BountyAndSubmissionMismatch,
+        has_one = hunter @ BountyHunterErrors::BountyAndSubmissionMismatch,
+        constraint = submission.status != SubmissionStatus::Accepted @ BountyHunterErrors::SubmissionAlreadyAccepted,
+    )]
+    pub submission: Account<'info, Submission>,
+}
+
+impl WithdrawSubmission<'_> {
+    pub fn handler(ctx: Context<WithdrawSubmission>) -> Result<()> {
+        ctx.accounts.bounty.submissions -= 1;
+
+        // with nothing left to review the maker may cancel again
+        if ctx.accounts.bounty.submissions == 0
+            && ctx.accounts.bounty.status == BountyStatus::InReview
+        {
+            ctx.accounts.bounty.transition(BountyStatus::Open)?;
+        }
+
+        emit_cpi!(SubmissionWithdrawn {
+            bounty: ctx.accounts.bounty.key(),
+            submission: ctx.accounts.submission.key(),
+            hunter: ctx.accounts.hunter.key(),
+        });
+        Ok(())
+    }
+}
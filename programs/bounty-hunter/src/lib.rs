@@ -1,12 +1,19 @@
 pub mod constants;
 pub mod error;
+pub mod escrow;
+pub mod events;
+pub mod extensions;
 pub mod instructions;
+pub mod resize;
+pub mod reward_legs;
 pub mod state;
+pub mod validation;
 
 use anchor_lang::prelude::*;
 
 pub use constants::*;
 pub use instructions::*;
+use state::BountyAccess;
 
 declare_id!("ELt3SqpiHUsHJ5fxZpH1ksug6nWjAvYBxxKqK5PHfkBa");
 
@@ -14,26 +21,137 @@ declare_id!("ELt3SqpiHUsHJ5fxZpH1ksug6nWjAvYBxxKqK5PHfkBa");
 pub mod bounty_hunter {
     use super::*;
 
+    pub fn initialize_config(
+        ctx: Context<InitializeConfig>,
+        fee_bps: u16,
+        treasury: Pubkey,
+    ) -> Result<()> {
+        InitializeConfig::handler(ctx, fee_bps, treasury)
+    }
+    pub fn update_config(
+        ctx: Context<UpdateConfig>,
+        admin: Pubkey,
+        fee_bps: u16,
+        treasury: Pubkey,
+    ) -> Result<()> {
+        UpdateConfig::handler(ctx, admin, fee_bps, treasury)
+    }
+    pub fn set_min_reward(ctx: Context<SetMinReward>, mint: Pubkey, amount: u64) -> Result<()> {
+        SetMinReward::handler(ctx, mint, amount)
+    }
+    #[allow(clippy::too_many_arguments)]
     pub fn create_bounty(
         ctx: Context<CreateBounty>,
         seed: u64,
         description: String,
         link: String,
         reward: u64,
+        deadline: Option<i64>,
+        prize_bps: Vec<u16>,
+        milestones: Vec<MilestoneArgs>,
+        arbiter: Option<Pubkey>,
+        access: BountyAccess,
+        submission_bond: u64,
+        content_hash: Option<[u8; 32]>,
+    ) -> Result<()> {
+        CreateBounty::handler(
+            ctx,
+            seed,
+            description,
+            link,
+            reward,
+            deadline,
+            prize_bps,
+            milestones,
+            arbiter,
+            access,
+            submission_bond,
+            content_hash,
+        )
+    }
+    pub fn update_bounty(
+        ctx: Context<UpdateBounty>,
+        description: String,
+        link: String,
+        content_hash: Option<[u8; 32]>,
     ) -> Result<()> {
-        CreateBounty::handler(ctx, seed, description, link, reward)
+        UpdateBounty::handler(ctx, description, link, content_hash)
+    }
+    pub fn add_reward_leg(ctx: Context<AddRewardLeg>, amount: u64) -> Result<()> {
+        AddRewardLeg::handler(ctx, amount)
     }
-    pub fn cancel_bounty(ctx: Context<CancelBounty>) -> Result<()> {
+    pub fn cancel_bounty<'info>(
+        ctx: Context<'_, '_, '_, 'info, CancelBounty<'info>>,
+    ) -> Result<()> {
         CancelBounty::handler(ctx)
     }
     pub fn submit_solution(
         ctx: Context<SubmitSolution>,
         link: String,
         notes: String,
+        proof: Vec<[u8; 32]>,
+        content_hash: Option<[u8; 32]>,
+    ) -> Result<()> {
+        SubmitSolution::handler(ctx, link, notes, proof, content_hash)
+    }
+    pub fn edit_submission(
+        ctx: Context<EditSubmission>,
+        link: String,
+        notes: String,
+        content_hash: Option<[u8; 32]>,
     ) -> Result<()> {
-        SubmitSolution::handler(ctx, link, notes)
+        EditSubmission::handler(ctx, link, notes, content_hash)
+    }
+    pub fn withdraw_submission(ctx: Context<WithdrawSubmission>) -> Result<()> {
+        WithdrawSubmission::handler(ctx)
     }
-    pub fn accept_solution(ctx: Context<AcceptSolution>) -> Result<()> {
+    pub fn close_submission(ctx: Context<CloseSubmission>) -> Result<()> {
+        CloseSubmission::handler(ctx)
+    }
+    pub fn review_submission(ctx: Context<ReviewSubmission>) -> Result<()> {
+        ReviewSubmission::handler(ctx)
+    }
+    pub fn slash_submission(ctx: Context<SlashSubmission>) -> Result<()> {
+        SlashSubmission::handler(ctx)
+    }
+    pub fn accept_solution<'info>(
+        ctx: Context<'_, '_, '_, 'info, AcceptSolution<'info>>,
+    ) -> Result<()> {
         AcceptSolution::handler(ctx)
     }
+    pub fn approve_milestone(ctx: Context<ApproveMilestone>, index: u8) -> Result<()> {
+        ApproveMilestone::handler(ctx, index)
+    }
+    pub fn fund_bounty(ctx: Context<FundBounty>, amount: u64) -> Result<()> {
+        FundBounty::handler(ctx, amount)
+    }
+    pub fn increase_reward(ctx: Context<IncreaseReward>, amount: u64) -> Result<()> {
+        IncreaseReward::handler(ctx, amount)
+    }
+    pub fn decrease_reward(ctx: Context<DecreaseReward>, amount: u64) -> Result<()> {
+        DecreaseReward::handler(ctx, amount)
+    }
+    pub fn refund_contribution(ctx: Context<RefundContribution>) -> Result<()> {
+        RefundContribution::handler(ctx)
+    }
+    pub fn sweep_excess<'info>(ctx: Context<'_, '_, '_, 'info, SweepExcess<'info>>) -> Result<()> {
+        SweepExcess::handler(ctx)
+    }
+    pub fn expire_bounty<'info>(
+        ctx: Context<'_, '_, '_, 'info, ExpireBounty<'info>>,
+    ) -> Result<()> {
+        ExpireBounty::handler(ctx)
+    }
+    pub fn close_bounty<'info>(ctx: Context<'_, '_, '_, 'info, CloseBounty<'info>>) -> Result<()> {
+        CloseBounty::handler(ctx)
+    }
+    pub fn open_dispute(ctx: Context<OpenDispute>) -> Result<()> {
+        OpenDispute::handler(ctx)
+    }
+    pub fn resolve_dispute<'info>(
+        ctx: Context<'_, '_, '_, 'info, ResolveDispute<'info>>,
+        award_hunter: bool,
+    ) -> Result<()> {
+        ResolveDispute::handler(ctx, award_hunter)
+    }
 }
@@ -0,0 +1,36 @@
+//! Resizing accounts sized to their content. Anchor's `realloc` settles every lamport above the
+//! rent minimum with the payer, which would move a native bounty's escrow or a submission's bond,
+//! so only the rent difference changes hands here
+
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{transfer, Transfer};
+
+pub fn resize<'info>(
+    account: &AccountInfo<'info>,
+    payer: &AccountInfo<'info>,
+    system_program: &Program<'info, System>,
+    space: usize,
+) -> Result<()> {
+    let rent = Rent::get()?;
+    let current = rent.minimum_balance(account.data_len());
+    let required = rent.minimum_balance(space);
+
+    if required > current {
+        transfer(
+            CpiContext::new(
+                system_program.to_account_info(),
+                Transfer {
+                    from: payer.clone(),
+                    to: account.clone(),
+                },
+            ),
+            required - current,
+        )?;
+    } else {
+        account.sub_lamports(current - required)?;
+        payer.add_lamports(current - required)?;
+    }
+
+    account.realloc(space, false)?;
+    Ok(())
+}
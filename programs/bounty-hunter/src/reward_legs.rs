@@ -0,0 +1,237 @@
+//! Extra reward legs paid next to the main reward, each from its own vault. Their accounts come in
+//! through the remaining accounts, one fixed size group per leg in the order of `Bounty::reward_legs`
+
+use anchor_lang::prelude::*;
+use anchor_lang::Ids;
+use anchor_spl::associated_token::get_associated_token_address_with_program_id;
+use anchor_spl::memo::Memo;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use crate::{
+    error::BountyHunterErrors,
+    escrow, extensions,
+    state::{Bounty, Config, HunterProfile, RewardLeg},
+};
+
+/// `[mint, vault, hunter token account, treasury token account, token program]`
+pub const PAYOUT_ACCOUNTS: usize = 5;
+
+/// `[mint, vault, maker token account, token program]`
+pub const REFUND_ACCOUNTS: usize = 4;
+
+/// pays every leg's share of the next prize tier to `hunter` and adds it to their earnings, the
+/// protocol fee is taken from each leg like from the main reward
+pub fn pay<'info>(
+    bounty: &mut Account<'info, Bounty>,
+    remaining_accounts: &[AccountInfo<'info>],
+    hunter: &Pubkey,
+    hunter_profile: &mut HunterProfile,
+    config: &Config,
+    maker: &AccountInfo<'info>,
+    memo_program: &Option<Program<'info, Memo>>,
+) -> Result<()> {
+    require!(
+        remaining_accounts.len() == bounty.reward_legs.len() * PAYOUT_ACCOUNTS,
+        BountyHunterErrors::MissingRewardLegAccounts
+    );
+
+    for (index, accounts) in remaining_accounts.chunks(PAYOUT_ACCOUNTS).enumerate() {
+        let [mint, vault, hunter_token_account, treasury_token_account, token_program] = accounts
+        else {
+            return err!(BountyHunterErrors::MissingRewardLegAccounts);
+        };
+        let leg = bounty.reward_legs[index].clone();
+        // fully paid out, its vault may already be closed
+        if leg.escrowed == 0 {
+            check_vault(&bounty.key(), &leg, mint, vault, token_program)?;
+            continue;
+        }
+        let (decimals, vault_amount) = check_leg(&bounty.key(), &leg, mint, vault, token_program)?;
+        check_token_account(hunter_token_account, &leg.mint, hunter)?;
+
+        let amount = bounty.next_leg_prize(index)?;
+        let fee = config.fee(amount)?;
+        bounty.reward_legs[index].escrowed = leg
+            .escrowed
+            .checked_sub(amount)
+            .ok_or(ProgramError::ArithmeticOverflow)?;
+
+        extensions::memo_if_required(memo_program, hunter_token_account)?;
+        escrow::transfer(
+            bounty,
+            token_program,
+            mint,
+            vault,
+            hunter_token_account,
+            amount - fee,
+            decimals,
+        )?;
+        if fee > 0 {
+            check_token_account(treasury_token_account, &leg.mint, &config.treasury)?;
+            extensions::memo_if_required(memo_program, treasury_token_account)?;
+            escrow::transfer(
+                bounty,
+                token_program,
+                mint,
+                vault,
+                treasury_token_account,
+                fee,
+                decimals,
+            )?;
+        }
+        hunter_profile.record_earnings(leg.mint, amount - fee);
+
+        // the last prize tier empties the vault, unless someone sent it more than the leg
+        if bounty.reward_legs[index].escrowed == 0 && vault_amount == amount {
+            escrow::close(bounty, token_program, vault, maker)?;
+        }
+    }
+    Ok(())
+}
+
+/// sends every leg still escrowed back to the maker in full and closes its vault
+pub fn refund<'info>(
+    bounty: &mut Account<'info, Bounty>,
+    remaining_accounts: &[AccountInfo<'info>],
+    maker: &AccountInfo<'info>,
+    memo_program: &Option<Program<'info, Memo>>,
+) -> Result<()> {
+    require!(
+        remaining_accounts.len() == bounty.reward_legs.len() * REFUND_ACCOUNTS,
+        BountyHunterErrors::MissingRewardLegAccounts
+    );
+
+    for (index, accounts) in remaining_accounts.chunks(REFUND_ACCOUNTS).enumerate() {
+        let [mint, vault, maker_token_account, token_program] = accounts else {
+            return err!(BountyHunterErrors::MissingRewardLegAccounts);
+        };
+        let leg = bounty.reward_legs[index].clone();
+        // paid out already, any excess left in its vault is for `sweep`
+        if leg.escrowed == 0 {
+            check_vault(&bounty.key(), &leg, mint, vault, token_program)?;
+            continue;
+        }
+        let (decimals, vault_amount) = check_leg(&bounty.key(), &leg, mint, vault, token_program)?;
+        check_token_account(maker_token_account, &leg.mint, maker.key)?;
+
+        bounty.reward_legs[index].escrowed = 0;
+
+        // anything sent to the vault on top of the leg goes back too
+        extensions::memo_if_required(memo_program, maker_token_account)?;
+        escrow::transfer(
+            bounty,
+            token_program,
+            mint,
+            vault,
+            maker_token_account,
+            vault_amount,
+            decimals,
+        )?;
+        escrow::close(bounty, token_program, vault, maker)?;
+    }
+    Ok(())
+}
+
+/// sends whatever each leg's vault holds on top of `RewardLeg::escrowed` back to the maker and
+/// closes the vault once the leg has been paid out or refunded. Vaults already closed with their
+/// last payout are skipped. Returns the mint and amount of every leg that had excess
+pub fn sweep<'info>(
+    bounty: &Account<'info, Bounty>,
+    remaining_accounts: &[AccountInfo<'info>],
+    maker: &AccountInfo<'info>,
+    memo_program: &Option<Program<'info, Memo>>,
+) -> Result<Vec<(Pubkey, u64)>> {
+    require!(
+        remaining_accounts.len() == bounty.reward_legs.len() * REFUND_ACCOUNTS,
+        BountyHunterErrors::MissingRewardLegAccounts
+    );
+
+    let mut swept = Vec::new();
+    for (leg, accounts) in bounty
+        .reward_legs
+        .iter()
+        .zip(remaining_accounts.chunks(REFUND_ACCOUNTS))
+    {
+        let [mint, vault, maker_token_account, token_program] = accounts else {
+            return err!(BountyHunterErrors::MissingRewardLegAccounts);
+        };
+        check_vault(&bounty.key(), leg, mint, vault, token_program)?;
+        if vault.data_is_empty() {
+            continue;
+        }
+        let (decimals, vault_amount) = read_leg(mint, vault)?;
+
+        let excess = vault_amount.saturating_sub(leg.escrowed);
+        if excess > 0 {
+            check_token_account(maker_token_account, &leg.mint, maker.key)?;
+            extensions::memo_if_required(memo_program, maker_token_account)?;
+            escrow::transfer(
+                bounty,
+                token_program,
+                mint,
+                vault,
+                maker_token_account,
+                excess,
+                decimals,
+            )?;
+            swept.push((leg.mint, excess));
+        }
+
+        if leg.escrowed == 0 {
+            escrow::close(bounty, token_program, vault, maker)?;
+        }
+    }
+    Ok(swept)
+}
+
+/// returns the mint decimals and what the vault holds
+fn check_leg(
+    bounty: &Pubkey,
+    leg: &RewardLeg,
+    mint: &AccountInfo,
+    vault: &AccountInfo,
+    token_program: &AccountInfo,
+) -> Result<(u8, u64)> {
+    check_vault(bounty, leg, mint, vault, token_program)?;
+    read_leg(mint, vault)
+}
+
+fn check_vault(
+    bounty: &Pubkey,
+    leg: &RewardLeg,
+    mint: &AccountInfo,
+    vault: &AccountInfo,
+    token_program: &AccountInfo,
+) -> Result<()> {
+    require_keys_eq!(*mint.key, leg.mint, BountyHunterErrors::InvalidMint);
+    require!(
+        TokenInterface::ids().contains(token_program.key) && mint.owner == token_program.key,
+        BountyHunterErrors::InvalidRewardLegAccount
+    );
+    require_keys_eq!(
+        *vault.key,
+        get_associated_token_address_with_program_id(bounty, mint.key, token_program.key),
+        BountyHunterErrors::InvalidRewardLegAccount
+    );
+    Ok(())
+}
+
+fn read_leg(mint: &AccountInfo, vault: &AccountInfo) -> Result<(u8, u64)> {
+    let decimals = Mint::try_deserialize(&mut mint.try_borrow_data()?.as_ref())?.decimals;
+    let vault_amount =
+        TokenAccount::try_deserialize(&mut vault.try_borrow_data()?.as_ref())?.amount;
+    Ok((decimals, vault_amount))
+}
+
+fn check_token_account(token_account: &AccountInfo, mint: &Pubkey, owner: &Pubkey) -> Result<()> {
+    require!(
+        TokenInterface::ids().contains(token_account.owner),
+        BountyHunterErrors::InvalidRewardLegAccount
+    );
+    let account = TokenAccount::try_deserialize(&mut token_account.try_borrow_data()?.as_ref())?;
+    require!(
+        account.mint == *mint && account.owner == *owner,
+        BountyHunterErrors::InvalidRewardLegAccount
+    );
+    Ok(())
+}
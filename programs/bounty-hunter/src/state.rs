@@ -1,27 +1,443 @@
 use anchor_lang::prelude::*;
 
+use anchor_lang::solana_program::hash::hashv;
+
+use crate::constants::{
+    BPS_DENOMINATOR, MAX_ALLOWED_HUNTERS, MAX_DESCRIPTION_LEN, MAX_LINK_LEN, MAX_MILESTONES,
+    MAX_MILESTONE_DESCRIPTION_LEN, MAX_NOTES_LEN, MAX_PROFILE_MINTS, MAX_REWARD_LEGS, MAX_WINNERS,
+};
+use crate::error::BountyHunterErrors;
+
+/// sized to its `description` and `link`, see `Bounty::space`
 #[derive(InitSpace)]
 #[account(discriminator = 1)]
 pub struct Bounty {
     pub seed: u64,
-    #[max_len(1024)]
+    /// the description itself, or the URI of a metadata document when `content_hash` is set
+    #[max_len(MAX_DESCRIPTION_LEN)]
     pub description: String,
-    #[max_len(100)]
+    #[max_len(MAX_LINK_LEN)]
     pub link: String,
+    /// `Pubkey::default()` for native SOL bounties
     pub mint: Pubkey,
+    /// advertised reward, changed only by the maker through `increase_reward` and `decrease_reward`
     pub reward: u64,
     pub bump: u8,
     pub maker: Pubkey,
-    pub accepted_submission: Pubkey,
+    #[max_len(MAX_WINNERS)]
+    pub accepted_submissions: Vec<Pubkey>,
+    /// unix timestamp after which the bounty no longer takes submissions and can be expired
+    pub deadline: Option<i64>,
+    /// number of submissions made against this bounty, once non-zero the maker can no longer cancel
+    pub submissions: u64,
+    /// share of the reward for each prize tier in basis points, 1st place first
+    #[max_len(MAX_WINNERS)]
+    pub prize_bps: Vec<u16>,
+    /// optional partial releases of the reward, paid one at a time by `approve_milestone`
+    #[max_len(MAX_MILESTONES)]
+    pub milestones: Vec<Milestone>,
+    /// total contributed by third parties through `fund_bounty`
+    pub funded: u64,
+    /// number of `Contribution` accounts still open for this bounty
+    pub contributors: u32,
+    /// escrow left when a crowdfunded bounty was cancelled or expired, shared pro rata between funders
+    pub refund_pool: Option<u64>,
+    /// what the program still holds for this bounty, anything above it in the vault is excess
+    pub escrowed: u64,
+    /// tokens held in the vault on top of `escrowed` to cover Token-2022 transfer fees on hunter
+    /// payouts, so hunters receive the advertised prize
+    pub fee_reserve: u64,
+    /// third party that settles disputes between the maker and a hunter
+    pub arbiter: Option<Pubkey>,
+    /// submission whose hunter has escalated to the arbiter, blocks cancelling and expiring the bounty
+    pub dispute: Option<Pubkey>,
+    pub status: BountyStatus,
+    /// who may submit solutions
+    pub access: BountyAccess,
+    /// lamports every hunter locks in their submission, refunded with its rent unless the maker
+    /// slashes it as spam
+    pub submission_bond: u64,
+    /// other mints paid out with every prize tier, added through `add_reward_leg`
+    #[max_len(MAX_REWARD_LEGS)]
+    pub reward_legs: Vec<RewardLeg>,
+    /// times the maker changed `description` or `link` through `update_bounty`
+    pub edits: u32,
+    /// slot of the creation or the last `update_bounty`, so hunters can tell the terms moved
+    pub updated_slot: u64,
+    /// SHA-256 of the metadata document `description` points to, keeps the account small while
+    /// letting anyone check the document wasn't swapped
+    pub content_hash: Option<[u8; 32]>,
+}
+
+/// lifecycle of a bounty, only ever changed through `Bounty::transition`
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace, Debug)]
+pub enum BountyStatus {
+    /// taking submissions, none made yet
+    Open,
+    /// has submissions waiting on the maker, some prize tiers or milestones may already be paid
+    InReview,
+    /// every prize tier or milestone has been paid
+    Completed,
+    /// refunded by the maker
+    Cancelled,
+    /// refunded after the deadline passed
+    Expired,
+    /// a hunter escalated a submission to the arbiter
+    Disputed,
+}
+
+/// an extra mint paid with the main reward, split across the prize tiers the same way
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, InitSpace)]
+pub struct RewardLeg {
+    pub mint: Pubkey,
+    /// advertised amount, set once when the leg is added
+    pub amount: u64,
+    /// what the leg's vault still holds for this bounty
+    pub escrowed: u64,
+}
+
+/// restricts `submit_solution` to vetted hunters on private bounties
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, InitSpace, Debug)]
+pub enum BountyAccess {
+    /// anyone can submit
+    Public,
+    /// only the listed hunters can submit
+    AllowList {
+        #[max_len(MAX_ALLOWED_HUNTERS)]
+        hunters: Vec<Pubkey>,
+    },
+    /// only hunters with a proof of membership in the tree can submit, for lists too long to
+    /// store on chain
+    MerkleRoot { root: [u8; 32] },
+}
+
+impl BountyAccess {
+    pub fn allows(&self, hunter: &Pubkey, proof: &[[u8; 32]]) -> bool {
+        match self {
+            BountyAccess::Public => true,
+            BountyAccess::AllowList { hunters } => hunters.contains(hunter),
+            BountyAccess::MerkleRoot { root } => {
+                let node = proof.iter().fold(Self::leaf(hunter), |node, sibling| {
+                    Self::node(&node, sibling)
+                });
+                node == *root
+            }
+        }
+    }
+
+    /// leaves and inner nodes are hashed with different prefixes so a node can't pass as a leaf
+    pub fn leaf(hunter: &Pubkey) -> [u8; 32] {
+        hashv(&[&[0], hunter.as_ref()]).to_bytes()
+    }
+
+    /// siblings are hashed in sorted order so proofs don't need to say which side they are on
+    pub fn node(a: &[u8; 32], b: &[u8; 32]) -> [u8; 32] {
+        let (left, right) = if a <= b { (a, b) } else { (b, a) };
+        hashv(&[&[1], left, right]).to_bytes()
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, InitSpace)]
+pub struct Milestone {
+    #[max_len(MAX_MILESTONE_DESCRIPTION_LEN)]
+    pub description: String,
+    pub amount: u64,
+    pub approved_submission: Option<Pubkey>,
+}
+
+impl Bounty {
+    /// account size with room for every other field, only the strings are sized to their content
+    pub fn space(description: &str, link: &str) -> usize {
+        Bounty::DISCRIMINATOR.len() + Bounty::INIT_SPACE - MAX_DESCRIPTION_LEN - MAX_LINK_LEN
+            + description.len()
+            + link.len()
+    }
+
+    pub fn is_expired(&self, now: i64) -> bool {
+        self.deadline.is_some_and(|deadline| now > deadline)
+    }
+
+    /// native SOL bounties keep the reward as lamports in the bounty account instead of a token vault
+    pub fn is_native(&self) -> bool {
+        self.mint == Pubkey::default()
+    }
+
+    /// still taking submissions, payouts and funding
+    pub fn is_open(&self) -> bool {
+        matches!(
+            self.status,
+            BountyStatus::Open | BountyStatus::InReview | BountyStatus::Disputed
+        )
+    }
+
+    pub fn transition(&mut self, status: BountyStatus) -> Result<()> {
+        use BountyStatus::*;
+
+        let allowed = matches!(
+            (self.status, status),
+            (Open, InReview | Cancelled | Expired)
+                | (InReview, Open | InReview | Completed | Expired | Disputed)
+                | (Disputed, InReview | Completed | Disputed)
+        );
+        require!(allowed, BountyHunterErrors::InvalidStatusTransition);
+
+        self.status = status;
+        Ok(())
+    }
+
+    /// where paying a prize tier or milestone leaves the bounty
+    pub fn status_after_payout(&self) -> BountyStatus {
+        if self.is_completed() {
+            BountyStatus::Completed
+        } else if self.dispute.is_some() {
+            BountyStatus::Disputed
+        } else {
+            BountyStatus::InReview
+        }
+    }
+
+    /// whether a prize tier or milestone has been paid, after which the terms can't change
+    pub fn has_payouts(&self) -> bool {
+        !self.accepted_submissions.is_empty()
+            || self
+                .milestones
+                .iter()
+                .any(|milestone| milestone.approved_submission.is_some())
+    }
+
+    pub fn is_completed(&self) -> bool {
+        if !self.milestones.is_empty() {
+            return self
+                .milestones
+                .iter()
+                .all(|milestone| milestone.approved_submission.is_some());
+        }
+        self.accepted_submissions.len() == self.prize_bps.len()
+    }
+
+    /// position of the next payout among the prize tiers, or the next pending milestone, used to
+    /// derive its `Receipt`
+    pub fn payout_index(&self) -> u8 {
+        if self.milestones.is_empty() {
+            return self.accepted_submissions.len() as u8;
+        }
+        self.milestones
+            .iter()
+            .position(|milestone| milestone.approved_submission.is_none())
+            .unwrap_or(self.milestones.len()) as u8
+    }
+
+    /// amount owed to the next winner, the last tier takes whatever is left in escrow
+    pub fn next_prize(&self) -> Result<u64> {
+        let tier = self.accepted_submissions.len();
+        if tier + 1 == self.prize_bps.len() {
+            return Ok(self.escrowed);
+        }
+        let prize = (self.total_funding() as u128)
+            .checked_mul(self.prize_bps[tier] as u128)
+            .and_then(|amount| amount.checked_div(BPS_DENOMINATOR as u128))
+            .ok_or(ProgramError::ArithmeticOverflow)?;
+        Ok(prize as u64)
+    }
+
+    /// amount of reward leg `index` owed to the next winner, the last tier takes what is left of it
+    pub fn next_leg_prize(&self, index: usize) -> Result<u64> {
+        let leg = &self.reward_legs[index];
+        let tier = self.accepted_submissions.len();
+        if tier + 1 == self.prize_bps.len() {
+            return Ok(leg.escrowed);
+        }
+        let prize = (leg.amount as u128)
+            .checked_mul(self.prize_bps[tier] as u128)
+            .and_then(|amount| amount.checked_div(BPS_DENOMINATOR as u128))
+            .ok_or(ProgramError::ArithmeticOverflow)?;
+        Ok(prize as u64)
+    }
+
+    /// the maker's reward plus everything crowdfunded on top of it
+    pub fn total_funding(&self) -> u64 {
+        self.reward.saturating_add(self.funded)
+    }
+
+    /// takes a payout or refund out of the escrowed balance
+    pub fn release(&mut self, amount: u64) -> Result<()> {
+        self.escrowed = self
+            .escrowed
+            .checked_sub(amount)
+            .ok_or(ProgramError::ArithmeticOverflow)?;
+        Ok(())
+    }
+
+    /// pro rata part of `pool` owed to someone who put `amount` into the bounty
+    pub fn refund_share(&self, pool: u64, amount: u64) -> Result<u64> {
+        let share = (pool as u128)
+            .checked_mul(amount as u128)
+            .and_then(|share| share.checked_div(self.total_funding() as u128))
+            .ok_or(ProgramError::ArithmeticOverflow)?;
+        Ok(share as u64)
+    }
 }
 
+/// sized to its `link` and `notes`, see `Submission::space`
 #[derive(InitSpace)]
 #[account(discriminator = 2)]
 pub struct Submission {
     pub bounty: Pubkey,
-    #[max_len(100)]
+    #[max_len(MAX_LINK_LEN)]
     pub link: String,
     pub hunter: Pubkey,
-    #[max_len(1024)]
+    /// the notes themselves, or the URI of a metadata document when `content_hash` is set
+    #[max_len(MAX_NOTES_LEN)]
     pub notes: String,
+    pub status: SubmissionStatus,
+    /// times the hunter changed `link` or `notes` through `edit_submission`
+    pub edits: u32,
+    /// unix timestamp of the original submission or the last edit
+    pub updated_at: i64,
+    /// lamports held on top of the rent, the bounty's `submission_bond` at the time of submitting
+    pub bond: u64,
+    /// SHA-256 of the metadata document `notes` points to
+    pub content_hash: Option<[u8; 32]>,
+}
+
+impl Submission {
+    /// account size with room for every other field, only the strings are sized to their content
+    pub fn space(link: &str, notes: &str) -> usize {
+        Submission::DISCRIMINATOR.len() + Submission::INIT_SPACE - MAX_LINK_LEN - MAX_NOTES_LEN
+            + link.len()
+            + notes.len()
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace, Debug)]
+pub enum SubmissionStatus {
+    /// the hunter can still edit or withdraw it
+    Pending,
+    /// locked by the maker while reviewing, it can no longer be edited and only now be accepted
+    InReview,
+    /// paid out for a prize tier or milestone
+    Accepted,
+    /// turned down by the arbiter in a dispute, it can't be disputed again
+    Rejected,
+}
+
+/// permanent proof of a payout, it outlives the bounty and submission accounts
+#[derive(InitSpace)]
+#[account(discriminator = 5)]
+pub struct Receipt {
+    pub bounty: Pubkey,
+    pub submission: Pubkey,
+    pub hunter: Pubkey,
+    /// `Pubkey::default()` for native SOL bounties
+    pub mint: Pubkey,
+    /// prize tier or milestone index, part of the seeds
+    pub index: u8,
+    /// paid out before the protocol fee
+    pub amount: u64,
+    pub fee: u64,
+    /// unix timestamp of the payout
+    pub paid_at: i64,
+    pub bump: u8,
+}
+
+/// a hunter's track record across every bounty, one per hunter
+#[derive(InitSpace)]
+#[account(discriminator = 6)]
+pub struct HunterProfile {
+    pub hunter: Pubkey,
+    /// prize tiers and milestones paid out to the hunter, whoever approved them
+    pub bounties_won: u32,
+    /// received per mint after the protocol fee, only the first `MAX_PROFILE_MINTS` mints are kept
+    #[max_len(MAX_PROFILE_MINTS)]
+    pub earnings: Vec<Earnings>,
+    /// unix timestamp of the last payout
+    pub last_win_at: Option<i64>,
+    pub bump: u8,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, InitSpace)]
+pub struct Earnings {
+    /// `Pubkey::default()` for native SOL
+    pub mint: Pubkey,
+    pub amount: u64,
+}
+
+impl HunterProfile {
+    pub fn record_win(&mut self, mint: Pubkey, amount: u64, now: i64) {
+        self.bounties_won += 1;
+        self.last_win_at = Some(now);
+        self.record_earnings(mint, amount);
+    }
+
+    /// adds to the earnings in `mint` without counting another win, reward legs are paid next to
+    /// the win they belong to
+    pub fn record_earnings(&mut self, mint: Pubkey, amount: u64) {
+        if let Some(earnings) = self
+            .earnings
+            .iter_mut()
+            .find(|earnings| earnings.mint == mint)
+        {
+            earnings.amount = earnings.amount.saturating_add(amount);
+        } else if self.earnings.len() < MAX_PROFILE_MINTS {
+            self.earnings.push(Earnings { mint, amount });
+        }
+        // a full list never blocks a payout, the win still counts
+    }
+}
+
+/// a maker's track record across every bounty, one per maker
+#[derive(InitSpace)]
+#[account(discriminator = 7)]
+pub struct MakerProfile {
+    pub maker: Pubkey,
+    pub bounties_created: u32,
+    /// prize tiers and milestones paid out from the maker's bounties, arbiter awards included
+    pub payouts: u32,
+    /// cancelled by the maker, only possible before any hunter has submitted
+    pub bounties_cancelled: u32,
+    /// left to expire after hunters had submitted to them, the maker walked away from their work
+    pub expired_with_submissions: u32,
+    pub bump: u8,
+}
+
+/// a third party deposit into a bounty vault, refunded pro rata if the bounty is cancelled or expires
+#[derive(InitSpace)]
+#[account(discriminator = 3)]
+pub struct Contribution {
+    pub bounty: Pubkey,
+    pub funder: Pubkey,
+    pub amount: u64,
+    pub bump: u8,
+}
+
+/// program wide settings, a single PDA created once after deployment. Payouts and slashing read
+/// the fee and treasury from it and fail until it exists, so initializing it is part of deploying
+#[derive(InitSpace)]
+#[account(discriminator = 4)]
+pub struct Config {
+    pub admin: Pubkey,
+    /// protocol fee taken from every hunter payout, in basis points
+    pub fee_bps: u16,
+    pub treasury: Pubkey,
+    pub bump: u8,
+}
+
+impl Config {
+    pub fn fee(&self, amount: u64) -> Result<u64> {
+        let fee = (amount as u128)
+            .checked_mul(self.fee_bps as u128)
+            .and_then(|fee| fee.checked_div(BPS_DENOMINATOR as u128))
+            .ok_or(ProgramError::ArithmeticOverflow)?;
+        Ok(fee as u64)
+    }
+}
+
+/// the smallest reward a bounty paid in `mint` can offer, set by the config admin. Native SOL
+/// bounties use the default pubkey as their mint
+#[derive(InitSpace)]
+#[account(discriminator = 8)]
+pub struct MinReward {
+    pub mint: Pubkey,
+    pub amount: u64,
+    pub bump: u8,
 }
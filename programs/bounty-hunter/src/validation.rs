@@ -0,0 +1,68 @@
+//! Checks on what makers and hunters type in, run before anything is stored so a bad input fails
+//! with its own error rather than inside serialization. The CLI runs the same checks before sending
+
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::{MAX_DESCRIPTION_LEN, MAX_LINK_LEN, MAX_NOTES_LEN},
+    error::BountyHunterErrors,
+    state::MinReward,
+};
+
+/// schemes a bounty or submission link can use
+const LINK_SCHEMES: [&str; 2] = ["https://", "http://"];
+
+pub fn check_description(description: &str) -> Result<()> {
+    require!(
+        description.len() <= MAX_DESCRIPTION_LEN,
+        BountyHunterErrors::DescriptionTooLong
+    );
+    Ok(())
+}
+
+pub fn check_link(link: &str) -> Result<()> {
+    require!(!link.is_empty(), BountyHunterErrors::EmptyLink);
+    require!(link.len() <= MAX_LINK_LEN, BountyHunterErrors::LinkTooLong);
+    // the scheme alone doesn't point anywhere
+    require!(
+        LINK_SCHEMES
+            .iter()
+            .any(|scheme| link.len() > scheme.len() && link.starts_with(scheme)),
+        BountyHunterErrors::InvalidLinkScheme
+    );
+    Ok(())
+}
+
+pub fn check_notes(notes: &str) -> Result<()> {
+    require!(
+        notes.len() <= MAX_NOTES_LEN,
+        BountyHunterErrors::NotesTooLong
+    );
+    Ok(())
+}
+
+pub fn check_reward(reward: u64) -> Result<()> {
+    require!(reward > 0, BountyHunterErrors::ZeroReward);
+    Ok(())
+}
+
+/// `min_reward` is `None` while the admin hasn't set a minimum for the bounty's mint
+pub fn check_min_reward(min_reward: Option<&MinReward>, reward: u64) -> Result<()> {
+    if let Some(min_reward) = min_reward {
+        require!(
+            reward >= min_reward.amount,
+            BountyHunterErrors::RewardBelowMinimum
+        );
+    }
+    Ok(())
+}
+
+/// reads the minimum out of the `min_reward` PDA, which only holds one once the admin created it
+pub fn load_min_reward(min_reward: &AccountInfo) -> Result<Option<MinReward>> {
+    if min_reward.owner != &crate::ID || min_reward.data_is_empty() {
+        return Ok(None);
+    }
+    Ok(Some(MinReward::try_deserialize(
+        &mut min_reward.try_borrow_data()?.as_ref(),
+    )?))
+}
@@ -1,2 +1,46 @@
 #[cfg(test)]
+mod program_data;
+#[cfg(test)]
+mod review;
+#[cfg(test)]
+mod test_close_bounty;
+#[cfg(test)]
+mod test_close_submission;
+#[cfg(test)]
+mod test_config;
+#[cfg(test)]
+mod test_crowdfunding;
+#[cfg(test)]
+mod test_dispute;
+#[cfg(test)]
+mod test_expire_bounty;
+#[cfg(test)]
 mod test_initialize;
+#[cfg(test)]
+mod test_metadata;
+#[cfg(test)]
+mod test_milestones;
+#[cfg(test)]
+mod test_min_reward;
+#[cfg(test)]
+mod test_multi_winner;
+#[cfg(test)]
+mod test_native_bounty;
+#[cfg(test)]
+mod test_private_bounty;
+#[cfg(test)]
+mod test_reward_changes;
+#[cfg(test)]
+mod test_reward_legs;
+#[cfg(test)]
+mod test_submission_bond;
+#[cfg(test)]
+mod test_submission_edits;
+#[cfg(test)]
+mod test_sweep_excess;
+#[cfg(test)]
+mod test_token_2022;
+#[cfg(test)]
+mod test_update_bounty;
+#[cfg(test)]
+mod test_validation;
@@ -0,0 +1,30 @@
+use anchor_client::anchor_lang::solana_program::bpf_loader_upgradeable;
+use anchor_client::solana_sdk::account::Account;
+use anchor_litesvm::{AnchorContext, Pubkey};
+
+/// litesvm deploys the program without a ProgramData account, this writes one naming `authority`
+/// as the upgrade authority so it can initialize the config
+pub fn set_upgrade_authority(ctx: &mut AnchorContext, authority: &Pubkey) -> Pubkey {
+    let program_data = bpf_loader_upgradeable::get_program_data_address(&bounty_hunter::ID);
+
+    // bincode of UpgradeableLoaderState::ProgramData { slot: 0, upgrade_authority_address }
+    let mut data = 3u32.to_le_bytes().to_vec();
+    data.extend_from_slice(&0u64.to_le_bytes());
+    data.push(1);
+    data.extend_from_slice(authority.as_ref());
+
+    ctx.svm
+        .set_account(
+            program_data,
+            Account {
+                lamports: ctx.svm.minimum_balance_for_rent_exemption(data.len()),
+                data,
+                owner: bpf_loader_upgradeable::ID,
+                executable: false,
+                rent_epoch: 0,
+            },
+        )
+        .unwrap();
+
+    program_data
+}
@@ -0,0 +1,31 @@
+use anchor_litesvm::{AnchorContext, Keypair, Pubkey, Signer};
+use litesvm_utils::TestHelpers;
+
+/// locks `submission` for review, the maker can only accept it or approve milestones with it after
+pub fn review_submission(
+    ctx: &mut AnchorContext,
+    maker: &Keypair,
+    bounty: Pubkey,
+    submission: Pubkey,
+) {
+    let (event_authority, _) = ctx
+        .svm
+        .get_pda_with_bump(&[b"__event_authority"], &bounty_hunter::ID);
+
+    let review_ix = ctx
+        .program()
+        .accounts(bounty_hunter::accounts::ReviewSubmission {
+            maker: maker.pubkey(),
+            bounty,
+            submission,
+            event_authority,
+            program: bounty_hunter::ID,
+        })
+        .args(bounty_hunter::instruction::ReviewSubmission {})
+        .instruction()
+        .unwrap();
+
+    ctx.execute_instruction(review_ix, &[maker])
+        .unwrap()
+        .assert_success();
+}
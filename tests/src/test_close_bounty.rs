@@ -0,0 +1,219 @@
+use anchor_litesvm::{AnchorLiteSVM, Pubkey, Signer};
+use bounty_hunter::state::{Bounty, BountyAccess, Receipt};
+use litesvm_utils::{AssertionHelpers, TestHelpers};
+
+use crate::program_data::set_upgrade_authority;
+use crate::review::review_submission;
+
+#[test]
+fn close_bounty_after_payout_test() {
+    let mut ctx = AnchorLiteSVM::build_with_program(
+        bounty_hunter::ID,
+        include_bytes!("../../target/deploy/bounty_hunter.so"),
+    );
+    let (event_authority, _) = ctx
+        .svm
+        .get_pda_with_bump(&[b"__event_authority"], &bounty_hunter::ID);
+
+    let maker = ctx.svm.create_funded_account(10_000_000_000).unwrap();
+
+    let seed = 1u64;
+    let reward = 1_000_000_000u64;
+
+    let (bounty, _) = ctx.svm.get_pda_with_bump(
+        &[b"bounty", maker.pubkey().as_array(), &seed.to_le_bytes()],
+        &bounty_hunter::ID,
+    );
+    let (maker_profile, _) = ctx.svm.get_pda_with_bump(
+        &[b"maker_profile", maker.pubkey().as_ref()],
+        &bounty_hunter::ID,
+    );
+
+    // --- Create bounty without a mint ---
+    let (min_reward, _) = ctx.svm.get_pda_with_bump(
+        &[b"min_reward", Pubkey::default().as_ref()],
+        &bounty_hunter::ID,
+    );
+    let create_ix = ctx
+        .program()
+        .accounts(bounty_hunter::accounts::CreateBounty {
+            maker: maker.pubkey(),
+            bounty,
+            maker_profile,
+            mint: None,
+            maker_token_account: None,
+            vault: None,
+            min_reward,
+            system_program: solana_system_interface::program::ID,
+            token_program: None,
+            associated_token_program: None,
+            event_authority,
+            program: bounty_hunter::ID,
+        })
+        .args(bounty_hunter::instruction::CreateBounty {
+            seed,
+            description: "closed after payout".to_string(),
+            link: "https://issue.link".to_string(),
+            reward,
+            deadline: None,
+            prize_bps: vec![],
+            milestones: vec![],
+            arbiter: None,
+            access: BountyAccess::Public,
+            submission_bond: 0,
+            content_hash: None,
+        })
+        .instruction()
+        .unwrap();
+
+    ctx.execute_instruction(create_ix, &[&maker])
+        .unwrap()
+        .assert_success();
+
+    let (config, _) = ctx.svm.get_pda_with_bump(&[b"config"], &bounty_hunter::ID);
+
+    let program_data = set_upgrade_authority(&mut ctx, &maker.pubkey());
+
+    let config_ix = ctx
+        .program()
+        .accounts(bounty_hunter::accounts::InitializeConfig {
+            admin: maker.pubkey(),
+            config,
+            program_data,
+            system_program: solana_system_interface::program::ID,
+            event_authority,
+            program: bounty_hunter::ID,
+        })
+        .args(bounty_hunter::instruction::InitializeConfig {
+            fee_bps: 0,
+            treasury: maker.pubkey(),
+        })
+        .instruction()
+        .unwrap();
+
+    ctx.execute_instruction(config_ix, &[&maker])
+        .unwrap()
+        .assert_success();
+
+    let b: Bounty = ctx.get_account(&bounty).unwrap();
+    assert!(b.is_native());
+
+    let hunter = ctx.svm.create_funded_account(10_000_000_000).unwrap();
+
+    let (submission, _) = ctx.svm.get_pda_with_bump(
+        &[b"submission", hunter.pubkey().as_ref(), bounty.as_ref()],
+        &bounty_hunter::ID,
+    );
+
+    let submit_ix = ctx
+        .program()
+        .accounts(bounty_hunter::accounts::SubmitSolution {
+            hunter: hunter.pubkey(),
+            bounty,
+            submission,
+            system_program: solana_system_interface::program::ID,
+            event_authority,
+            program: bounty_hunter::ID,
+        })
+        .args(bounty_hunter::instruction::SubmitSolution {
+            link: "https://github.com/hunter/solution".to_string(),
+            notes: "done".to_string(),
+            proof: vec![],
+            content_hash: None,
+        })
+        .instruction()
+        .unwrap();
+
+    ctx.execute_instruction(submit_ix, &[&hunter])
+        .unwrap()
+        .assert_success();
+
+    review_submission(&mut ctx, &maker, bounty, submission);
+
+    let close_ix = ctx
+        .program()
+        .accounts(bounty_hunter::accounts::CloseBounty {
+            maker: maker.pubkey(),
+            bounty,
+            vault: None,
+            mint: None,
+            maker_token_account: None,
+            token_program: None,
+            associated_token_program: None,
+            memo_program: None,
+            event_authority,
+            program: bounty_hunter::ID,
+        })
+        .args(bounty_hunter::instruction::CloseBounty {})
+        .instruction()
+        .unwrap();
+
+    // still waiting on its winner
+    ctx.execute_instruction(close_ix.clone(), &[&maker])
+        .unwrap()
+        .assert_anchor_error("BountyStillOpen");
+
+    let (receipt, _) = ctx
+        .svm
+        .get_pda_with_bump(&[b"receipt", bounty.as_ref(), &[0]], &bounty_hunter::ID);
+
+    let (hunter_profile, _) = ctx.svm.get_pda_with_bump(
+        &[b"hunter_profile", hunter.pubkey().as_ref()],
+        &bounty_hunter::ID,
+    );
+    let accept_ix = ctx
+        .program()
+        .accounts(bounty_hunter::accounts::AcceptSolution {
+            maker: maker.pubkey(),
+            bounty,
+            submission,
+            receipt,
+            maker_profile,
+            hunter_profile,
+            vault: None,
+            hunter: hunter.pubkey(),
+            mint: None,
+            hunter_token_account: None,
+            token_program: None,
+            associated_token_program: None,
+            config,
+            treasury: maker.pubkey(),
+            treasury_token_account: None,
+            system_program: solana_system_interface::program::ID,
+            memo_program: None,
+            event_authority,
+            program: bounty_hunter::ID,
+        })
+        .args(bounty_hunter::instruction::AcceptSolution {})
+        .instruction()
+        .unwrap();
+
+    ctx.execute_instruction(accept_ix, &[&maker])
+        .unwrap()
+        .assert_success();
+
+    let maker_balance = ctx.svm.get_balance(&maker.pubkey()).unwrap();
+    let bounty_rent = ctx.svm.get_balance(&bounty).unwrap();
+
+    // fresh blockhash so the retry is not deduplicated against the failed attempt
+    ctx.svm.expire_blockhash();
+
+    ctx.execute_instruction(close_ix, &[&maker])
+        .unwrap()
+        .assert_success();
+
+    // --- Assertions ---
+    ctx.svm.assert_account_closed(&bounty);
+    assert_eq!(
+        ctx.svm.get_balance(&maker.pubkey()).unwrap(),
+        maker_balance + bounty_rent
+    );
+
+    // the payout outlives the bounty
+    let r: Receipt = ctx.get_account(&receipt).unwrap();
+    assert_eq!(r.bounty, bounty);
+    assert_eq!(r.submission, submission);
+    assert_eq!(r.hunter, hunter.pubkey());
+    assert_eq!(r.amount, reward);
+    assert_eq!(r.fee, 0);
+}
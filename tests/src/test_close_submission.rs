@@ -0,0 +1,168 @@
+use anchor_client::solana_sdk::clock::Clock;
+use anchor_litesvm::{AnchorLiteSVM, Pubkey, Signer};
+use bounty_hunter::state::{BountyAccess, MakerProfile};
+use litesvm_utils::{AssertionHelpers, TestHelpers};
+
+#[test]
+fn close_submission_after_expiry_test() {
+    let mut ctx = AnchorLiteSVM::build_with_program(
+        bounty_hunter::ID,
+        include_bytes!("../../target/deploy/bounty_hunter.so"),
+    );
+    let (event_authority, _) = ctx
+        .svm
+        .get_pda_with_bump(&[b"__event_authority"], &bounty_hunter::ID);
+
+    let maker = ctx.svm.create_funded_account(10_000_000_000).unwrap();
+    let hunter = ctx.svm.create_funded_account(10_000_000_000).unwrap();
+    let keeper = ctx.svm.create_funded_account(10_000_000_000).unwrap();
+
+    let seed = 1u64;
+
+    let (bounty, _) = ctx.svm.get_pda_with_bump(
+        &[b"bounty", maker.pubkey().as_array(), &seed.to_le_bytes()],
+        &bounty_hunter::ID,
+    );
+    let (maker_profile, _) = ctx.svm.get_pda_with_bump(
+        &[b"maker_profile", maker.pubkey().as_ref()],
+        &bounty_hunter::ID,
+    );
+
+    let mut clock: Clock = ctx.svm.get_sysvar();
+    let deadline = clock.unix_timestamp + 60;
+
+    let (min_reward, _) = ctx.svm.get_pda_with_bump(
+        &[b"min_reward", Pubkey::default().as_ref()],
+        &bounty_hunter::ID,
+    );
+    let create_ix = ctx
+        .program()
+        .accounts(bounty_hunter::accounts::CreateBounty {
+            maker: maker.pubkey(),
+            bounty,
+            maker_profile,
+            mint: None,
+            maker_token_account: None,
+            vault: None,
+            min_reward,
+            system_program: solana_system_interface::program::ID,
+            token_program: None,
+            associated_token_program: None,
+            event_authority,
+            program: bounty_hunter::ID,
+        })
+        .args(bounty_hunter::instruction::CreateBounty {
+            seed,
+            description: "time boxed".to_string(),
+            link: "https://issue.link".to_string(),
+            reward: 1_000_000,
+            deadline: Some(deadline),
+            prize_bps: vec![],
+            milestones: vec![],
+            arbiter: None,
+            access: BountyAccess::Public,
+            submission_bond: 0,
+            content_hash: None,
+        })
+        .instruction()
+        .unwrap();
+
+    ctx.execute_instruction(create_ix, &[&maker])
+        .unwrap()
+        .assert_success();
+
+    let (submission, _) = ctx.svm.get_pda_with_bump(
+        &[b"submission", hunter.pubkey().as_ref(), bounty.as_ref()],
+        &bounty_hunter::ID,
+    );
+
+    let submit_ix = ctx
+        .program()
+        .accounts(bounty_hunter::accounts::SubmitSolution {
+            hunter: hunter.pubkey(),
+            bounty,
+            submission,
+            system_program: solana_system_interface::program::ID,
+            event_authority,
+            program: bounty_hunter::ID,
+        })
+        .args(bounty_hunter::instruction::SubmitSolution {
+            link: "https://github.com/hunter/solution".to_string(),
+            notes: "never reviewed".to_string(),
+            proof: vec![],
+            content_hash: None,
+        })
+        .instruction()
+        .unwrap();
+
+    ctx.execute_instruction(submit_ix, &[&hunter])
+        .unwrap()
+        .assert_success();
+
+    let close_ix = ctx
+        .program()
+        .accounts(bounty_hunter::accounts::CloseSubmission {
+            payer: keeper.pubkey(),
+            hunter: hunter.pubkey(),
+            bounty,
+            submission,
+            event_authority,
+            program: bounty_hunter::ID,
+        })
+        .args(bounty_hunter::instruction::CloseSubmission {})
+        .instruction()
+        .unwrap();
+
+    // the bounty is still open
+    ctx.execute_instruction(close_ix.clone(), &[&keeper])
+        .unwrap()
+        .assert_anchor_error("BountyStillOpen");
+
+    clock.unix_timestamp = deadline + 1;
+    ctx.svm.set_sysvar(&clock);
+
+    let expire_ix = ctx
+        .program()
+        .accounts(bounty_hunter::accounts::ExpireBounty {
+            payer: keeper.pubkey(),
+            maker: maker.pubkey(),
+            bounty,
+            maker_profile,
+            vault: None,
+            mint: None,
+            maker_token_account: None,
+            token_program: None,
+            associated_token_program: None,
+            memo_program: None,
+            event_authority,
+            program: bounty_hunter::ID,
+        })
+        .args(bounty_hunter::instruction::ExpireBounty {})
+        .instruction()
+        .unwrap();
+
+    ctx.execute_instruction(expire_ix, &[&keeper])
+        .unwrap()
+        .assert_success();
+
+    let hunter_balance = ctx.svm.get_balance(&hunter.pubkey()).unwrap();
+    let submission_rent = ctx.svm.get_balance(&submission).unwrap();
+
+    // fresh blockhash so the retry is not deduplicated against the failed attempt
+    ctx.svm.expire_blockhash();
+
+    ctx.execute_instruction(close_ix, &[&keeper])
+        .unwrap()
+        .assert_success();
+
+    // --- Assertions ---
+    ctx.svm.assert_account_closed(&submission);
+    assert_eq!(
+        ctx.svm.get_balance(&hunter.pubkey()).unwrap(),
+        hunter_balance + submission_rent
+    );
+    // the maker let it expire with the hunter's work pending
+    let profile: MakerProfile = ctx.get_account(&maker_profile).unwrap();
+    assert_eq!(profile.expired_with_submissions, 1);
+    assert_eq!(profile.bounties_cancelled, 0);
+}
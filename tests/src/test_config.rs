@@ -0,0 +1,268 @@
+use anchor_client::anchor_lang::{event::EVENT_IX_TAG_LE, AnchorDeserialize, Discriminator};
+use anchor_litesvm::{AnchorContext, AnchorLiteSVM, Pubkey, Signer};
+use bounty_hunter::state::BountyAccess;
+use bounty_hunter::MAX_FEE_BPS;
+use bounty_hunter::{events::SolutionAccepted, state::Config};
+use litesvm_utils::{AssertionHelpers, TestHelpers};
+
+use crate::program_data::set_upgrade_authority;
+use crate::review::review_submission;
+
+use spl_associated_token_account_client::address::get_associated_token_address;
+
+#[test]
+fn protocol_fee_test() {
+    let mut ctx = AnchorLiteSVM::build_with_program(
+        bounty_hunter::ID,
+        include_bytes!("../../target/deploy/bounty_hunter.so"),
+    );
+    let (event_authority, _) = ctx
+        .svm
+        .get_pda_with_bump(&[b"__event_authority"], &bounty_hunter::ID);
+
+    let admin = ctx.svm.create_funded_account(10_000_000_000).unwrap();
+    let treasury = ctx.svm.create_funded_account(1_000_000_000).unwrap();
+    let maker = ctx.svm.create_funded_account(10_000_000_000).unwrap();
+    let hunter = ctx.svm.create_funded_account(10_000_000_000).unwrap();
+    let mint = ctx.svm.create_token_mint(&maker, 3).unwrap();
+
+    let (config, _) = ctx.svm.get_pda_with_bump(&[b"config"], &bounty_hunter::ID);
+
+    let program_data = set_upgrade_authority(&mut ctx, &admin.pubkey());
+
+    let config_ix = |ctx: &AnchorContext, signer: Pubkey, fee_bps: u16| {
+        ctx.program()
+            .accounts(bounty_hunter::accounts::InitializeConfig {
+                admin: signer,
+                config,
+                program_data,
+                system_program: solana_system_interface::program::ID,
+                event_authority,
+                program: bounty_hunter::ID,
+            })
+            .args(bounty_hunter::instruction::InitializeConfig {
+                fee_bps,
+                treasury: treasury.pubkey(),
+            })
+            .instruction()
+            .unwrap()
+    };
+
+    // only the upgrade authority may initialize the config
+    let ix = config_ix(&ctx, maker.pubkey(), 500);
+    ctx.execute_instruction(ix, &[&maker])
+        .unwrap()
+        .assert_anchor_error("InvalidConfigAuthority");
+
+    let ix = config_ix(&ctx, admin.pubkey(), MAX_FEE_BPS + 1);
+    ctx.execute_instruction(ix, &[&admin])
+        .unwrap()
+        .assert_anchor_error("InvalidFee");
+
+    let ix = config_ix(&ctx, admin.pubkey(), 500);
+    ctx.execute_instruction(ix, &[&admin])
+        .unwrap()
+        .assert_success();
+
+    // the fee stays capped after initialization
+    let update_ix = ctx
+        .program()
+        .accounts(bounty_hunter::accounts::UpdateConfig {
+            admin: admin.pubkey(),
+            config,
+            event_authority,
+            program: bounty_hunter::ID,
+        })
+        .args(bounty_hunter::instruction::UpdateConfig {
+            admin: admin.pubkey(),
+            fee_bps: MAX_FEE_BPS + 1,
+            treasury: treasury.pubkey(),
+        })
+        .instruction()
+        .unwrap();
+
+    ctx.execute_instruction(update_ix, &[&admin])
+        .unwrap()
+        .assert_anchor_error("InvalidFee");
+
+    // only the admin may change the fee
+    let update_ix = ctx
+        .program()
+        .accounts(bounty_hunter::accounts::UpdateConfig {
+            admin: maker.pubkey(),
+            config,
+            event_authority,
+            program: bounty_hunter::ID,
+        })
+        .args(bounty_hunter::instruction::UpdateConfig {
+            admin: maker.pubkey(),
+            fee_bps: 0,
+            treasury: maker.pubkey(),
+        })
+        .instruction()
+        .unwrap();
+
+    ctx.execute_instruction(update_ix, &[&maker])
+        .unwrap()
+        .assert_anchor_error("InvalidConfigAuthority");
+
+    let maker_token_account = ctx
+        .svm
+        .create_associated_token_account(&mint.pubkey(), &maker)
+        .unwrap();
+
+    ctx.svm
+        .mint_to(&mint.pubkey(), &maker_token_account, &maker, 1_000)
+        .unwrap();
+
+    let seed = 1u64;
+
+    let (bounty, _) = ctx.svm.get_pda_with_bump(
+        &[b"bounty", maker.pubkey().as_array(), &seed.to_le_bytes()],
+        &bounty_hunter::ID,
+    );
+    let (maker_profile, _) = ctx.svm.get_pda_with_bump(
+        &[b"maker_profile", maker.pubkey().as_ref()],
+        &bounty_hunter::ID,
+    );
+    let vault = get_associated_token_address(&bounty, &mint.pubkey());
+
+    let (min_reward, _) = ctx
+        .svm
+        .get_pda_with_bump(&[b"min_reward", mint.pubkey().as_ref()], &bounty_hunter::ID);
+    let create_ix = ctx
+        .program()
+        .accounts(bounty_hunter::accounts::CreateBounty {
+            maker: maker.pubkey(),
+            bounty,
+            maker_profile,
+            mint: Some(mint.pubkey()),
+            maker_token_account: Some(maker_token_account),
+            vault: Some(vault),
+            min_reward,
+            system_program: solana_system_interface::program::ID,
+            token_program: Some(spl_token::ID),
+            associated_token_program: Some(spl_associated_token_account_client::program::ID),
+            event_authority,
+            program: bounty_hunter::ID,
+        })
+        .args(bounty_hunter::instruction::CreateBounty {
+            seed,
+            description: "fee bounty".to_string(),
+            link: "https://fee.link".to_string(),
+            reward: 100,
+            deadline: None,
+            prize_bps: vec![],
+            milestones: vec![],
+            arbiter: None,
+            access: BountyAccess::Public,
+            submission_bond: 0,
+            content_hash: None,
+        })
+        .instruction()
+        .unwrap();
+
+    ctx.execute_instruction(create_ix, &[&maker])
+        .unwrap()
+        .assert_success();
+
+    let (submission, _) = ctx.svm.get_pda_with_bump(
+        &[b"submission", hunter.pubkey().as_ref(), bounty.as_ref()],
+        &bounty_hunter::ID,
+    );
+
+    let submit_ix = ctx
+        .program()
+        .accounts(bounty_hunter::accounts::SubmitSolution {
+            hunter: hunter.pubkey(),
+            bounty,
+            submission,
+            system_program: solana_system_interface::program::ID,
+            event_authority,
+            program: bounty_hunter::ID,
+        })
+        .args(bounty_hunter::instruction::SubmitSolution {
+            link: "https://github.com/hunter/solution".to_string(),
+            notes: "done".to_string(),
+            proof: vec![],
+            content_hash: None,
+        })
+        .instruction()
+        .unwrap();
+
+    ctx.execute_instruction(submit_ix, &[&hunter])
+        .unwrap()
+        .assert_success();
+
+    review_submission(&mut ctx, &maker, bounty, submission);
+
+    let hunter_token_account = ctx
+        .svm
+        .create_associated_token_account(&mint.pubkey(), &hunter)
+        .unwrap();
+    // created by the maker on accept
+    let treasury_token_account = get_associated_token_address(&treasury.pubkey(), &mint.pubkey());
+
+    let (receipt, _) = ctx
+        .svm
+        .get_pda_with_bump(&[b"receipt", bounty.as_ref(), &[0]], &bounty_hunter::ID);
+
+    let (hunter_profile, _) = ctx.svm.get_pda_with_bump(
+        &[b"hunter_profile", hunter.pubkey().as_ref()],
+        &bounty_hunter::ID,
+    );
+    let accept_ix = ctx
+        .program()
+        .accounts(bounty_hunter::accounts::AcceptSolution {
+            maker: maker.pubkey(),
+            bounty,
+            submission,
+            receipt,
+            maker_profile,
+            hunter_profile,
+            vault: Some(vault),
+            hunter: hunter.pubkey(),
+            mint: Some(mint.pubkey()),
+            hunter_token_account: Some(hunter_token_account),
+            token_program: Some(spl_token::ID),
+            associated_token_program: Some(spl_associated_token_account_client::program::ID),
+            config,
+            treasury: treasury.pubkey(),
+            treasury_token_account: Some(treasury_token_account),
+            system_program: solana_system_interface::program::ID,
+            memo_program: None,
+            event_authority,
+            program: bounty_hunter::ID,
+        })
+        .args(bounty_hunter::instruction::AcceptSolution {})
+        .instruction()
+        .unwrap();
+
+    let result = ctx.execute_instruction(accept_ix, &[&maker]).unwrap();
+    result.assert_success();
+
+    // emitted as a self CPI, the instruction data is the event tag followed by the event
+    let event = result
+        .inner()
+        .inner_instructions
+        .iter()
+        .flatten()
+        .find_map(|ix| {
+            let data = ix.instruction.data.strip_prefix(EVENT_IX_TAG_LE)?;
+            let data = data.strip_prefix(SolutionAccepted::DISCRIMINATOR)?;
+            SolutionAccepted::try_from_slice(data).ok()
+        })
+        .expect("SolutionAccepted was not emitted");
+    assert_eq!(event.submission, submission);
+    assert_eq!(event.amount, 100);
+    assert_eq!(event.fee, 5);
+
+    // --- Assertions ---
+    ctx.svm.assert_token_balance(&hunter_token_account, 95);
+    ctx.svm.assert_token_balance(&treasury_token_account, 5);
+    ctx.svm.assert_account_closed(&vault);
+
+    let c: Config = ctx.get_account(&config).unwrap();
+    assert_eq!(c.admin, admin.pubkey());
+    assert_eq!(c.fee_bps, 500);
+}
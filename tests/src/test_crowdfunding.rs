@@ -0,0 +1,203 @@
+use anchor_litesvm::{AnchorContext, AnchorLiteSVM, Signer};
+use bounty_hunter::state::{Bounty, BountyAccess, BountyStatus, Contribution};
+use litesvm_utils::{AssertionHelpers, TestHelpers};
+
+use spl_associated_token_account_client::address::get_associated_token_address;
+
+#[test]
+fn fund_and_refund_bounty_test() {
+    let mut ctx = AnchorLiteSVM::build_with_program(
+        bounty_hunter::ID,
+        include_bytes!("../../target/deploy/bounty_hunter.so"),
+    );
+    let (event_authority, _) = ctx
+        .svm
+        .get_pda_with_bump(&[b"__event_authority"], &bounty_hunter::ID);
+
+    let maker = ctx.svm.create_funded_account(10_000_000_000).unwrap();
+    let mint = ctx.svm.create_token_mint(&maker, 3).unwrap();
+
+    let maker_token_account = ctx
+        .svm
+        .create_associated_token_account(&mint.pubkey(), &maker)
+        .unwrap();
+
+    ctx.svm
+        .mint_to(&mint.pubkey(), &maker_token_account, &maker, 10_000)
+        .unwrap();
+
+    let seed = 1u64;
+
+    let (bounty, _) = ctx.svm.get_pda_with_bump(
+        &[b"bounty", maker.pubkey().as_array(), &seed.to_le_bytes()],
+        &bounty_hunter::ID,
+    );
+    let (maker_profile, _) = ctx.svm.get_pda_with_bump(
+        &[b"maker_profile", maker.pubkey().as_ref()],
+        &bounty_hunter::ID,
+    );
+    let vault = get_associated_token_address(&bounty, &mint.pubkey());
+
+    let (min_reward, _) = ctx
+        .svm
+        .get_pda_with_bump(&[b"min_reward", mint.pubkey().as_ref()], &bounty_hunter::ID);
+    let create_ix = ctx
+        .program()
+        .accounts(bounty_hunter::accounts::CreateBounty {
+            maker: maker.pubkey(),
+            bounty,
+            maker_profile,
+            mint: Some(mint.pubkey()),
+            maker_token_account: Some(maker_token_account),
+            vault: Some(vault),
+            min_reward,
+            system_program: solana_system_interface::program::ID,
+            token_program: Some(spl_token::ID),
+            associated_token_program: Some(spl_associated_token_account_client::program::ID),
+            event_authority,
+            program: bounty_hunter::ID,
+        })
+        .args(bounty_hunter::instruction::CreateBounty {
+            seed,
+            description: "popular issue".to_string(),
+            link: "https://issue.link".to_string(),
+            reward: 100,
+            deadline: None,
+            prize_bps: vec![],
+            milestones: vec![],
+            arbiter: None,
+            access: BountyAccess::Public,
+            submission_bond: 0,
+            content_hash: None,
+        })
+        .instruction()
+        .unwrap();
+
+    ctx.execute_instruction(create_ix, &[&maker])
+        .unwrap()
+        .assert_success();
+
+    // --- A third party tops up the vault ---
+    let funder = ctx.svm.create_funded_account(10_000_000_000).unwrap();
+
+    let funder_token_account = ctx
+        .svm
+        .create_associated_token_account(&mint.pubkey(), &funder)
+        .unwrap();
+
+    ctx.svm
+        .mint_to(&mint.pubkey(), &funder_token_account, &maker, 1_000)
+        .unwrap();
+
+    let (contribution, _) = ctx.svm.get_pda_with_bump(
+        &[b"contribution", bounty.as_ref(), funder.pubkey().as_ref()],
+        &bounty_hunter::ID,
+    );
+
+    let fund_ix = |ctx: &AnchorContext, amount: u64| {
+        ctx.program()
+            .accounts(bounty_hunter::accounts::FundBounty {
+                funder: funder.pubkey(),
+                bounty,
+                contribution,
+                mint: Some(mint.pubkey()),
+                funder_token_account: Some(funder_token_account),
+                vault: Some(vault),
+                system_program: solana_system_interface::program::ID,
+                token_program: Some(spl_token::ID),
+                associated_token_program: Some(spl_associated_token_account_client::program::ID),
+                event_authority,
+                program: bounty_hunter::ID,
+            })
+            .args(bounty_hunter::instruction::FundBounty { amount })
+            .instruction()
+            .unwrap()
+    };
+
+    // an empty contribution isn't a contribution
+    let ix = fund_ix(&ctx, 0);
+    ctx.execute_instruction(ix, &[&funder])
+        .unwrap()
+        .assert_anchor_error("ZeroContribution");
+
+    let ix = fund_ix(&ctx, 300);
+    ctx.execute_instruction(ix, &[&funder])
+        .unwrap()
+        .assert_success();
+
+    ctx.svm.assert_token_balance(&vault, 400);
+
+    let c: Contribution = ctx.get_account(&contribution).unwrap();
+    assert_eq!(c.amount, 300);
+    assert_eq!(c.funder, funder.pubkey());
+
+    let b: Bounty = ctx.get_account(&bounty).unwrap();
+    assert_eq!(b.contributors, 1);
+
+    // --- Cancelling only hands the maker its own share ---
+    let cancel_ix = ctx
+        .program()
+        .accounts(bounty_hunter::accounts::CancelBounty {
+            maker: maker.pubkey(),
+            bounty,
+            maker_profile,
+            vault: Some(vault),
+            mint: Some(mint.pubkey()),
+            maker_token_account: Some(maker_token_account),
+            token_program: Some(spl_token::ID),
+            associated_token_program: Some(spl_associated_token_account_client::program::ID),
+            memo_program: None,
+            event_authority,
+            program: bounty_hunter::ID,
+        })
+        .args(bounty_hunter::instruction::CancelBounty {})
+        .instruction()
+        .unwrap();
+
+    ctx.execute_instruction(cancel_ix, &[&maker])
+        .unwrap()
+        .assert_success();
+
+    ctx.svm.assert_token_balance(&maker_token_account, 10_000);
+    ctx.svm.assert_token_balance(&vault, 300);
+
+    let b: Bounty = ctx.get_account(&bounty).unwrap();
+    assert_eq!(b.refund_pool, Some(400));
+
+    // --- Anyone can crank the funder refund ---
+    let keeper = ctx.svm.create_funded_account(10_000_000_000).unwrap();
+
+    let refund_ix = ctx
+        .program()
+        .accounts(bounty_hunter::accounts::RefundContribution {
+            payer: keeper.pubkey(),
+            funder: funder.pubkey(),
+            maker: maker.pubkey(),
+            bounty,
+            contribution,
+            vault: Some(vault),
+            mint: Some(mint.pubkey()),
+            funder_token_account: Some(funder_token_account),
+            token_program: Some(spl_token::ID),
+            associated_token_program: Some(spl_associated_token_account_client::program::ID),
+            memo_program: None,
+            event_authority,
+            program: bounty_hunter::ID,
+        })
+        .args(bounty_hunter::instruction::RefundContribution {})
+        .instruction()
+        .unwrap();
+
+    ctx.execute_instruction(refund_ix, &[&keeper])
+        .unwrap()
+        .assert_success();
+
+    // --- Assertions ---
+    ctx.svm.assert_token_balance(&funder_token_account, 1_000);
+    ctx.svm.assert_account_closed(&contribution);
+    ctx.svm.assert_account_closed(&vault);
+
+    let b: Bounty = ctx.get_account(&bounty).unwrap();
+    assert_eq!(b.status, BountyStatus::Cancelled);
+    assert_eq!(b.escrowed, 0);
+}
@@ -0,0 +1,483 @@
+use anchor_client::solana_sdk::clock::Clock;
+use anchor_litesvm::{AnchorContext, AnchorLiteSVM, Pubkey, Signer};
+use bounty_hunter::state::{
+    Bounty, BountyAccess, BountyStatus, MakerProfile, Submission, SubmissionStatus,
+};
+use litesvm_utils::{AssertionHelpers, TestHelpers};
+
+use crate::program_data::set_upgrade_authority;
+
+use spl_associated_token_account_client::address::get_associated_token_address;
+
+#[test]
+fn dispute_awarded_to_hunter_test() {
+    let mut ctx = AnchorLiteSVM::build_with_program(
+        bounty_hunter::ID,
+        include_bytes!("../../target/deploy/bounty_hunter.so"),
+    );
+    let (event_authority, _) = ctx
+        .svm
+        .get_pda_with_bump(&[b"__event_authority"], &bounty_hunter::ID);
+
+    let maker = ctx.svm.create_funded_account(10_000_000_000).unwrap();
+    let arbiter = ctx.svm.create_funded_account(10_000_000_000).unwrap();
+    let hunter = ctx.svm.create_funded_account(10_000_000_000).unwrap();
+    let mint = ctx.svm.create_token_mint(&maker, 3).unwrap();
+
+    let maker_token_account = ctx
+        .svm
+        .create_associated_token_account(&mint.pubkey(), &maker)
+        .unwrap();
+
+    ctx.svm
+        .mint_to(&mint.pubkey(), &maker_token_account, &maker, 10_000)
+        .unwrap();
+
+    let (config, _) = ctx.svm.get_pda_with_bump(&[b"config"], &bounty_hunter::ID);
+
+    let program_data = set_upgrade_authority(&mut ctx, &maker.pubkey());
+
+    let config_ix = ctx
+        .program()
+        .accounts(bounty_hunter::accounts::InitializeConfig {
+            admin: maker.pubkey(),
+            config,
+            program_data,
+            system_program: solana_system_interface::program::ID,
+            event_authority,
+            program: bounty_hunter::ID,
+        })
+        .args(bounty_hunter::instruction::InitializeConfig {
+            fee_bps: 0,
+            treasury: maker.pubkey(),
+        })
+        .instruction()
+        .unwrap();
+
+    ctx.execute_instruction(config_ix, &[&maker])
+        .unwrap()
+        .assert_success();
+
+    let seed = 1u64;
+
+    let (bounty, _) = ctx.svm.get_pda_with_bump(
+        &[b"bounty", maker.pubkey().as_array(), &seed.to_le_bytes()],
+        &bounty_hunter::ID,
+    );
+    let (maker_profile, _) = ctx.svm.get_pda_with_bump(
+        &[b"maker_profile", maker.pubkey().as_ref()],
+        &bounty_hunter::ID,
+    );
+    let vault = get_associated_token_address(&bounty, &mint.pubkey());
+
+    let mut clock: Clock = ctx.svm.get_sysvar();
+    let deadline = clock.unix_timestamp + 60;
+
+    let (min_reward, _) = ctx
+        .svm
+        .get_pda_with_bump(&[b"min_reward", mint.pubkey().as_ref()], &bounty_hunter::ID);
+    let create_ix = ctx
+        .program()
+        .accounts(bounty_hunter::accounts::CreateBounty {
+            maker: maker.pubkey(),
+            bounty,
+            maker_profile,
+            mint: Some(mint.pubkey()),
+            maker_token_account: Some(maker_token_account),
+            vault: Some(vault),
+            min_reward,
+            system_program: solana_system_interface::program::ID,
+            token_program: Some(spl_token::ID),
+            associated_token_program: Some(spl_associated_token_account_client::program::ID),
+            event_authority,
+            program: bounty_hunter::ID,
+        })
+        .args(bounty_hunter::instruction::CreateBounty {
+            seed,
+            description: "moderated".to_string(),
+            link: "https://issue.link".to_string(),
+            reward: 100,
+            deadline: Some(deadline),
+            prize_bps: vec![],
+            milestones: vec![],
+            arbiter: Some(arbiter.pubkey()),
+            access: BountyAccess::Public,
+            submission_bond: 0,
+            content_hash: None,
+        })
+        .instruction()
+        .unwrap();
+
+    ctx.execute_instruction(create_ix, &[&maker])
+        .unwrap()
+        .assert_success();
+
+    let (submission, _) = ctx.svm.get_pda_with_bump(
+        &[b"submission", hunter.pubkey().as_ref(), bounty.as_ref()],
+        &bounty_hunter::ID,
+    );
+
+    let submit_ix = ctx
+        .program()
+        .accounts(bounty_hunter::accounts::SubmitSolution {
+            hunter: hunter.pubkey(),
+            bounty,
+            submission,
+            system_program: solana_system_interface::program::ID,
+            event_authority,
+            program: bounty_hunter::ID,
+        })
+        .args(bounty_hunter::instruction::SubmitSolution {
+            link: "https://github.com/hunter/solution".to_string(),
+            notes: "ignored by the maker".to_string(),
+            proof: vec![],
+            content_hash: None,
+        })
+        .instruction()
+        .unwrap();
+
+    ctx.execute_instruction(submit_ix, &[&hunter])
+        .unwrap()
+        .assert_success();
+
+    let dispute_ix = ctx
+        .program()
+        .accounts(bounty_hunter::accounts::OpenDispute {
+            hunter: hunter.pubkey(),
+            bounty,
+            submission,
+            event_authority,
+            program: bounty_hunter::ID,
+        })
+        .args(bounty_hunter::instruction::OpenDispute {})
+        .instruction()
+        .unwrap();
+
+    ctx.execute_instruction(dispute_ix, &[&hunter])
+        .unwrap()
+        .assert_success();
+
+    let b: Bounty = ctx.get_account(&bounty).unwrap();
+    assert_eq!(b.dispute, Some(submission));
+    assert_eq!(b.status, BountyStatus::Disputed);
+
+    // the maker can't run out the clock while the dispute is open
+    clock.unix_timestamp = deadline + 1;
+    ctx.svm.set_sysvar(&clock);
+
+    let expire_ix = ctx
+        .program()
+        .accounts(bounty_hunter::accounts::ExpireBounty {
+            payer: maker.pubkey(),
+            maker: maker.pubkey(),
+            bounty,
+            maker_profile,
+            vault: Some(vault),
+            mint: Some(mint.pubkey()),
+            maker_token_account: Some(maker_token_account),
+            token_program: Some(spl_token::ID),
+            associated_token_program: Some(spl_associated_token_account_client::program::ID),
+            memo_program: None,
+            event_authority,
+            program: bounty_hunter::ID,
+        })
+        .args(bounty_hunter::instruction::ExpireBounty {})
+        .instruction()
+        .unwrap();
+
+    ctx.execute_instruction(expire_ix, &[&maker])
+        .unwrap()
+        .assert_anchor_error("BountyDisputed");
+
+    let hunter_token_account = ctx
+        .svm
+        .create_associated_token_account(&mint.pubkey(), &hunter)
+        .unwrap();
+
+    let (receipt, _) = ctx
+        .svm
+        .get_pda_with_bump(&[b"receipt", bounty.as_ref(), &[0]], &bounty_hunter::ID);
+
+    let resolve_ix = |ctx: &AnchorContext, resolver| {
+        let (hunter_profile, _) = ctx.svm.get_pda_with_bump(
+            &[b"hunter_profile", hunter.pubkey().as_ref()],
+            &bounty_hunter::ID,
+        );
+        ctx.program()
+            .accounts(bounty_hunter::accounts::ResolveDispute {
+                arbiter: resolver,
+                maker: maker.pubkey(),
+                bounty,
+                submission,
+                receipt: Some(receipt),
+                maker_profile,
+                hunter_profile: Some(hunter_profile),
+                hunter: hunter.pubkey(),
+                vault: Some(vault),
+                mint: Some(mint.pubkey()),
+                hunter_token_account: Some(hunter_token_account),
+                config,
+                treasury: maker.pubkey(),
+                treasury_token_account: None,
+                system_program: solana_system_interface::program::ID,
+                token_program: Some(spl_token::ID),
+                associated_token_program: Some(spl_associated_token_account_client::program::ID),
+                memo_program: None,
+                event_authority,
+                program: bounty_hunter::ID,
+            })
+            .args(bounty_hunter::instruction::ResolveDispute { award_hunter: true })
+            .instruction()
+            .unwrap()
+    };
+
+    // only the arbiter settles
+    let ix = resolve_ix(&ctx, maker.pubkey());
+    ctx.execute_instruction(ix, &[&maker])
+        .unwrap()
+        .assert_anchor_error("InvalidArbiter");
+
+    let ix = resolve_ix(&ctx, arbiter.pubkey());
+    ctx.execute_instruction(ix, &[&arbiter])
+        .unwrap()
+        .assert_success();
+
+    // --- Assertions ---
+    ctx.svm.assert_token_balance(&hunter_token_account, 100);
+    ctx.svm.assert_account_closed(&vault);
+
+    let b: Bounty = ctx.get_account(&bounty).unwrap();
+    assert_eq!(b.dispute, None);
+    assert_eq!(b.accepted_submissions, vec![submission]);
+    assert_eq!(b.status, BountyStatus::Completed);
+}
+
+#[test]
+fn dispute_awarded_to_maker_test() {
+    let mut ctx = AnchorLiteSVM::build_with_program(
+        bounty_hunter::ID,
+        include_bytes!("../../target/deploy/bounty_hunter.so"),
+    );
+    let (event_authority, _) = ctx
+        .svm
+        .get_pda_with_bump(&[b"__event_authority"], &bounty_hunter::ID);
+
+    let maker = ctx.svm.create_funded_account(10_000_000_000).unwrap();
+    let arbiter = ctx.svm.create_funded_account(10_000_000_000).unwrap();
+    let hunter = ctx.svm.create_funded_account(10_000_000_000).unwrap();
+
+    let (config, _) = ctx.svm.get_pda_with_bump(&[b"config"], &bounty_hunter::ID);
+
+    let program_data = set_upgrade_authority(&mut ctx, &maker.pubkey());
+
+    let config_ix = ctx
+        .program()
+        .accounts(bounty_hunter::accounts::InitializeConfig {
+            admin: maker.pubkey(),
+            config,
+            program_data,
+            system_program: solana_system_interface::program::ID,
+            event_authority,
+            program: bounty_hunter::ID,
+        })
+        .args(bounty_hunter::instruction::InitializeConfig {
+            fee_bps: 0,
+            treasury: maker.pubkey(),
+        })
+        .instruction()
+        .unwrap();
+
+    ctx.execute_instruction(config_ix, &[&maker])
+        .unwrap()
+        .assert_success();
+
+    let seed = 1u64;
+    let reward = 1_000_000_000u64;
+
+    let (bounty, _) = ctx.svm.get_pda_with_bump(
+        &[b"bounty", maker.pubkey().as_array(), &seed.to_le_bytes()],
+        &bounty_hunter::ID,
+    );
+    let (maker_profile, _) = ctx.svm.get_pda_with_bump(
+        &[b"maker_profile", maker.pubkey().as_ref()],
+        &bounty_hunter::ID,
+    );
+
+    let (min_reward, _) = ctx.svm.get_pda_with_bump(
+        &[b"min_reward", Pubkey::default().as_ref()],
+        &bounty_hunter::ID,
+    );
+    let create_ix = ctx
+        .program()
+        .accounts(bounty_hunter::accounts::CreateBounty {
+            maker: maker.pubkey(),
+            bounty,
+            maker_profile,
+            mint: None,
+            maker_token_account: None,
+            vault: None,
+            min_reward,
+            system_program: solana_system_interface::program::ID,
+            token_program: None,
+            associated_token_program: None,
+            event_authority,
+            program: bounty_hunter::ID,
+        })
+        .args(bounty_hunter::instruction::CreateBounty {
+            seed,
+            description: "moderated".to_string(),
+            link: "https://issue.link".to_string(),
+            reward,
+            deadline: None,
+            prize_bps: vec![],
+            milestones: vec![],
+            arbiter: Some(arbiter.pubkey()),
+            access: BountyAccess::Public,
+            submission_bond: 0,
+            content_hash: None,
+        })
+        .instruction()
+        .unwrap();
+
+    ctx.execute_instruction(create_ix, &[&maker])
+        .unwrap()
+        .assert_success();
+
+    let (submission, _) = ctx.svm.get_pda_with_bump(
+        &[b"submission", hunter.pubkey().as_ref(), bounty.as_ref()],
+        &bounty_hunter::ID,
+    );
+
+    let submit_ix = ctx
+        .program()
+        .accounts(bounty_hunter::accounts::SubmitSolution {
+            hunter: hunter.pubkey(),
+            bounty,
+            submission,
+            system_program: solana_system_interface::program::ID,
+            event_authority,
+            program: bounty_hunter::ID,
+        })
+        .args(bounty_hunter::instruction::SubmitSolution {
+            link: "https://github.com/hunter/spam".to_string(),
+            notes: "not a solution".to_string(),
+            proof: vec![],
+            content_hash: None,
+        })
+        .instruction()
+        .unwrap();
+
+    ctx.execute_instruction(submit_ix, &[&hunter])
+        .unwrap()
+        .assert_success();
+
+    let dispute_ix = ctx
+        .program()
+        .accounts(bounty_hunter::accounts::OpenDispute {
+            hunter: hunter.pubkey(),
+            bounty,
+            submission,
+            event_authority,
+            program: bounty_hunter::ID,
+        })
+        .args(bounty_hunter::instruction::OpenDispute {})
+        .instruction()
+        .unwrap();
+
+    ctx.execute_instruction(dispute_ix, &[&hunter])
+        .unwrap()
+        .assert_success();
+
+    let bounty_balance = ctx.svm.get_balance(&bounty).unwrap();
+
+    let resolve_ix = ctx
+        .program()
+        .accounts(bounty_hunter::accounts::ResolveDispute {
+            arbiter: arbiter.pubkey(),
+            maker: maker.pubkey(),
+            bounty,
+            submission,
+            receipt: None,
+            maker_profile,
+            hunter_profile: None,
+            hunter: hunter.pubkey(),
+            vault: None,
+            mint: None,
+            hunter_token_account: None,
+            config,
+            treasury: maker.pubkey(),
+            treasury_token_account: None,
+            system_program: solana_system_interface::program::ID,
+            token_program: None,
+            associated_token_program: None,
+            memo_program: None,
+            event_authority,
+            program: bounty_hunter::ID,
+        })
+        .args(bounty_hunter::instruction::ResolveDispute {
+            award_hunter: false,
+        })
+        .instruction()
+        .unwrap();
+
+    ctx.execute_instruction(resolve_ix, &[&arbiter])
+        .unwrap()
+        .assert_success();
+
+    // the hunter can't escalate the same submission again
+    let dispute_ix = ctx
+        .program()
+        .accounts(bounty_hunter::accounts::OpenDispute {
+            hunter: hunter.pubkey(),
+            bounty,
+            submission,
+            event_authority,
+            program: bounty_hunter::ID,
+        })
+        .args(bounty_hunter::instruction::OpenDispute {})
+        .instruction()
+        .unwrap();
+
+    ctx.execute_instruction(dispute_ix, &[&hunter])
+        .unwrap()
+        .assert_anchor_error("SubmissionRejected");
+
+    // and the maker still can't walk away with the reward
+    let cancel_ix = ctx
+        .program()
+        .accounts(bounty_hunter::accounts::CancelBounty {
+            maker: maker.pubkey(),
+            bounty,
+            maker_profile,
+            vault: None,
+            mint: None,
+            maker_token_account: None,
+            token_program: None,
+            associated_token_program: None,
+            memo_program: None,
+            event_authority,
+            program: bounty_hunter::ID,
+        })
+        .args(bounty_hunter::instruction::CancelBounty {})
+        .instruction()
+        .unwrap();
+
+    ctx.execute_instruction(cancel_ix, &[&maker])
+        .unwrap()
+        .assert_anchor_error("BountyHasSubmissions");
+
+    // --- Assertions ---
+    assert_eq!(ctx.svm.get_balance(&bounty).unwrap(), bounty_balance);
+
+    let b: Bounty = ctx.get_account(&bounty).unwrap();
+    assert_eq!(b.status, BountyStatus::InReview);
+    assert_eq!(b.dispute, None);
+    assert_eq!(b.escrowed, reward);
+    assert_eq!(b.submissions, 1);
+
+    let sub: Submission = ctx.get_account(&submission).unwrap();
+    assert_eq!(sub.status, SubmissionStatus::Rejected);
+
+    let profile: MakerProfile = ctx.get_account(&maker_profile).unwrap();
+    assert_eq!(profile.bounties_cancelled, 0);
+}
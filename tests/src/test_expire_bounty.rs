@@ -0,0 +1,242 @@
+use anchor_client::solana_sdk::clock::Clock;
+use anchor_litesvm::{AnchorLiteSVM, Signer};
+use bounty_hunter::state::{Bounty, BountyAccess, BountyStatus};
+use litesvm_utils::{AssertionHelpers, TestHelpers};
+
+use spl_associated_token_account_client::address::get_associated_token_address;
+
+#[test]
+fn expire_bounty_test() {
+    let mut ctx = AnchorLiteSVM::build_with_program(
+        bounty_hunter::ID,
+        include_bytes!("../../target/deploy/bounty_hunter.so"),
+    );
+    let (event_authority, _) = ctx
+        .svm
+        .get_pda_with_bump(&[b"__event_authority"], &bounty_hunter::ID);
+
+    let maker = ctx.svm.create_funded_account(10_000_000_000).unwrap();
+    let mint = ctx.svm.create_token_mint(&maker, 3).unwrap();
+
+    let maker_token_account = ctx
+        .svm
+        .create_associated_token_account(&mint.pubkey(), &maker)
+        .unwrap();
+
+    ctx.svm
+        .mint_to(&mint.pubkey(), &maker_token_account, &maker, 10_000)
+        .unwrap();
+
+    let seed = 1u64;
+
+    let (bounty, _) = ctx.svm.get_pda_with_bump(
+        &[b"bounty", maker.pubkey().as_array(), &seed.to_le_bytes()],
+        &bounty_hunter::ID,
+    );
+    let (maker_profile, _) = ctx.svm.get_pda_with_bump(
+        &[b"maker_profile", maker.pubkey().as_ref()],
+        &bounty_hunter::ID,
+    );
+    let vault = get_associated_token_address(&bounty, &mint.pubkey());
+
+    let mut clock: Clock = ctx.svm.get_sysvar();
+    let deadline = clock.unix_timestamp + 60;
+
+    let (min_reward, _) = ctx
+        .svm
+        .get_pda_with_bump(&[b"min_reward", mint.pubkey().as_ref()], &bounty_hunter::ID);
+    let create_ix = ctx
+        .program()
+        .accounts(bounty_hunter::accounts::CreateBounty {
+            maker: maker.pubkey(),
+            bounty,
+            maker_profile,
+            mint: Some(mint.pubkey()),
+            maker_token_account: Some(maker_token_account),
+            vault: Some(vault),
+            min_reward,
+            system_program: solana_system_interface::program::ID,
+            token_program: Some(spl_token::ID),
+            associated_token_program: Some(spl_associated_token_account_client::program::ID),
+            event_authority,
+            program: bounty_hunter::ID,
+        })
+        .args(bounty_hunter::instruction::CreateBounty {
+            seed,
+            description: "time boxed".to_string(),
+            link: "https://issue.link".to_string(),
+            reward: 100,
+            deadline: Some(deadline),
+            prize_bps: vec![],
+            milestones: vec![],
+            arbiter: None,
+            access: BountyAccess::Public,
+            submission_bond: 0,
+            content_hash: None,
+        })
+        .instruction()
+        .unwrap();
+
+    ctx.execute_instruction(create_ix, &[&maker])
+        .unwrap()
+        .assert_success();
+
+    let b: Bounty = ctx.get_account(&bounty).unwrap();
+    assert_eq!(b.deadline, Some(deadline));
+
+    // anyone can crank the expiry, the maker does not sign
+    let keeper = ctx.svm.create_funded_account(10_000_000_000).unwrap();
+
+    let expire_ix = ctx
+        .program()
+        .accounts(bounty_hunter::accounts::ExpireBounty {
+            payer: keeper.pubkey(),
+            maker: maker.pubkey(),
+            bounty,
+            maker_profile,
+            vault: Some(vault),
+            mint: Some(mint.pubkey()),
+            maker_token_account: Some(maker_token_account),
+            token_program: Some(spl_token::ID),
+            associated_token_program: Some(spl_associated_token_account_client::program::ID),
+            memo_program: None,
+            event_authority,
+            program: bounty_hunter::ID,
+        })
+        .args(bounty_hunter::instruction::ExpireBounty {})
+        .instruction()
+        .unwrap();
+
+    // too early
+    ctx.execute_instruction(expire_ix.clone(), &[&keeper])
+        .unwrap()
+        .assert_anchor_error("BountyNotExpired");
+
+    clock.unix_timestamp = deadline + 1;
+    ctx.svm.set_sysvar(&clock);
+    // fresh blockhash so the retry is not deduplicated against the failed attempt
+    ctx.svm.expire_blockhash();
+
+    ctx.execute_instruction(expire_ix, &[&keeper])
+        .unwrap()
+        .assert_success();
+
+    // --- Assertions ---
+    ctx.svm.assert_account_closed(&vault);
+    ctx.svm.assert_token_balance(&maker_token_account, 10_000);
+
+    let b: Bounty = ctx.get_account(&bounty).unwrap();
+    assert_eq!(b.status, BountyStatus::Expired);
+}
+
+#[test]
+fn submit_after_deadline_test() {
+    let mut ctx = AnchorLiteSVM::build_with_program(
+        bounty_hunter::ID,
+        include_bytes!("../../target/deploy/bounty_hunter.so"),
+    );
+    let (event_authority, _) = ctx
+        .svm
+        .get_pda_with_bump(&[b"__event_authority"], &bounty_hunter::ID);
+
+    let maker = ctx.svm.create_funded_account(10_000_000_000).unwrap();
+    let mint = ctx.svm.create_token_mint(&maker, 3).unwrap();
+
+    let maker_token_account = ctx
+        .svm
+        .create_associated_token_account(&mint.pubkey(), &maker)
+        .unwrap();
+
+    ctx.svm
+        .mint_to(&mint.pubkey(), &maker_token_account, &maker, 10_000)
+        .unwrap();
+
+    let seed = 1u64;
+
+    let (bounty, _) = ctx.svm.get_pda_with_bump(
+        &[b"bounty", maker.pubkey().as_array(), &seed.to_le_bytes()],
+        &bounty_hunter::ID,
+    );
+    let (maker_profile, _) = ctx.svm.get_pda_with_bump(
+        &[b"maker_profile", maker.pubkey().as_ref()],
+        &bounty_hunter::ID,
+    );
+    let vault = get_associated_token_address(&bounty, &mint.pubkey());
+
+    let mut clock: Clock = ctx.svm.get_sysvar();
+    let deadline = clock.unix_timestamp + 60;
+
+    let (min_reward, _) = ctx
+        .svm
+        .get_pda_with_bump(&[b"min_reward", mint.pubkey().as_ref()], &bounty_hunter::ID);
+    let create_ix = ctx
+        .program()
+        .accounts(bounty_hunter::accounts::CreateBounty {
+            maker: maker.pubkey(),
+            bounty,
+            maker_profile,
+            mint: Some(mint.pubkey()),
+            maker_token_account: Some(maker_token_account),
+            vault: Some(vault),
+            min_reward,
+            system_program: solana_system_interface::program::ID,
+            token_program: Some(spl_token::ID),
+            associated_token_program: Some(spl_associated_token_account_client::program::ID),
+            event_authority,
+            program: bounty_hunter::ID,
+        })
+        .args(bounty_hunter::instruction::CreateBounty {
+            seed,
+            description: "time boxed".to_string(),
+            link: "https://issue.link".to_string(),
+            reward: 100,
+            deadline: Some(deadline),
+            prize_bps: vec![],
+            milestones: vec![],
+            arbiter: None,
+            access: BountyAccess::Public,
+            submission_bond: 0,
+            content_hash: None,
+        })
+        .instruction()
+        .unwrap();
+
+    ctx.execute_instruction(create_ix, &[&maker])
+        .unwrap()
+        .assert_success();
+
+    clock.unix_timestamp = deadline + 1;
+    ctx.svm.set_sysvar(&clock);
+
+    let hunter = ctx.svm.create_funded_account(10_000_000_000).unwrap();
+
+    let (submission, _) = ctx.svm.get_pda_with_bump(
+        &[b"submission", hunter.pubkey().as_ref(), bounty.as_ref()],
+        &bounty_hunter::ID,
+    );
+
+    let submit_ix = ctx
+        .program()
+        .accounts(bounty_hunter::accounts::SubmitSolution {
+            hunter: hunter.pubkey(),
+            bounty,
+            submission,
+            system_program: solana_system_interface::program::ID,
+            event_authority,
+            program: bounty_hunter::ID,
+        })
+        .args(bounty_hunter::instruction::SubmitSolution {
+            link: "https://github.com/solution".to_string(),
+            notes: "too late".to_string(),
+            proof: vec![],
+            content_hash: None,
+        })
+        .instruction()
+        .unwrap();
+
+    ctx.execute_instruction(submit_ix, &[&hunter])
+        .unwrap()
+        .assert_anchor_error("BountyExpired");
+
+    ctx.svm.assert_account_closed(&submission);
+}
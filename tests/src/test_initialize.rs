@@ -1,7 +1,10 @@
 use anchor_litesvm::{AnchorLiteSVM, Signer};
-use bounty_hunter::state::{Bounty, Submission};
+use bounty_hunter::state::{Bounty, BountyAccess, BountyStatus, Submission};
 use litesvm_utils::{AssertionHelpers, TestHelpers};
 
+use crate::program_data::set_upgrade_authority;
+use crate::review::review_submission;
+
 use spl_associated_token_account_client::address::get_associated_token_address;
 
 #[test]
@@ -10,6 +13,9 @@ fn create_bounty_test() {
         bounty_hunter::ID,
         include_bytes!("../../target/deploy/bounty_hunter.so"),
     );
+    let (event_authority, _) = ctx
+        .svm
+        .get_pda_with_bump(&[b"__event_authority"], &bounty_hunter::ID);
 
     let user = ctx.svm.create_funded_account(10_000_000_000).unwrap();
     let mint = ctx.svm.create_token_mint(&user, 3).unwrap();
@@ -29,26 +35,44 @@ fn create_bounty_test() {
         &[b"bounty", user.pubkey().as_array(), &seed.to_le_bytes()],
         &bounty_hunter::ID,
     );
+    let (maker_profile, _) = ctx.svm.get_pda_with_bump(
+        &[b"maker_profile", user.pubkey().as_ref()],
+        &bounty_hunter::ID,
+    );
     let vault = get_associated_token_address(&bounty, &mint.pubkey());
 
     // --- Create bounty ---
+    let (min_reward, _) = ctx
+        .svm
+        .get_pda_with_bump(&[b"min_reward", mint.pubkey().as_ref()], &bounty_hunter::ID);
     let ix = ctx
         .program()
         .accounts(bounty_hunter::accounts::CreateBounty {
             maker: user.pubkey(),
             bounty: bounty,
-            mint: mint.pubkey(),
-            maker_token_account: maker_token_account,
-            vault: vault,
+            maker_profile,
+            mint: Some(mint.pubkey()),
+            maker_token_account: Some(maker_token_account),
+            vault: Some(vault),
+            min_reward,
             system_program: solana_system_interface::program::ID,
-            token_program: spl_token::ID,
-            associated_token_program: spl_associated_token_account_client::program::ID,
+            token_program: Some(spl_token::ID),
+            associated_token_program: Some(spl_associated_token_account_client::program::ID),
+            event_authority,
+            program: bounty_hunter::ID,
         })
         .args(bounty_hunter::instruction::CreateBounty {
             seed: seed,
             description: "testeeee".to_string(),
-            link: "httpQQcoisa".to_string(),
+            link: "https://coisa.link".to_string(),
             reward: 1,
+            deadline: None,
+            prize_bps: vec![],
+            milestones: vec![],
+            arbiter: None,
+            access: BountyAccess::Public,
+            submission_bond: 0,
+            content_hash: None,
         })
         .instruction()
         .unwrap();
@@ -61,7 +85,7 @@ fn create_bounty_test() {
 
     assert_eq!(b.maker, user.pubkey());
     assert_eq!(b.description, "testeeee".to_string());
-    assert_eq!(b.link, "httpQQcoisa".to_string());
+    assert_eq!(b.link, "https://coisa.link".to_string());
     assert_eq!(b.reward, 1);
     assert_eq!(b.seed, seed);
 
@@ -74,6 +98,9 @@ fn cancel_bounty_test() {
         bounty_hunter::ID,
         include_bytes!("../../target/deploy/bounty_hunter.so"),
     );
+    let (event_authority, _) = ctx
+        .svm
+        .get_pda_with_bump(&[b"__event_authority"], &bounty_hunter::ID);
 
     let user = ctx.svm.create_funded_account(10_000_000_000).unwrap();
     let mint = ctx.svm.create_token_mint(&user, 3).unwrap();
@@ -93,26 +120,44 @@ fn cancel_bounty_test() {
         &[b"bounty", user.pubkey().as_array(), &seed.to_le_bytes()],
         &bounty_hunter::ID,
     );
+    let (maker_profile, _) = ctx.svm.get_pda_with_bump(
+        &[b"maker_profile", user.pubkey().as_ref()],
+        &bounty_hunter::ID,
+    );
     let vault = get_associated_token_address(&bounty, &mint.pubkey());
 
     // --- Create bounty ---
+    let (min_reward, _) = ctx
+        .svm
+        .get_pda_with_bump(&[b"min_reward", mint.pubkey().as_ref()], &bounty_hunter::ID);
     let ix = ctx
         .program()
         .accounts(bounty_hunter::accounts::CreateBounty {
             maker: user.pubkey(),
             bounty: bounty,
-            mint: mint.pubkey(),
-            maker_token_account: maker_token_account,
-            vault: vault,
+            maker_profile,
+            mint: Some(mint.pubkey()),
+            maker_token_account: Some(maker_token_account),
+            vault: Some(vault),
+            min_reward,
             system_program: solana_system_interface::program::ID,
-            token_program: spl_token::ID,
-            associated_token_program: spl_associated_token_account_client::program::ID,
+            token_program: Some(spl_token::ID),
+            associated_token_program: Some(spl_associated_token_account_client::program::ID),
+            event_authority,
+            program: bounty_hunter::ID,
         })
         .args(bounty_hunter::instruction::CreateBounty {
             seed: seed,
             description: "testeeee".to_string(),
-            link: "httpQQcoisa".to_string(),
+            link: "https://coisa.link".to_string(),
             reward: 1,
+            deadline: None,
+            prize_bps: vec![],
+            milestones: vec![],
+            arbiter: None,
+            access: BountyAccess::Public,
+            submission_bond: 0,
+            content_hash: None,
         })
         .instruction()
         .unwrap();
@@ -126,11 +171,15 @@ fn cancel_bounty_test() {
         .accounts(bounty_hunter::accounts::CancelBounty {
             maker: user.pubkey(),
             bounty,
-            vault,
-            mint: mint.pubkey(),
-            maker_token_account,
-            token_program: spl_token::ID,
-            associated_token_program: spl_associated_token_account_client::program::ID,
+            maker_profile,
+            vault: Some(vault),
+            mint: Some(mint.pubkey()),
+            maker_token_account: Some(maker_token_account),
+            token_program: Some(spl_token::ID),
+            associated_token_program: Some(spl_associated_token_account_client::program::ID),
+            memo_program: None,
+            event_authority,
+            program: bounty_hunter::ID,
         })
         .args(bounty_hunter::instruction::CancelBounty {})
         .instruction()
@@ -148,10 +197,10 @@ fn cancel_bounty_test() {
     // Maker should get the reward back
     ctx.svm.assert_token_balance(&maker_token_account, 10_000);
 
-    // Bounty account should be closed (or no longer exist)
-    let res = ctx.get_account::<Bounty>(&bounty);
-    assert!(res.is_err());
-    ctx.svm.assert_account_closed(&bounty);
+    // Bounty account stays around, marked as cancelled
+    let b: Bounty = ctx.get_account(&bounty).unwrap();
+    assert_eq!(b.status, BountyStatus::Cancelled);
+    assert_eq!(b.escrowed, 0);
 }
 
 #[test]
@@ -160,6 +209,9 @@ fn submit_solution_test() {
         bounty_hunter::ID,
         include_bytes!("../../target/deploy/bounty_hunter.so"),
     );
+    let (event_authority, _) = ctx
+        .svm
+        .get_pda_with_bump(&[b"__event_authority"], &bounty_hunter::ID);
 
     let user = ctx.svm.create_funded_account(10_000_000_000).unwrap();
     let mint = ctx.svm.create_token_mint(&user, 3).unwrap();
@@ -179,25 +231,43 @@ fn submit_solution_test() {
         &[b"bounty", user.pubkey().as_array(), &seed.to_le_bytes()],
         &bounty_hunter::ID,
     );
+    let (maker_profile, _) = ctx.svm.get_pda_with_bump(
+        &[b"maker_profile", user.pubkey().as_ref()],
+        &bounty_hunter::ID,
+    );
     let vault = get_associated_token_address(&bounty, &mint.pubkey());
 
+    let (min_reward, _) = ctx
+        .svm
+        .get_pda_with_bump(&[b"min_reward", mint.pubkey().as_ref()], &bounty_hunter::ID);
     let create_ix = ctx
         .program()
         .accounts(bounty_hunter::accounts::CreateBounty {
             maker: user.pubkey(),
             bounty,
-            mint: mint.pubkey(),
-            maker_token_account,
-            vault,
+            maker_profile,
+            mint: Some(mint.pubkey()),
+            maker_token_account: Some(maker_token_account),
+            vault: Some(vault),
+            min_reward,
             system_program: solana_system_interface::program::ID,
-            token_program: spl_token::ID,
-            associated_token_program: spl_associated_token_account_client::program::ID,
+            token_program: Some(spl_token::ID),
+            associated_token_program: Some(spl_associated_token_account_client::program::ID),
+            event_authority,
+            program: bounty_hunter::ID,
         })
         .args(bounty_hunter::instruction::CreateBounty {
             seed,
             description: "solve me".to_string(),
             link: "https://bounty.link".to_string(),
             reward: 1,
+            deadline: None,
+            prize_bps: vec![],
+            milestones: vec![],
+            arbiter: None,
+            access: BountyAccess::Public,
+            submission_bond: 0,
+            content_hash: None,
         })
         .instruction()
         .unwrap();
@@ -220,10 +290,14 @@ fn submit_solution_test() {
             bounty,
             submission,
             system_program: solana_system_interface::program::ID,
+            event_authority,
+            program: bounty_hunter::ID,
         })
         .args(bounty_hunter::instruction::SubmitSolution {
             link: "https://github.com/solution".to_string(),
             notes: "Here is my fix".to_string(),
+            proof: vec![],
+            content_hash: None,
         })
         .instruction()
         .unwrap();
@@ -239,6 +313,139 @@ fn submit_solution_test() {
     assert_eq!(s.hunter, hunter.pubkey());
     assert_eq!(s.link, "https://github.com/solution".to_string());
     assert_eq!(s.notes, "Here is my fix".to_string());
+
+    let b: Bounty = ctx.get_account(&bounty).unwrap();
+    assert_eq!(b.submissions, 1);
+}
+
+#[test]
+fn cancel_after_submission_test() {
+    let mut ctx = AnchorLiteSVM::build_with_program(
+        bounty_hunter::ID,
+        include_bytes!("../../target/deploy/bounty_hunter.so"),
+    );
+    let (event_authority, _) = ctx
+        .svm
+        .get_pda_with_bump(&[b"__event_authority"], &bounty_hunter::ID);
+
+    let user = ctx.svm.create_funded_account(10_000_000_000).unwrap();
+    let mint = ctx.svm.create_token_mint(&user, 3).unwrap();
+
+    let maker_token_account = ctx
+        .svm
+        .create_associated_token_account(&mint.pubkey(), &user)
+        .unwrap();
+
+    ctx.svm
+        .mint_to(&mint.pubkey(), &maker_token_account, &user, 10_000)
+        .unwrap();
+
+    let seed = 1u64;
+
+    let (bounty, _) = ctx.svm.get_pda_with_bump(
+        &[b"bounty", user.pubkey().as_array(), &seed.to_le_bytes()],
+        &bounty_hunter::ID,
+    );
+    let (maker_profile, _) = ctx.svm.get_pda_with_bump(
+        &[b"maker_profile", user.pubkey().as_ref()],
+        &bounty_hunter::ID,
+    );
+    let vault = get_associated_token_address(&bounty, &mint.pubkey());
+
+    let (min_reward, _) = ctx
+        .svm
+        .get_pda_with_bump(&[b"min_reward", mint.pubkey().as_ref()], &bounty_hunter::ID);
+    let create_ix = ctx
+        .program()
+        .accounts(bounty_hunter::accounts::CreateBounty {
+            maker: user.pubkey(),
+            bounty,
+            maker_profile,
+            mint: Some(mint.pubkey()),
+            maker_token_account: Some(maker_token_account),
+            vault: Some(vault),
+            min_reward,
+            system_program: solana_system_interface::program::ID,
+            token_program: Some(spl_token::ID),
+            associated_token_program: Some(spl_associated_token_account_client::program::ID),
+            event_authority,
+            program: bounty_hunter::ID,
+        })
+        .args(bounty_hunter::instruction::CreateBounty {
+            seed,
+            description: "solve me".to_string(),
+            link: "https://bounty.link".to_string(),
+            reward: 1,
+            deadline: None,
+            prize_bps: vec![],
+            milestones: vec![],
+            arbiter: None,
+            access: BountyAccess::Public,
+            submission_bond: 0,
+            content_hash: None,
+        })
+        .instruction()
+        .unwrap();
+
+    ctx.execute_instruction(create_ix, &[&user])
+        .unwrap()
+        .assert_success();
+
+    let hunter = ctx.svm.create_funded_account(10_000_000_000).unwrap();
+
+    let (submission, _) = ctx.svm.get_pda_with_bump(
+        &[b"submission", hunter.pubkey().as_ref(), bounty.as_ref()],
+        &bounty_hunter::ID,
+    );
+
+    let submit_ix = ctx
+        .program()
+        .accounts(bounty_hunter::accounts::SubmitSolution {
+            hunter: hunter.pubkey(),
+            bounty,
+            submission,
+            system_program: solana_system_interface::program::ID,
+            event_authority,
+            program: bounty_hunter::ID,
+        })
+        .args(bounty_hunter::instruction::SubmitSolution {
+            link: "https://github.com/solution".to_string(),
+            notes: "Here is my fix".to_string(),
+            proof: vec![],
+            content_hash: None,
+        })
+        .instruction()
+        .unwrap();
+
+    ctx.execute_instruction(submit_ix, &[&hunter])
+        .unwrap()
+        .assert_success();
+
+    let cancel_ix = ctx
+        .program()
+        .accounts(bounty_hunter::accounts::CancelBounty {
+            maker: user.pubkey(),
+            bounty,
+            maker_profile,
+            vault: Some(vault),
+            mint: Some(mint.pubkey()),
+            maker_token_account: Some(maker_token_account),
+            token_program: Some(spl_token::ID),
+            associated_token_program: Some(spl_associated_token_account_client::program::ID),
+            memo_program: None,
+            event_authority,
+            program: bounty_hunter::ID,
+        })
+        .args(bounty_hunter::instruction::CancelBounty {})
+        .instruction()
+        .unwrap();
+
+    ctx.execute_instruction(cancel_ix, &[&user])
+        .unwrap()
+        .assert_anchor_error("BountyHasSubmissions");
+
+    // The reward stays escrowed for the hunters
+    ctx.svm.assert_token_balance(&vault, 1);
 }
 
 #[test]
@@ -247,6 +454,9 @@ fn accept_solution_test() {
         bounty_hunter::ID,
         include_bytes!("../../target/deploy/bounty_hunter.so"),
     );
+    let (event_authority, _) = ctx
+        .svm
+        .get_pda_with_bump(&[b"__event_authority"], &bounty_hunter::ID);
 
     let maker = ctx.svm.create_funded_account(10_000_000_000).unwrap();
     let mint = ctx.svm.create_token_mint(&maker, 3).unwrap();
@@ -266,26 +476,44 @@ fn accept_solution_test() {
         &[b"bounty", maker.pubkey().as_array(), &seed.to_le_bytes()],
         &bounty_hunter::ID,
     );
+    let (maker_profile, _) = ctx.svm.get_pda_with_bump(
+        &[b"maker_profile", maker.pubkey().as_ref()],
+        &bounty_hunter::ID,
+    );
 
     let vault = get_associated_token_address(&bounty, &mint.pubkey());
 
+    let (min_reward, _) = ctx
+        .svm
+        .get_pda_with_bump(&[b"min_reward", mint.pubkey().as_ref()], &bounty_hunter::ID);
     let create_ix = ctx
         .program()
         .accounts(bounty_hunter::accounts::CreateBounty {
             maker: maker.pubkey(),
             bounty,
-            mint: mint.pubkey(),
-            maker_token_account,
-            vault,
+            maker_profile,
+            mint: Some(mint.pubkey()),
+            maker_token_account: Some(maker_token_account),
+            vault: Some(vault),
+            min_reward,
             system_program: solana_system_interface::program::ID,
-            token_program: spl_token::ID,
-            associated_token_program: spl_associated_token_account_client::program::ID,
+            token_program: Some(spl_token::ID),
+            associated_token_program: Some(spl_associated_token_account_client::program::ID),
+            event_authority,
+            program: bounty_hunter::ID,
         })
         .args(bounty_hunter::instruction::CreateBounty {
             seed,
             description: "fix this bug".to_string(),
             link: "https://issue.link".to_string(),
             reward: 1,
+            deadline: None,
+            prize_bps: vec![],
+            milestones: vec![],
+            arbiter: None,
+            access: BountyAccess::Public,
+            submission_bond: 0,
+            content_hash: None,
         })
         .instruction()
         .unwrap();
@@ -294,6 +522,31 @@ fn accept_solution_test() {
         .unwrap()
         .assert_success();
 
+    let (config, _) = ctx.svm.get_pda_with_bump(&[b"config"], &bounty_hunter::ID);
+
+    let program_data = set_upgrade_authority(&mut ctx, &maker.pubkey());
+
+    let config_ix = ctx
+        .program()
+        .accounts(bounty_hunter::accounts::InitializeConfig {
+            admin: maker.pubkey(),
+            config,
+            program_data,
+            system_program: solana_system_interface::program::ID,
+            event_authority,
+            program: bounty_hunter::ID,
+        })
+        .args(bounty_hunter::instruction::InitializeConfig {
+            fee_bps: 0,
+            treasury: maker.pubkey(),
+        })
+        .instruction()
+        .unwrap();
+
+    ctx.execute_instruction(config_ix, &[&maker])
+        .unwrap()
+        .assert_success();
+
     ctx.svm.assert_token_balance(&vault, 1);
 
     let hunter = ctx.svm.create_funded_account(10_000_000_000).unwrap();
@@ -310,10 +563,14 @@ fn accept_solution_test() {
             bounty,
             submission,
             system_program: solana_system_interface::program::ID,
+            event_authority,
+            program: bounty_hunter::ID,
         })
         .args(bounty_hunter::instruction::SubmitSolution {
             link: "https://github.com/hunter/solution".to_string(),
             notes: "This fixes everything".to_string(),
+            proof: vec![],
+            content_hash: None,
         })
         .instruction()
         .unwrap();
@@ -330,23 +587,50 @@ fn accept_solution_test() {
         .create_associated_token_account(&mint.pubkey(), &hunter)
         .unwrap();
 
+    let (receipt, _) = ctx
+        .svm
+        .get_pda_with_bump(&[b"receipt", bounty.as_ref(), &[0]], &bounty_hunter::ID);
+
+    let (hunter_profile, _) = ctx.svm.get_pda_with_bump(
+        &[b"hunter_profile", hunter.pubkey().as_ref()],
+        &bounty_hunter::ID,
+    );
     let accept_ix = ctx
         .program()
         .accounts(bounty_hunter::accounts::AcceptSolution {
             maker: maker.pubkey(),
             bounty,
             submission,
-            vault,
+            receipt,
+            maker_profile,
+            hunter_profile,
+            vault: Some(vault),
             hunter: hunter.pubkey(),
-            mint: mint.pubkey(),
-            hunter_token_account,
-            token_program: spl_token::ID,
-            associated_token_program: spl_associated_token_account_client::program::ID,
+            mint: Some(mint.pubkey()),
+            hunter_token_account: Some(hunter_token_account),
+            token_program: Some(spl_token::ID),
+            associated_token_program: Some(spl_associated_token_account_client::program::ID),
+            config,
+            treasury: maker.pubkey(),
+            treasury_token_account: None,
+            system_program: solana_system_interface::program::ID,
+            memo_program: None,
+            event_authority,
+            program: bounty_hunter::ID,
         })
         .args(bounty_hunter::instruction::AcceptSolution {})
         .instruction()
         .unwrap();
 
+    // still pending, the maker has to lock it for review first
+    ctx.execute_instruction(accept_ix.clone(), &[&maker])
+        .unwrap()
+        .assert_anchor_error("SubmissionNotInReview");
+
+    review_submission(&mut ctx, &maker, bounty, submission);
+    // fresh blockhash so the retry is not deduplicated against the failed attempt
+    ctx.svm.expire_blockhash();
+
     ctx.execute_instruction(accept_ix, &[&maker])
         .unwrap()
         .assert_success();
@@ -363,5 +647,5 @@ fn accept_solution_test() {
 
     // Bounty updated
     let b: Bounty = ctx.get_account(&bounty).unwrap();
-    assert_eq!(b.accepted_submission, submission);
+    assert_eq!(b.accepted_submissions, vec![submission]);
 }
@@ -0,0 +1,160 @@
+use anchor_client::anchor_lang::solana_program::hash::hash;
+use anchor_litesvm::{AnchorContext, AnchorLiteSVM, Pubkey, Signer};
+use bounty_hunter::state::{Bounty, BountyAccess, Submission};
+use litesvm_utils::TestHelpers;
+
+/// what the account holds on top of its rent
+fn held(ctx: &AnchorContext, account: &Pubkey) -> u64 {
+    let account = ctx.svm.get_account(account).unwrap();
+    account.lamports
+        - ctx
+            .svm
+            .minimum_balance_for_rent_exemption(account.data.len())
+}
+
+#[test]
+fn metadata_uri_test() {
+    let mut ctx = AnchorLiteSVM::build_with_program(
+        bounty_hunter::ID,
+        include_bytes!("../../target/deploy/bounty_hunter.so"),
+    );
+    let (event_authority, _) = ctx
+        .svm
+        .get_pda_with_bump(&[b"__event_authority"], &bounty_hunter::ID);
+
+    let maker = ctx.svm.create_funded_account(10_000_000_000).unwrap();
+    let hunter = ctx.svm.create_funded_account(10_000_000_000).unwrap();
+    let bond = 10_000_000u64;
+
+    let seed = 1u64;
+
+    let (bounty, _) = ctx.svm.get_pda_with_bump(
+        &[b"bounty", maker.pubkey().as_array(), &seed.to_le_bytes()],
+        &bounty_hunter::ID,
+    );
+    let (maker_profile, _) = ctx.svm.get_pda_with_bump(
+        &[b"maker_profile", maker.pubkey().as_ref()],
+        &bounty_hunter::ID,
+    );
+
+    let document = br#"{"description":"a long write-up of the issue"}"#;
+    let uri = "https://meta.link/bounty.json";
+
+    let (min_reward, _) = ctx.svm.get_pda_with_bump(
+        &[b"min_reward", Pubkey::default().as_ref()],
+        &bounty_hunter::ID,
+    );
+    let create_ix = ctx
+        .program()
+        .accounts(bounty_hunter::accounts::CreateBounty {
+            maker: maker.pubkey(),
+            bounty,
+            maker_profile,
+            mint: None,
+            maker_token_account: None,
+            vault: None,
+            min_reward,
+            system_program: solana_system_interface::program::ID,
+            token_program: None,
+            associated_token_program: None,
+            event_authority,
+            program: bounty_hunter::ID,
+        })
+        .args(bounty_hunter::instruction::CreateBounty {
+            seed,
+            description: uri.to_string(),
+            link: "https://issue.link".to_string(),
+            reward: 1_000_000_000,
+            deadline: None,
+            prize_bps: vec![],
+            milestones: vec![],
+            arbiter: None,
+            access: BountyAccess::Public,
+            submission_bond: bond,
+            content_hash: Some(hash(document).to_bytes()),
+        })
+        .instruction()
+        .unwrap();
+
+    ctx.execute_instruction(create_ix, &[&maker])
+        .unwrap()
+        .assert_success();
+
+    // only the URI is paid for, not the 1 KB an inline description could take
+    let b: Bounty = ctx.get_account(&bounty).unwrap();
+    assert_eq!(b.description, uri);
+    assert_eq!(b.content_hash, Some(hash(document).to_bytes()));
+    assert_eq!(
+        ctx.svm.get_account(&bounty).unwrap().data.len(),
+        Bounty::space(uri, "https://issue.link")
+    );
+
+    let (submission, _) = ctx.svm.get_pda_with_bump(
+        &[b"submission", hunter.pubkey().as_ref(), bounty.as_ref()],
+        &bounty_hunter::ID,
+    );
+
+    let notes = br#"{"notes":"how the fix works"}"#;
+
+    let submit_ix = ctx
+        .program()
+        .accounts(bounty_hunter::accounts::SubmitSolution {
+            hunter: hunter.pubkey(),
+            bounty,
+            submission,
+            system_program: solana_system_interface::program::ID,
+            event_authority,
+            program: bounty_hunter::ID,
+        })
+        .args(bounty_hunter::instruction::SubmitSolution {
+            link: "https://github.com/hunter/solution".to_string(),
+            notes: "https://meta.link/notes.json".to_string(),
+            proof: vec![],
+            content_hash: Some(hash(notes).to_bytes()),
+        })
+        .instruction()
+        .unwrap();
+
+    ctx.execute_instruction(submit_ix, &[&hunter])
+        .unwrap()
+        .assert_success();
+
+    assert_eq!(held(&ctx, &submission), bond);
+
+    // growing the submission is paid by the hunter, the bond stays put
+    let notes = br#"{"notes":"how the fix works, with benchmarks"}"#;
+    let edit_ix = ctx
+        .program()
+        .accounts(bounty_hunter::accounts::EditSubmission {
+            hunter: hunter.pubkey(),
+            bounty,
+            submission,
+            system_program: solana_system_interface::program::ID,
+            event_authority,
+            program: bounty_hunter::ID,
+        })
+        .args(bounty_hunter::instruction::EditSubmission {
+            link: "https://github.com/hunter/solution".to_string(),
+            notes: "https://meta.link/notes-with-benchmarks.json".to_string(),
+            content_hash: Some(hash(notes).to_bytes()),
+        })
+        .instruction()
+        .unwrap();
+
+    ctx.execute_instruction(edit_ix, &[&hunter])
+        .unwrap()
+        .assert_success();
+
+    // --- Assertions ---
+    let s: Submission = ctx.get_account(&submission).unwrap();
+    assert_eq!(s.notes, "https://meta.link/notes-with-benchmarks.json");
+    assert_eq!(s.content_hash, Some(hash(notes).to_bytes()));
+    assert_eq!(
+        ctx.svm.get_account(&submission).unwrap().data.len(),
+        Submission::space(
+            "https://github.com/hunter/solution",
+            "https://meta.link/notes-with-benchmarks.json"
+        )
+    );
+    assert_eq!(held(&ctx, &submission), bond);
+}
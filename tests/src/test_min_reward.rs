@@ -0,0 +1,184 @@
+use anchor_litesvm::{AnchorContext, AnchorLiteSVM, Instruction, Keypair, Pubkey, Signer};
+use bounty_hunter::state::{Bounty, BountyAccess, MinReward};
+use litesvm_utils::TestHelpers;
+
+use crate::program_data::set_upgrade_authority;
+
+fn set_min_reward_ix(ctx: &AnchorContext, admin: &Keypair, amount: u64) -> Instruction {
+    let (event_authority, _) = ctx
+        .svm
+        .get_pda_with_bump(&[b"__event_authority"], &bounty_hunter::ID);
+    let (config, _) = ctx.svm.get_pda_with_bump(&[b"config"], &bounty_hunter::ID);
+    let (min_reward, _) = ctx.svm.get_pda_with_bump(
+        &[b"min_reward", Pubkey::default().as_ref()],
+        &bounty_hunter::ID,
+    );
+
+    ctx.program()
+        .accounts(bounty_hunter::accounts::SetMinReward {
+            admin: admin.pubkey(),
+            config,
+            min_reward,
+            system_program: solana_system_interface::program::ID,
+            event_authority,
+            program: bounty_hunter::ID,
+        })
+        .args(bounty_hunter::instruction::SetMinReward {
+            mint: Pubkey::default(),
+            amount,
+        })
+        .instruction()
+        .unwrap()
+}
+
+fn create_ix(ctx: &AnchorContext, maker: &Keypair, bounty: Pubkey, reward: u64) -> Instruction {
+    let (event_authority, _) = ctx
+        .svm
+        .get_pda_with_bump(&[b"__event_authority"], &bounty_hunter::ID);
+    let (min_reward, _) = ctx.svm.get_pda_with_bump(
+        &[b"min_reward", Pubkey::default().as_ref()],
+        &bounty_hunter::ID,
+    );
+    let (maker_profile, _) = ctx.svm.get_pda_with_bump(
+        &[b"maker_profile", maker.pubkey().as_ref()],
+        &bounty_hunter::ID,
+    );
+
+    ctx.program()
+        .accounts(bounty_hunter::accounts::CreateBounty {
+            maker: maker.pubkey(),
+            bounty,
+            maker_profile,
+            mint: None,
+            maker_token_account: None,
+            vault: None,
+            min_reward,
+            system_program: solana_system_interface::program::ID,
+            token_program: None,
+            associated_token_program: None,
+            event_authority,
+            program: bounty_hunter::ID,
+        })
+        .args(bounty_hunter::instruction::CreateBounty {
+            seed: 1,
+            description: "fix the parser".to_string(),
+            link: "https://issue.link".to_string(),
+            reward,
+            deadline: None,
+            prize_bps: vec![],
+            milestones: vec![],
+            arbiter: None,
+            access: BountyAccess::Public,
+            submission_bond: 0,
+            content_hash: None,
+        })
+        .instruction()
+        .unwrap()
+}
+
+#[test]
+fn min_reward_test() {
+    let mut ctx = AnchorLiteSVM::build_with_program(
+        bounty_hunter::ID,
+        include_bytes!("../../target/deploy/bounty_hunter.so"),
+    );
+    let (event_authority, _) = ctx
+        .svm
+        .get_pda_with_bump(&[b"__event_authority"], &bounty_hunter::ID);
+
+    let admin = ctx.svm.create_funded_account(10_000_000_000).unwrap();
+    let maker = ctx.svm.create_funded_account(10_000_000_000).unwrap();
+
+    let (config, _) = ctx.svm.get_pda_with_bump(&[b"config"], &bounty_hunter::ID);
+    let (min_reward, _) = ctx.svm.get_pda_with_bump(
+        &[b"min_reward", Pubkey::default().as_ref()],
+        &bounty_hunter::ID,
+    );
+    let (bounty, _) = ctx.svm.get_pda_with_bump(
+        &[b"bounty", maker.pubkey().as_array(), &1u64.to_le_bytes()],
+        &bounty_hunter::ID,
+    );
+
+    let program_data = set_upgrade_authority(&mut ctx, &admin.pubkey());
+
+    let config_ix = ctx
+        .program()
+        .accounts(bounty_hunter::accounts::InitializeConfig {
+            admin: admin.pubkey(),
+            config,
+            program_data,
+            system_program: solana_system_interface::program::ID,
+            event_authority,
+            program: bounty_hunter::ID,
+        })
+        .args(bounty_hunter::instruction::InitializeConfig {
+            fee_bps: 0,
+            treasury: admin.pubkey(),
+        })
+        .instruction()
+        .unwrap();
+
+    ctx.execute_instruction(config_ix, &[&admin])
+        .unwrap()
+        .assert_success();
+
+    // only the admin sets minimums
+    let ix = set_min_reward_ix(&ctx, &maker, 1);
+    ctx.execute_instruction(ix, &[&maker])
+        .unwrap()
+        .assert_anchor_error("InvalidConfigAuthority");
+
+    let ix = set_min_reward_ix(&ctx, &admin, 1_000_000_000);
+    ctx.execute_instruction(ix, &[&admin])
+        .unwrap()
+        .assert_success();
+
+    let m: MinReward = ctx.get_account(&min_reward).unwrap();
+    assert_eq!(m.mint, Pubkey::default());
+    assert_eq!(m.amount, 1_000_000_000);
+
+    let ix = create_ix(&ctx, &maker, bounty, 999_999_999);
+    ctx.execute_instruction(ix, &[&maker])
+        .unwrap()
+        .assert_anchor_error("RewardBelowMinimum");
+
+    let ix = create_ix(&ctx, &maker, bounty, 1_500_000_000);
+    ctx.execute_instruction(ix, &[&maker])
+        .unwrap()
+        .assert_success();
+
+    let decrease_ix = |ctx: &AnchorContext, amount: u64| {
+        ctx.program()
+            .accounts(bounty_hunter::accounts::DecreaseReward {
+                maker: maker.pubkey(),
+                bounty,
+                mint: None,
+                vault: None,
+                maker_token_account: None,
+                min_reward,
+                token_program: None,
+                associated_token_program: None,
+                memo_program: None,
+                event_authority,
+                program: bounty_hunter::ID,
+            })
+            .args(bounty_hunter::instruction::DecreaseReward { amount })
+            .instruction()
+            .unwrap()
+    };
+
+    // the reward can't be decreased under the minimum either
+    let ix = decrease_ix(&ctx, 500_000_001);
+    ctx.execute_instruction(ix, &[&maker])
+        .unwrap()
+        .assert_anchor_error("RewardBelowMinimum");
+
+    let ix = decrease_ix(&ctx, 500_000_000);
+    ctx.execute_instruction(ix, &[&maker])
+        .unwrap()
+        .assert_success();
+
+    // --- Assertions ---
+    let b: Bounty = ctx.get_account(&bounty).unwrap();
+    assert_eq!(b.reward, 1_000_000_000);
+}
@@ -0,0 +1,221 @@
+use anchor_litesvm::{AnchorContext, AnchorLiteSVM, Signer};
+use bounty_hunter::state::{Bounty, BountyAccess, BountyStatus};
+use litesvm_utils::{AssertionHelpers, TestHelpers};
+
+use crate::program_data::set_upgrade_authority;
+use crate::review::review_submission;
+
+use spl_associated_token_account_client::address::get_associated_token_address;
+
+#[test]
+fn multi_winner_test() {
+    let mut ctx = AnchorLiteSVM::build_with_program(
+        bounty_hunter::ID,
+        include_bytes!("../../target/deploy/bounty_hunter.so"),
+    );
+    let (event_authority, _) = ctx
+        .svm
+        .get_pda_with_bump(&[b"__event_authority"], &bounty_hunter::ID);
+
+    let maker = ctx.svm.create_funded_account(10_000_000_000).unwrap();
+    let mint = ctx.svm.create_token_mint(&maker, 3).unwrap();
+
+    let maker_token_account = ctx
+        .svm
+        .create_associated_token_account(&mint.pubkey(), &maker)
+        .unwrap();
+
+    ctx.svm
+        .mint_to(&mint.pubkey(), &maker_token_account, &maker, 10_000)
+        .unwrap();
+
+    let seed = 1u64;
+
+    let (bounty, _) = ctx.svm.get_pda_with_bump(
+        &[b"bounty", maker.pubkey().as_array(), &seed.to_le_bytes()],
+        &bounty_hunter::ID,
+    );
+    let (maker_profile, _) = ctx.svm.get_pda_with_bump(
+        &[b"maker_profile", maker.pubkey().as_ref()],
+        &bounty_hunter::ID,
+    );
+    let vault = get_associated_token_address(&bounty, &mint.pubkey());
+
+    let (min_reward, _) = ctx
+        .svm
+        .get_pda_with_bump(&[b"min_reward", mint.pubkey().as_ref()], &bounty_hunter::ID);
+    let create_ix = ctx
+        .program()
+        .accounts(bounty_hunter::accounts::CreateBounty {
+            maker: maker.pubkey(),
+            bounty,
+            maker_profile,
+            mint: Some(mint.pubkey()),
+            maker_token_account: Some(maker_token_account),
+            vault: Some(vault),
+            min_reward,
+            system_program: solana_system_interface::program::ID,
+            token_program: Some(spl_token::ID),
+            associated_token_program: Some(spl_associated_token_account_client::program::ID),
+            event_authority,
+            program: bounty_hunter::ID,
+        })
+        .args(bounty_hunter::instruction::CreateBounty {
+            seed,
+            description: "hackathon".to_string(),
+            link: "https://hackathon.link".to_string(),
+            reward: 100,
+            deadline: None,
+            prize_bps: vec![7_000, 3_000],
+            milestones: vec![],
+            arbiter: None,
+            access: BountyAccess::Public,
+            submission_bond: 0,
+            content_hash: None,
+        })
+        .instruction()
+        .unwrap();
+
+    ctx.execute_instruction(create_ix, &[&maker])
+        .unwrap()
+        .assert_success();
+
+    let (config, _) = ctx.svm.get_pda_with_bump(&[b"config"], &bounty_hunter::ID);
+
+    let program_data = set_upgrade_authority(&mut ctx, &maker.pubkey());
+
+    let config_ix = ctx
+        .program()
+        .accounts(bounty_hunter::accounts::InitializeConfig {
+            admin: maker.pubkey(),
+            config,
+            program_data,
+            system_program: solana_system_interface::program::ID,
+            event_authority,
+            program: bounty_hunter::ID,
+        })
+        .args(bounty_hunter::instruction::InitializeConfig {
+            fee_bps: 0,
+            treasury: maker.pubkey(),
+        })
+        .instruction()
+        .unwrap();
+
+    ctx.execute_instruction(config_ix, &[&maker])
+        .unwrap()
+        .assert_success();
+
+    let mut winners = Vec::new();
+    for _ in 0..2 {
+        let hunter = ctx.svm.create_funded_account(10_000_000_000).unwrap();
+
+        let (submission, _) = ctx.svm.get_pda_with_bump(
+            &[b"submission", hunter.pubkey().as_ref(), bounty.as_ref()],
+            &bounty_hunter::ID,
+        );
+
+        let submit_ix = ctx
+            .program()
+            .accounts(bounty_hunter::accounts::SubmitSolution {
+                hunter: hunter.pubkey(),
+                bounty,
+                submission,
+                system_program: solana_system_interface::program::ID,
+                event_authority,
+                program: bounty_hunter::ID,
+            })
+            .args(bounty_hunter::instruction::SubmitSolution {
+                link: "https://github.com/hunter/solution".to_string(),
+                notes: "my entry".to_string(),
+                proof: vec![],
+                content_hash: None,
+            })
+            .instruction()
+            .unwrap();
+
+        ctx.execute_instruction(submit_ix, &[&hunter])
+            .unwrap()
+            .assert_success();
+
+        review_submission(&mut ctx, &maker, bounty, submission);
+
+        let hunter_token_account = ctx
+            .svm
+            .create_associated_token_account(&mint.pubkey(), &hunter)
+            .unwrap();
+
+        winners.push((hunter, submission, hunter_token_account));
+    }
+
+    let accept_ix = |ctx: &AnchorContext, winner: usize| {
+        let (hunter, submission, hunter_token_account) = &winners[winner];
+        // receipts are numbered by prize tier, not by winner
+        let b: Bounty = ctx.get_account(&bounty).unwrap();
+        let tier = b.accepted_submissions.len() as u8;
+        let (receipt, _) = ctx
+            .svm
+            .get_pda_with_bump(&[b"receipt", bounty.as_ref(), &[tier]], &bounty_hunter::ID);
+        let (hunter_profile, _) = ctx.svm.get_pda_with_bump(
+            &[b"hunter_profile", hunter.pubkey().as_ref()],
+            &bounty_hunter::ID,
+        );
+        ctx.program()
+            .accounts(bounty_hunter::accounts::AcceptSolution {
+                maker: maker.pubkey(),
+                bounty,
+                submission: *submission,
+                receipt,
+                maker_profile,
+                hunter_profile,
+                vault: Some(vault),
+                hunter: hunter.pubkey(),
+                mint: Some(mint.pubkey()),
+                hunter_token_account: Some(*hunter_token_account),
+                token_program: Some(spl_token::ID),
+                associated_token_program: Some(spl_associated_token_account_client::program::ID),
+                config,
+                treasury: maker.pubkey(),
+                treasury_token_account: None,
+                system_program: solana_system_interface::program::ID,
+                memo_program: None,
+                event_authority,
+                program: bounty_hunter::ID,
+            })
+            .args(bounty_hunter::instruction::AcceptSolution {})
+            .instruction()
+            .unwrap()
+    };
+
+    // 1st place
+    let ix = accept_ix(&ctx, 0);
+    ctx.execute_instruction(ix, &[&maker])
+        .unwrap()
+        .assert_success();
+
+    ctx.svm.assert_token_balance(&winners[0].2, 70);
+    ctx.svm.assert_token_balance(&vault, 30);
+
+    // still in review until the last tier is paid
+    let b: Bounty = ctx.get_account(&bounty).unwrap();
+    assert_eq!(b.status, BountyStatus::InReview);
+
+    // the same submission can't take a second prize
+    let ix = accept_ix(&ctx, 0);
+    ctx.execute_instruction(ix, &[&maker])
+        .unwrap()
+        .assert_anchor_error("SubmissionNotInReview");
+
+    // 2nd place
+    let ix = accept_ix(&ctx, 1);
+    ctx.execute_instruction(ix, &[&maker])
+        .unwrap()
+        .assert_success();
+
+    // --- Assertions ---
+    ctx.svm.assert_token_balance(&winners[1].2, 30);
+    ctx.svm.assert_account_closed(&vault);
+
+    let b: Bounty = ctx.get_account(&bounty).unwrap();
+    assert_eq!(b.accepted_submissions, vec![winners[0].1, winners[1].1]);
+    assert_eq!(b.status, BountyStatus::Completed);
+}
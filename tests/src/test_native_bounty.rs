@@ -0,0 +1,305 @@
+use anchor_litesvm::{AnchorLiteSVM, Pubkey, Signer};
+use bounty_hunter::state::{Bounty, BountyAccess, BountyStatus, HunterProfile, MakerProfile};
+use litesvm_utils::TestHelpers;
+
+use crate::program_data::set_upgrade_authority;
+use crate::review::review_submission;
+
+#[test]
+fn native_bounty_accept_test() {
+    let mut ctx = AnchorLiteSVM::build_with_program(
+        bounty_hunter::ID,
+        include_bytes!("../../target/deploy/bounty_hunter.so"),
+    );
+    let (event_authority, _) = ctx
+        .svm
+        .get_pda_with_bump(&[b"__event_authority"], &bounty_hunter::ID);
+
+    let maker = ctx.svm.create_funded_account(10_000_000_000).unwrap();
+
+    let seed = 1u64;
+    let reward = 1_000_000_000u64;
+
+    let (bounty, _) = ctx.svm.get_pda_with_bump(
+        &[b"bounty", maker.pubkey().as_array(), &seed.to_le_bytes()],
+        &bounty_hunter::ID,
+    );
+    let (maker_profile, _) = ctx.svm.get_pda_with_bump(
+        &[b"maker_profile", maker.pubkey().as_ref()],
+        &bounty_hunter::ID,
+    );
+
+    // --- Create bounty without a mint ---
+    let (min_reward, _) = ctx.svm.get_pda_with_bump(
+        &[b"min_reward", Pubkey::default().as_ref()],
+        &bounty_hunter::ID,
+    );
+    let create_ix = ctx
+        .program()
+        .accounts(bounty_hunter::accounts::CreateBounty {
+            maker: maker.pubkey(),
+            bounty,
+            maker_profile,
+            mint: None,
+            maker_token_account: None,
+            vault: None,
+            min_reward,
+            system_program: solana_system_interface::program::ID,
+            token_program: None,
+            associated_token_program: None,
+            event_authority,
+            program: bounty_hunter::ID,
+        })
+        .args(bounty_hunter::instruction::CreateBounty {
+            seed,
+            description: "paid in SOL".to_string(),
+            link: "https://issue.link".to_string(),
+            reward,
+            deadline: None,
+            prize_bps: vec![],
+            milestones: vec![],
+            arbiter: None,
+            access: BountyAccess::Public,
+            submission_bond: 0,
+            content_hash: None,
+        })
+        .instruction()
+        .unwrap();
+
+    ctx.execute_instruction(create_ix, &[&maker])
+        .unwrap()
+        .assert_success();
+
+    let (config, _) = ctx.svm.get_pda_with_bump(&[b"config"], &bounty_hunter::ID);
+
+    let b: Bounty = ctx.get_account(&bounty).unwrap();
+    assert!(b.is_native());
+
+    let hunter = ctx.svm.create_funded_account(10_000_000_000).unwrap();
+
+    let (submission, _) = ctx.svm.get_pda_with_bump(
+        &[b"submission", hunter.pubkey().as_ref(), bounty.as_ref()],
+        &bounty_hunter::ID,
+    );
+
+    let submit_ix = ctx
+        .program()
+        .accounts(bounty_hunter::accounts::SubmitSolution {
+            hunter: hunter.pubkey(),
+            bounty,
+            submission,
+            system_program: solana_system_interface::program::ID,
+            event_authority,
+            program: bounty_hunter::ID,
+        })
+        .args(bounty_hunter::instruction::SubmitSolution {
+            link: "https://github.com/hunter/solution".to_string(),
+            notes: "done".to_string(),
+            proof: vec![],
+            content_hash: None,
+        })
+        .instruction()
+        .unwrap();
+
+    ctx.execute_instruction(submit_ix, &[&hunter])
+        .unwrap()
+        .assert_success();
+
+    review_submission(&mut ctx, &maker, bounty, submission);
+
+    let hunter_balance = ctx.svm.get_balance(&hunter.pubkey()).unwrap();
+
+    let (receipt, _) = ctx
+        .svm
+        .get_pda_with_bump(&[b"receipt", bounty.as_ref(), &[0]], &bounty_hunter::ID);
+
+    let (hunter_profile, _) = ctx.svm.get_pda_with_bump(
+        &[b"hunter_profile", hunter.pubkey().as_ref()],
+        &bounty_hunter::ID,
+    );
+    let accept_ix = ctx
+        .program()
+        .accounts(bounty_hunter::accounts::AcceptSolution {
+            maker: maker.pubkey(),
+            bounty,
+            submission,
+            receipt,
+            maker_profile,
+            hunter_profile,
+            vault: None,
+            hunter: hunter.pubkey(),
+            mint: None,
+            hunter_token_account: None,
+            token_program: None,
+            associated_token_program: None,
+            config,
+            treasury: maker.pubkey(),
+            treasury_token_account: None,
+            system_program: solana_system_interface::program::ID,
+            memo_program: None,
+            event_authority,
+            program: bounty_hunter::ID,
+        })
+        .args(bounty_hunter::instruction::AcceptSolution {})
+        .instruction()
+        .unwrap();
+
+    // nothing is paid out until the config has been initialized after deployment
+    ctx.execute_instruction(accept_ix.clone(), &[&maker])
+        .unwrap()
+        .assert_anchor_error("AccountNotInitialized");
+
+    let program_data = set_upgrade_authority(&mut ctx, &maker.pubkey());
+
+    let config_ix = ctx
+        .program()
+        .accounts(bounty_hunter::accounts::InitializeConfig {
+            admin: maker.pubkey(),
+            config,
+            program_data,
+            system_program: solana_system_interface::program::ID,
+            event_authority,
+            program: bounty_hunter::ID,
+        })
+        .args(bounty_hunter::instruction::InitializeConfig {
+            fee_bps: 0,
+            treasury: maker.pubkey(),
+        })
+        .instruction()
+        .unwrap();
+
+    ctx.execute_instruction(config_ix, &[&maker])
+        .unwrap()
+        .assert_success();
+
+    ctx.svm.expire_blockhash();
+
+    ctx.execute_instruction(accept_ix, &[&maker])
+        .unwrap()
+        .assert_success();
+
+    // --- Assertions ---
+    assert_eq!(
+        ctx.svm.get_balance(&hunter.pubkey()).unwrap(),
+        hunter_balance + reward
+    );
+
+    let b: Bounty = ctx.get_account(&bounty).unwrap();
+    assert_eq!(b.accepted_submissions, vec![submission]);
+
+    let profile: HunterProfile = ctx.get_account(&hunter_profile).unwrap();
+    assert_eq!(profile.hunter, hunter.pubkey());
+    assert_eq!(profile.bounties_won, 1);
+    assert_eq!(profile.earnings[0].mint, Pubkey::default());
+    assert_eq!(profile.earnings[0].amount, reward);
+    assert!(profile.last_win_at.is_some());
+
+    let profile: MakerProfile = ctx.get_account(&maker_profile).unwrap();
+    assert_eq!(profile.bounties_created, 1);
+    assert_eq!(profile.payouts, 1);
+}
+
+#[test]
+fn native_bounty_cancel_test() {
+    let mut ctx = AnchorLiteSVM::build_with_program(
+        bounty_hunter::ID,
+        include_bytes!("../../target/deploy/bounty_hunter.so"),
+    );
+    let (event_authority, _) = ctx
+        .svm
+        .get_pda_with_bump(&[b"__event_authority"], &bounty_hunter::ID);
+
+    let maker = ctx.svm.create_funded_account(10_000_000_000).unwrap();
+
+    let seed = 1u64;
+
+    let (bounty, _) = ctx.svm.get_pda_with_bump(
+        &[b"bounty", maker.pubkey().as_array(), &seed.to_le_bytes()],
+        &bounty_hunter::ID,
+    );
+    let (maker_profile, _) = ctx.svm.get_pda_with_bump(
+        &[b"maker_profile", maker.pubkey().as_ref()],
+        &bounty_hunter::ID,
+    );
+
+    let (min_reward, _) = ctx.svm.get_pda_with_bump(
+        &[b"min_reward", Pubkey::default().as_ref()],
+        &bounty_hunter::ID,
+    );
+    let create_ix = ctx
+        .program()
+        .accounts(bounty_hunter::accounts::CreateBounty {
+            maker: maker.pubkey(),
+            bounty,
+            maker_profile,
+            mint: None,
+            maker_token_account: None,
+            vault: None,
+            min_reward,
+            system_program: solana_system_interface::program::ID,
+            token_program: None,
+            associated_token_program: None,
+            event_authority,
+            program: bounty_hunter::ID,
+        })
+        .args(bounty_hunter::instruction::CreateBounty {
+            seed,
+            description: "paid in SOL".to_string(),
+            link: "https://issue.link".to_string(),
+            reward: 1_000_000_000,
+            deadline: None,
+            prize_bps: vec![],
+            milestones: vec![],
+            arbiter: None,
+            access: BountyAccess::Public,
+            submission_bond: 0,
+            content_hash: None,
+        })
+        .instruction()
+        .unwrap();
+
+    ctx.execute_instruction(create_ix, &[&maker])
+        .unwrap()
+        .assert_success();
+
+    let maker_balance = ctx.svm.get_balance(&maker.pubkey()).unwrap();
+    let bounty_balance = ctx.svm.get_balance(&bounty).unwrap();
+
+    let cancel_ix = ctx
+        .program()
+        .accounts(bounty_hunter::accounts::CancelBounty {
+            maker: maker.pubkey(),
+            bounty,
+            maker_profile,
+            vault: None,
+            mint: None,
+            maker_token_account: None,
+            token_program: None,
+            associated_token_program: None,
+            memo_program: None,
+            event_authority,
+            program: bounty_hunter::ID,
+        })
+        .args(bounty_hunter::instruction::CancelBounty {})
+        .instruction()
+        .unwrap();
+
+    ctx.execute_instruction(cancel_ix, &[&maker])
+        .unwrap()
+        .assert_success();
+
+    // --- Assertions ---
+
+    // the reward comes back minus the transaction fee, the bounty keeps only its rent
+    let rent = ctx
+        .svm
+        .minimum_balance_for_rent_exemption(ctx.svm.get_account(&bounty).unwrap().data.len());
+    assert_eq!(ctx.svm.get_balance(&bounty).unwrap(), rent);
+    assert!(
+        ctx.svm.get_balance(&maker.pubkey()).unwrap()
+            > maker_balance + bounty_balance - rent - 10_000
+    );
+
+    let b: Bounty = ctx.get_account(&bounty).unwrap();
+    assert_eq!(b.status, BountyStatus::Cancelled);
+}
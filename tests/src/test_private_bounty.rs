@@ -0,0 +1,168 @@
+use anchor_litesvm::{AnchorContext, AnchorLiteSVM, Keypair, Pubkey, Signer};
+use bounty_hunter::state::{Bounty, BountyAccess};
+use litesvm_utils::TestHelpers;
+
+/// creates a native bounty only `access` lets hunters submit to
+fn create_private_bounty(ctx: &mut AnchorContext, maker: &Keypair, access: BountyAccess) -> Pubkey {
+    let (event_authority, _) = ctx
+        .svm
+        .get_pda_with_bump(&[b"__event_authority"], &bounty_hunter::ID);
+
+    let seed = 1u64;
+    let (bounty, _) = ctx.svm.get_pda_with_bump(
+        &[b"bounty", maker.pubkey().as_array(), &seed.to_le_bytes()],
+        &bounty_hunter::ID,
+    );
+    let (maker_profile, _) = ctx.svm.get_pda_with_bump(
+        &[b"maker_profile", maker.pubkey().as_ref()],
+        &bounty_hunter::ID,
+    );
+
+    let (min_reward, _) = ctx.svm.get_pda_with_bump(
+        &[b"min_reward", Pubkey::default().as_ref()],
+        &bounty_hunter::ID,
+    );
+    let create_ix = ctx
+        .program()
+        .accounts(bounty_hunter::accounts::CreateBounty {
+            maker: maker.pubkey(),
+            bounty,
+            maker_profile,
+            mint: None,
+            maker_token_account: None,
+            vault: None,
+            min_reward,
+            system_program: solana_system_interface::program::ID,
+            token_program: None,
+            associated_token_program: None,
+            event_authority,
+            program: bounty_hunter::ID,
+        })
+        .args(bounty_hunter::instruction::CreateBounty {
+            seed,
+            description: "vetted contractors only".to_string(),
+            link: "https://issue.link".to_string(),
+            reward: 1_000_000_000,
+            deadline: None,
+            prize_bps: vec![],
+            milestones: vec![],
+            arbiter: None,
+            access,
+            submission_bond: 0,
+            content_hash: None,
+        })
+        .instruction()
+        .unwrap();
+
+    ctx.execute_instruction(create_ix, &[maker])
+        .unwrap()
+        .assert_success();
+    bounty
+}
+
+fn submit_ix(
+    ctx: &AnchorContext,
+    bounty: Pubkey,
+    hunter: &Keypair,
+    proof: Vec<[u8; 32]>,
+) -> anchor_litesvm::Instruction {
+    let (event_authority, _) = ctx
+        .svm
+        .get_pda_with_bump(&[b"__event_authority"], &bounty_hunter::ID);
+    let (submission, _) = ctx.svm.get_pda_with_bump(
+        &[b"submission", hunter.pubkey().as_ref(), bounty.as_ref()],
+        &bounty_hunter::ID,
+    );
+
+    ctx.program()
+        .accounts(bounty_hunter::accounts::SubmitSolution {
+            hunter: hunter.pubkey(),
+            bounty,
+            submission,
+            system_program: solana_system_interface::program::ID,
+            event_authority,
+            program: bounty_hunter::ID,
+        })
+        .args(bounty_hunter::instruction::SubmitSolution {
+            link: "https://github.com/hunter/solution".to_string(),
+            notes: "done".to_string(),
+            proof,
+            content_hash: None,
+        })
+        .instruction()
+        .unwrap()
+}
+
+#[test]
+fn allow_list_bounty_test() {
+    let mut ctx = AnchorLiteSVM::build_with_program(
+        bounty_hunter::ID,
+        include_bytes!("../../target/deploy/bounty_hunter.so"),
+    );
+
+    let maker = ctx.svm.create_funded_account(10_000_000_000).unwrap();
+    let contractor = ctx.svm.create_funded_account(10_000_000_000).unwrap();
+    let stranger = ctx.svm.create_funded_account(10_000_000_000).unwrap();
+
+    let bounty = create_private_bounty(
+        &mut ctx,
+        &maker,
+        BountyAccess::AllowList {
+            hunters: vec![contractor.pubkey()],
+        },
+    );
+
+    let ix = submit_ix(&ctx, bounty, &stranger, vec![]);
+    ctx.execute_instruction(ix, &[&stranger])
+        .unwrap()
+        .assert_anchor_error("HunterNotAllowed");
+
+    let ix = submit_ix(&ctx, bounty, &contractor, vec![]);
+    ctx.execute_instruction(ix, &[&contractor])
+        .unwrap()
+        .assert_success();
+
+    // --- Assertions ---
+    let b: Bounty = ctx.get_account(&bounty).unwrap();
+    assert_eq!(b.submissions, 1);
+}
+
+#[test]
+fn merkle_root_bounty_test() {
+    let mut ctx = AnchorLiteSVM::build_with_program(
+        bounty_hunter::ID,
+        include_bytes!("../../target/deploy/bounty_hunter.so"),
+    );
+
+    let maker = ctx.svm.create_funded_account(10_000_000_000).unwrap();
+    let first = ctx.svm.create_funded_account(10_000_000_000).unwrap();
+    let second = ctx.svm.create_funded_account(10_000_000_000).unwrap();
+    let stranger = ctx.svm.create_funded_account(10_000_000_000).unwrap();
+
+    // a two leaf tree, each hunter's proof is the other hunter's leaf
+    let first_leaf = BountyAccess::leaf(&first.pubkey());
+    let second_leaf = BountyAccess::leaf(&second.pubkey());
+    let root = BountyAccess::node(&first_leaf, &second_leaf);
+
+    let bounty = create_private_bounty(&mut ctx, &maker, BountyAccess::MerkleRoot { root });
+
+    // a proof for someone else's leaf doesn't help
+    let ix = submit_ix(&ctx, bounty, &stranger, vec![second_leaf]);
+    ctx.execute_instruction(ix, &[&stranger])
+        .unwrap()
+        .assert_anchor_error("HunterNotAllowed");
+
+    let ix = submit_ix(&ctx, bounty, &first, vec![second_leaf]);
+    ctx.execute_instruction(ix, &[&first])
+        .unwrap()
+        .assert_success();
+
+    let ix = submit_ix(&ctx, bounty, &second, vec![first_leaf]);
+    ctx.execute_instruction(ix, &[&second])
+        .unwrap()
+        .assert_success();
+
+    // --- Assertions ---
+    let b: Bounty = ctx.get_account(&bounty).unwrap();
+    assert_eq!(b.submissions, 2);
+}
@@ -0,0 +1,183 @@
+use anchor_litesvm::{AnchorContext, AnchorLiteSVM, Pubkey, Signer};
+use bounty_hunter::state::{Bounty, BountyAccess};
+use litesvm_utils::TestHelpers;
+
+#[test]
+fn increase_and_decrease_reward_test() {
+    let mut ctx = AnchorLiteSVM::build_with_program(
+        bounty_hunter::ID,
+        include_bytes!("../../target/deploy/bounty_hunter.so"),
+    );
+    let (event_authority, _) = ctx
+        .svm
+        .get_pda_with_bump(&[b"__event_authority"], &bounty_hunter::ID);
+
+    let maker = ctx.svm.create_funded_account(10_000_000_000).unwrap();
+    let hunter = ctx.svm.create_funded_account(10_000_000_000).unwrap();
+
+    let seed = 1u64;
+
+    let (bounty, _) = ctx.svm.get_pda_with_bump(
+        &[b"bounty", maker.pubkey().as_array(), &seed.to_le_bytes()],
+        &bounty_hunter::ID,
+    );
+    let (maker_profile, _) = ctx.svm.get_pda_with_bump(
+        &[b"maker_profile", maker.pubkey().as_ref()],
+        &bounty_hunter::ID,
+    );
+
+    let (min_reward, _) = ctx.svm.get_pda_with_bump(
+        &[b"min_reward", Pubkey::default().as_ref()],
+        &bounty_hunter::ID,
+    );
+    let create_ix = ctx
+        .program()
+        .accounts(bounty_hunter::accounts::CreateBounty {
+            maker: maker.pubkey(),
+            bounty,
+            maker_profile,
+            mint: None,
+            maker_token_account: None,
+            vault: None,
+            min_reward,
+            system_program: solana_system_interface::program::ID,
+            token_program: None,
+            associated_token_program: None,
+            event_authority,
+            program: bounty_hunter::ID,
+        })
+        .args(bounty_hunter::instruction::CreateBounty {
+            seed,
+            description: "nobody wants this one".to_string(),
+            link: "https://issue.link".to_string(),
+            reward: 1_000_000_000,
+            deadline: None,
+            prize_bps: vec![],
+            milestones: vec![],
+            arbiter: None,
+            access: BountyAccess::Public,
+            submission_bond: 0,
+            content_hash: None,
+        })
+        .instruction()
+        .unwrap();
+
+    ctx.execute_instruction(create_ix, &[&maker])
+        .unwrap()
+        .assert_success();
+
+    let increase_ix = |ctx: &AnchorContext, amount: u64| {
+        ctx.program()
+            .accounts(bounty_hunter::accounts::IncreaseReward {
+                maker: maker.pubkey(),
+                bounty,
+                mint: None,
+                maker_token_account: None,
+                vault: None,
+                system_program: solana_system_interface::program::ID,
+                token_program: None,
+                associated_token_program: None,
+                event_authority,
+                program: bounty_hunter::ID,
+            })
+            .args(bounty_hunter::instruction::IncreaseReward { amount })
+            .instruction()
+            .unwrap()
+    };
+
+    let ix = increase_ix(&ctx, 0);
+    ctx.execute_instruction(ix, &[&maker])
+        .unwrap()
+        .assert_anchor_error("ZeroReward");
+
+    let bounty_balance = ctx.svm.get_balance(&bounty).unwrap();
+    let ix = increase_ix(&ctx, 500_000_000);
+    ctx.execute_instruction(ix, &[&maker])
+        .unwrap()
+        .assert_success();
+
+    let b: Bounty = ctx.get_account(&bounty).unwrap();
+    assert_eq!(b.reward, 1_500_000_000);
+    assert_eq!(b.escrowed, 1_500_000_000);
+    assert_eq!(
+        ctx.svm.get_balance(&bounty).unwrap(),
+        bounty_balance + 500_000_000
+    );
+
+    let decrease_ix = |ctx: &AnchorContext, amount: u64| {
+        ctx.program()
+            .accounts(bounty_hunter::accounts::DecreaseReward {
+                maker: maker.pubkey(),
+                bounty,
+                mint: None,
+                vault: None,
+                maker_token_account: None,
+                min_reward,
+                token_program: None,
+                associated_token_program: None,
+                memo_program: None,
+                event_authority,
+                program: bounty_hunter::ID,
+            })
+            .args(bounty_hunter::instruction::DecreaseReward { amount })
+            .instruction()
+            .unwrap()
+    };
+
+    // some reward has to be left
+    let ix = decrease_ix(&ctx, 1_500_000_000);
+    ctx.execute_instruction(ix, &[&maker])
+        .unwrap()
+        .assert_anchor_error("InvalidRewardDecrease");
+
+    let maker_balance = ctx.svm.get_balance(&maker.pubkey()).unwrap();
+    let ix = decrease_ix(&ctx, 1_000_000_000);
+    ctx.execute_instruction(ix, &[&maker])
+        .unwrap()
+        .assert_success();
+
+    let b: Bounty = ctx.get_account(&bounty).unwrap();
+    assert_eq!(b.reward, 500_000_000);
+    assert_eq!(b.escrowed, 500_000_000);
+    // the maker paid the transaction fee out of the refund
+    assert!(ctx.svm.get_balance(&maker.pubkey()).unwrap() > maker_balance + 990_000_000);
+
+    let (submission, _) = ctx.svm.get_pda_with_bump(
+        &[b"submission", hunter.pubkey().as_ref(), bounty.as_ref()],
+        &bounty_hunter::ID,
+    );
+
+    let submit_ix = ctx
+        .program()
+        .accounts(bounty_hunter::accounts::SubmitSolution {
+            hunter: hunter.pubkey(),
+            bounty,
+            submission,
+            system_program: solana_system_interface::program::ID,
+            event_authority,
+            program: bounty_hunter::ID,
+        })
+        .args(bounty_hunter::instruction::SubmitSolution {
+            link: "https://github.com/hunter/solution".to_string(),
+            notes: "done".to_string(),
+            proof: vec![],
+            content_hash: None,
+        })
+        .instruction()
+        .unwrap();
+
+    ctx.execute_instruction(submit_ix, &[&hunter])
+        .unwrap()
+        .assert_success();
+
+    // --- Assertions ---
+
+    // hunters that already submitted keep what they were promised
+    let ix = decrease_ix(&ctx, 100_000_000);
+    ctx.execute_instruction(ix, &[&maker])
+        .unwrap()
+        .assert_anchor_error("BountyHasSubmissions");
+
+    let b: Bounty = ctx.get_account(&bounty).unwrap();
+    assert_eq!(b.reward, 500_000_000);
+}
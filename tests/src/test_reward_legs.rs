@@ -0,0 +1,504 @@
+use anchor_litesvm::{AccountMeta, AnchorContext, AnchorLiteSVM, Keypair, Pubkey, Signer};
+use bounty_hunter::state::{Bounty, BountyAccess, BountyStatus, HunterProfile};
+use litesvm_utils::{AssertionHelpers, TestHelpers};
+
+use crate::program_data::set_upgrade_authority;
+use crate::review::review_submission;
+
+use spl_associated_token_account_client::address::get_associated_token_address;
+
+struct LegBounty {
+    maker: Keypair,
+    bounty: Pubkey,
+    mint: Pubkey,
+    vault: Pubkey,
+    maker_token_account: Pubkey,
+    leg_mint: Pubkey,
+    leg_vault: Pubkey,
+    maker_leg_token_account: Pubkey,
+}
+
+/// creates a token bounty of 100 and adds a 50 leg in a second mint
+fn create_leg_bounty(ctx: &mut AnchorContext) -> LegBounty {
+    let (event_authority, _) = ctx
+        .svm
+        .get_pda_with_bump(&[b"__event_authority"], &bounty_hunter::ID);
+
+    let maker = ctx.svm.create_funded_account(10_000_000_000).unwrap();
+    let mint = ctx.svm.create_token_mint(&maker, 3).unwrap();
+    let leg_mint = ctx.svm.create_token_mint(&maker, 6).unwrap();
+
+    let maker_token_account = ctx
+        .svm
+        .create_associated_token_account(&mint.pubkey(), &maker)
+        .unwrap();
+    let maker_leg_token_account = ctx
+        .svm
+        .create_associated_token_account(&leg_mint.pubkey(), &maker)
+        .unwrap();
+
+    ctx.svm
+        .mint_to(&mint.pubkey(), &maker_token_account, &maker, 100)
+        .unwrap();
+    ctx.svm
+        .mint_to(&leg_mint.pubkey(), &maker_leg_token_account, &maker, 50)
+        .unwrap();
+
+    let seed = 1u64;
+
+    let (bounty, _) = ctx.svm.get_pda_with_bump(
+        &[b"bounty", maker.pubkey().as_array(), &seed.to_le_bytes()],
+        &bounty_hunter::ID,
+    );
+    let (maker_profile, _) = ctx.svm.get_pda_with_bump(
+        &[b"maker_profile", maker.pubkey().as_ref()],
+        &bounty_hunter::ID,
+    );
+    let vault = get_associated_token_address(&bounty, &mint.pubkey());
+    let leg_vault = get_associated_token_address(&bounty, &leg_mint.pubkey());
+
+    let (min_reward, _) = ctx
+        .svm
+        .get_pda_with_bump(&[b"min_reward", mint.pubkey().as_ref()], &bounty_hunter::ID);
+    let create_ix = ctx
+        .program()
+        .accounts(bounty_hunter::accounts::CreateBounty {
+            maker: maker.pubkey(),
+            bounty,
+            maker_profile,
+            mint: Some(mint.pubkey()),
+            maker_token_account: Some(maker_token_account),
+            vault: Some(vault),
+            min_reward,
+            system_program: solana_system_interface::program::ID,
+            token_program: Some(spl_token::ID),
+            associated_token_program: Some(spl_associated_token_account_client::program::ID),
+            event_authority,
+            program: bounty_hunter::ID,
+        })
+        .args(bounty_hunter::instruction::CreateBounty {
+            seed,
+            description: "port the indexer".to_string(),
+            link: "https://issue.link".to_string(),
+            reward: 100,
+            deadline: None,
+            prize_bps: vec![],
+            milestones: vec![],
+            arbiter: None,
+            access: BountyAccess::Public,
+            submission_bond: 0,
+            content_hash: None,
+        })
+        .instruction()
+        .unwrap();
+
+    let add_leg_ix = ctx
+        .program()
+        .accounts(bounty_hunter::accounts::AddRewardLeg {
+            maker: maker.pubkey(),
+            bounty,
+            mint: leg_mint.pubkey(),
+            maker_token_account: maker_leg_token_account,
+            vault: leg_vault,
+            system_program: solana_system_interface::program::ID,
+            token_program: spl_token::ID,
+            associated_token_program: spl_associated_token_account_client::program::ID,
+            event_authority,
+            program: bounty_hunter::ID,
+        })
+        .args(bounty_hunter::instruction::AddRewardLeg { amount: 50 })
+        .instruction()
+        .unwrap();
+
+    // the leg is funded in the same transaction as the bounty
+    ctx.execute_instructions(vec![create_ix, add_leg_ix], &[&maker])
+        .unwrap()
+        .assert_success();
+
+    ctx.svm.assert_token_balance(&leg_vault, 50);
+
+    LegBounty {
+        maker,
+        bounty,
+        mint: mint.pubkey(),
+        vault,
+        maker_token_account,
+        leg_mint: leg_mint.pubkey(),
+        leg_vault,
+        maker_leg_token_account,
+    }
+}
+
+#[test]
+fn accept_pays_reward_legs_test() {
+    let mut ctx = AnchorLiteSVM::build_with_program(
+        bounty_hunter::ID,
+        include_bytes!("../../target/deploy/bounty_hunter.so"),
+    );
+    let (event_authority, _) = ctx
+        .svm
+        .get_pda_with_bump(&[b"__event_authority"], &bounty_hunter::ID);
+
+    let LegBounty {
+        maker,
+        bounty,
+        mint,
+        vault,
+        maker_leg_token_account,
+        leg_mint,
+        leg_vault,
+        ..
+    } = create_leg_bounty(&mut ctx);
+
+    let (config, _) = ctx.svm.get_pda_with_bump(&[b"config"], &bounty_hunter::ID);
+
+    let program_data = set_upgrade_authority(&mut ctx, &maker.pubkey());
+
+    let config_ix = ctx
+        .program()
+        .accounts(bounty_hunter::accounts::InitializeConfig {
+            admin: maker.pubkey(),
+            config,
+            program_data,
+            system_program: solana_system_interface::program::ID,
+            event_authority,
+            program: bounty_hunter::ID,
+        })
+        .args(bounty_hunter::instruction::InitializeConfig {
+            fee_bps: 0,
+            treasury: maker.pubkey(),
+        })
+        .instruction()
+        .unwrap();
+
+    ctx.execute_instruction(config_ix, &[&maker])
+        .unwrap()
+        .assert_success();
+
+    let hunter = ctx.svm.create_funded_account(10_000_000_000).unwrap();
+    let (submission, _) = ctx.svm.get_pda_with_bump(
+        &[b"submission", hunter.pubkey().as_ref(), bounty.as_ref()],
+        &bounty_hunter::ID,
+    );
+
+    let submit_ix = ctx
+        .program()
+        .accounts(bounty_hunter::accounts::SubmitSolution {
+            hunter: hunter.pubkey(),
+            bounty,
+            submission,
+            system_program: solana_system_interface::program::ID,
+            event_authority,
+            program: bounty_hunter::ID,
+        })
+        .args(bounty_hunter::instruction::SubmitSolution {
+            link: "https://github.com/hunter/solution".to_string(),
+            notes: "done".to_string(),
+            proof: vec![],
+            content_hash: None,
+        })
+        .instruction()
+        .unwrap();
+
+    ctx.execute_instruction(submit_ix, &[&hunter])
+        .unwrap()
+        .assert_success();
+
+    review_submission(&mut ctx, &maker, bounty, submission);
+
+    let hunter_token_account = ctx
+        .svm
+        .create_associated_token_account(&mint, &hunter)
+        .unwrap();
+    let hunter_leg_token_account = ctx
+        .svm
+        .create_associated_token_account(&leg_mint, &hunter)
+        .unwrap();
+
+    let (receipt, _) = ctx
+        .svm
+        .get_pda_with_bump(&[b"receipt", bounty.as_ref(), &[0]], &bounty_hunter::ID);
+    let (maker_profile, _) = ctx.svm.get_pda_with_bump(
+        &[b"maker_profile", maker.pubkey().as_ref()],
+        &bounty_hunter::ID,
+    );
+    let (hunter_profile, _) = ctx.svm.get_pda_with_bump(
+        &[b"hunter_profile", hunter.pubkey().as_ref()],
+        &bounty_hunter::ID,
+    );
+
+    let accept_ix = |ctx: &AnchorContext, leg_accounts: Vec<AccountMeta>| {
+        let mut ix = ctx
+            .program()
+            .accounts(bounty_hunter::accounts::AcceptSolution {
+                maker: maker.pubkey(),
+                bounty,
+                submission,
+                receipt,
+                maker_profile,
+                hunter_profile,
+                vault: Some(vault),
+                hunter: hunter.pubkey(),
+                mint: Some(mint),
+                hunter_token_account: Some(hunter_token_account),
+                token_program: Some(spl_token::ID),
+                associated_token_program: Some(spl_associated_token_account_client::program::ID),
+                config,
+                treasury: maker.pubkey(),
+                treasury_token_account: None,
+                system_program: solana_system_interface::program::ID,
+                memo_program: None,
+                event_authority,
+                program: bounty_hunter::ID,
+            })
+            .args(bounty_hunter::instruction::AcceptSolution {})
+            .instruction()
+            .unwrap();
+        ix.accounts.extend(leg_accounts);
+        ix
+    };
+
+    // sent to the leg vault on top of the leg, it keeps the vault open until the maker sweeps it
+    ctx.svm.mint_to(&leg_mint, &leg_vault, &maker, 5).unwrap();
+
+    // the legs can't be left out
+    let ix = accept_ix(&ctx, vec![]);
+    ctx.execute_instruction(ix, &[&maker])
+        .unwrap()
+        .assert_anchor_error("MissingRewardLegAccounts");
+
+    let ix = accept_ix(
+        &ctx,
+        vec![
+            AccountMeta::new(leg_mint, false),
+            AccountMeta::new(leg_vault, false),
+            AccountMeta::new(hunter_leg_token_account, false),
+            AccountMeta::new(maker_leg_token_account, false),
+            AccountMeta::new_readonly(spl_token::ID, false),
+        ],
+    );
+    ctx.execute_instruction(ix, &[&maker])
+        .unwrap()
+        .assert_success();
+
+    ctx.svm.assert_token_balance(&hunter_token_account, 100);
+    ctx.svm.assert_token_balance(&hunter_leg_token_account, 50);
+    ctx.svm.assert_account_closed(&vault);
+    ctx.svm.assert_token_balance(&leg_vault, 5);
+
+    let close_ix = |ctx: &AnchorContext, vault: Option<Pubkey>| {
+        let mut ix = ctx
+            .program()
+            .accounts(bounty_hunter::accounts::CloseBounty {
+                maker: maker.pubkey(),
+                bounty,
+                vault,
+                mint: Some(mint),
+                maker_token_account: None,
+                token_program: Some(spl_token::ID),
+                associated_token_program: None,
+                memo_program: None,
+                event_authority,
+                program: bounty_hunter::ID,
+            })
+            .args(bounty_hunter::instruction::CloseBounty {})
+            .instruction()
+            .unwrap();
+        ix.accounts.extend([
+            AccountMeta::new(leg_mint, false),
+            AccountMeta::new(leg_vault, false),
+            AccountMeta::new(maker_leg_token_account, false),
+            AccountMeta::new_readonly(spl_token::ID, false),
+        ]);
+        ix
+    };
+
+    // token bounties always pass their vault, even once the last payout closed it
+    let ix = close_ix(&ctx, None);
+    ctx.execute_instruction(ix, &[&maker])
+        .unwrap()
+        .assert_anchor_error("MissingTokenAccounts");
+
+    let ix = close_ix(&ctx, Some(vault));
+    ctx.execute_instruction(ix, &[&maker])
+        .unwrap()
+        .assert_success();
+
+    // --- Assertions ---
+    ctx.svm.assert_token_balance(&maker_leg_token_account, 5);
+    ctx.svm.assert_account_closed(&leg_vault);
+    ctx.svm.assert_account_closed(&bounty);
+
+    // the leg counts towards the hunter's earnings but not as another win
+    let profile: HunterProfile = ctx.get_account(&hunter_profile).unwrap();
+    assert_eq!(profile.bounties_won, 1);
+    assert_eq!(profile.earnings.len(), 2);
+    assert_eq!(profile.earnings[0].mint, mint);
+    assert_eq!(profile.earnings[0].amount, 100);
+    assert_eq!(profile.earnings[1].mint, leg_mint);
+    assert_eq!(profile.earnings[1].amount, 50);
+}
+
+#[test]
+fn cancel_refunds_reward_legs_test() {
+    let mut ctx = AnchorLiteSVM::build_with_program(
+        bounty_hunter::ID,
+        include_bytes!("../../target/deploy/bounty_hunter.so"),
+    );
+    let (event_authority, _) = ctx
+        .svm
+        .get_pda_with_bump(&[b"__event_authority"], &bounty_hunter::ID);
+
+    let LegBounty {
+        maker,
+        bounty,
+        mint,
+        vault,
+        maker_token_account,
+        leg_mint,
+        leg_vault,
+        maker_leg_token_account,
+    } = create_leg_bounty(&mut ctx);
+
+    let (maker_profile, _) = ctx.svm.get_pda_with_bump(
+        &[b"maker_profile", maker.pubkey().as_ref()],
+        &bounty_hunter::ID,
+    );
+
+    let mut cancel_ix = ctx
+        .program()
+        .accounts(bounty_hunter::accounts::CancelBounty {
+            maker: maker.pubkey(),
+            bounty,
+            maker_profile,
+            vault: Some(vault),
+            mint: Some(mint),
+            maker_token_account: Some(maker_token_account),
+            token_program: Some(spl_token::ID),
+            associated_token_program: Some(spl_associated_token_account_client::program::ID),
+            memo_program: None,
+            event_authority,
+            program: bounty_hunter::ID,
+        })
+        .args(bounty_hunter::instruction::CancelBounty {})
+        .instruction()
+        .unwrap();
+    cancel_ix.accounts.extend([
+        AccountMeta::new(leg_mint, false),
+        AccountMeta::new(leg_vault, false),
+        AccountMeta::new(maker_leg_token_account, false),
+        AccountMeta::new_readonly(spl_token::ID, false),
+    ]);
+
+    ctx.execute_instruction(cancel_ix, &[&maker])
+        .unwrap()
+        .assert_success();
+
+    // --- Assertions ---
+    ctx.svm.assert_token_balance(&maker_token_account, 100);
+    ctx.svm.assert_token_balance(&maker_leg_token_account, 50);
+    ctx.svm.assert_account_closed(&vault);
+    ctx.svm.assert_account_closed(&leg_vault);
+
+    let b: Bounty = ctx.get_account(&bounty).unwrap();
+    assert_eq!(b.status, BountyStatus::Cancelled);
+    assert_eq!(b.reward_legs[0].escrowed, 0);
+}
+
+#[test]
+fn zero_reward_leg_test() {
+    let mut ctx = AnchorLiteSVM::build_with_program(
+        bounty_hunter::ID,
+        include_bytes!("../../target/deploy/bounty_hunter.so"),
+    );
+    let (event_authority, _) = ctx
+        .svm
+        .get_pda_with_bump(&[b"__event_authority"], &bounty_hunter::ID);
+
+    let maker = ctx.svm.create_funded_account(10_000_000_000).unwrap();
+    let leg_mint = ctx.svm.create_token_mint(&maker, 6).unwrap();
+    let maker_leg_token_account = ctx
+        .svm
+        .create_associated_token_account(&leg_mint.pubkey(), &maker)
+        .unwrap();
+
+    let seed = 1u64;
+
+    let (bounty, _) = ctx.svm.get_pda_with_bump(
+        &[b"bounty", maker.pubkey().as_array(), &seed.to_le_bytes()],
+        &bounty_hunter::ID,
+    );
+    let (maker_profile, _) = ctx.svm.get_pda_with_bump(
+        &[b"maker_profile", maker.pubkey().as_ref()],
+        &bounty_hunter::ID,
+    );
+    let leg_vault = get_associated_token_address(&bounty, &leg_mint.pubkey());
+
+    let (min_reward, _) = ctx.svm.get_pda_with_bump(
+        &[b"min_reward", Pubkey::default().as_ref()],
+        &bounty_hunter::ID,
+    );
+    let create_ix = ctx
+        .program()
+        .accounts(bounty_hunter::accounts::CreateBounty {
+            maker: maker.pubkey(),
+            bounty,
+            maker_profile,
+            mint: None,
+            maker_token_account: None,
+            vault: None,
+            min_reward,
+            system_program: solana_system_interface::program::ID,
+            token_program: None,
+            associated_token_program: None,
+            event_authority,
+            program: bounty_hunter::ID,
+        })
+        .args(bounty_hunter::instruction::CreateBounty {
+            seed,
+            description: "hackathon".to_string(),
+            link: "https://hackathon.link".to_string(),
+            reward: 1_000_000_000,
+            deadline: None,
+            prize_bps: vec![5_000, 5_000],
+            milestones: vec![],
+            arbiter: None,
+            access: BountyAccess::Public,
+            submission_bond: 0,
+            content_hash: None,
+        })
+        .instruction()
+        .unwrap();
+
+    ctx.execute_instruction(create_ix, &[&maker])
+        .unwrap()
+        .assert_success();
+
+    let add_leg_ix = ctx
+        .program()
+        .accounts(bounty_hunter::accounts::AddRewardLeg {
+            maker: maker.pubkey(),
+            bounty,
+            mint: leg_mint.pubkey(),
+            maker_token_account: maker_leg_token_account,
+            vault: leg_vault,
+            system_program: solana_system_interface::program::ID,
+            token_program: spl_token::ID,
+            associated_token_program: spl_associated_token_account_client::program::ID,
+            event_authority,
+            program: bounty_hunter::ID,
+        })
+        .args(bounty_hunter::instruction::AddRewardLeg { amount: 0 })
+        .instruction()
+        .unwrap();
+
+    // an empty leg would have its vault closed by the first tier and brick the second
+    ctx.execute_instruction(add_leg_ix, &[&maker])
+        .unwrap()
+        .assert_anchor_error("ZeroReward");
+
+    // --- Assertions ---
+    ctx.svm.assert_account_closed(&leg_vault);
+    let b: Bounty = ctx.get_account(&bounty).unwrap();
+    assert!(b.reward_legs.is_empty());
+}
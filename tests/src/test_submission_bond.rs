@@ -0,0 +1,203 @@
+use anchor_litesvm::{AnchorLiteSVM, Pubkey, Signer};
+use bounty_hunter::state::{Bounty, BountyAccess, BountyStatus, Submission};
+use litesvm_utils::{AssertionHelpers, TestHelpers};
+
+use crate::program_data::set_upgrade_authority;
+
+#[test]
+fn slash_submission_bond_test() {
+    let mut ctx = AnchorLiteSVM::build_with_program(
+        bounty_hunter::ID,
+        include_bytes!("../../target/deploy/bounty_hunter.so"),
+    );
+    let (event_authority, _) = ctx
+        .svm
+        .get_pda_with_bump(&[b"__event_authority"], &bounty_hunter::ID);
+
+    let maker = ctx.svm.create_funded_account(10_000_000_000).unwrap();
+    let spammer = ctx.svm.create_funded_account(10_000_000_000).unwrap();
+    let treasury = ctx.svm.create_funded_account(1_000_000_000).unwrap();
+
+    let (config, _) = ctx.svm.get_pda_with_bump(&[b"config"], &bounty_hunter::ID);
+
+    let program_data = set_upgrade_authority(&mut ctx, &maker.pubkey());
+
+    let config_ix = ctx
+        .program()
+        .accounts(bounty_hunter::accounts::InitializeConfig {
+            admin: maker.pubkey(),
+            config,
+            program_data,
+            system_program: solana_system_interface::program::ID,
+            event_authority,
+            program: bounty_hunter::ID,
+        })
+        .args(bounty_hunter::instruction::InitializeConfig {
+            fee_bps: 0,
+            treasury: treasury.pubkey(),
+        })
+        .instruction()
+        .unwrap();
+
+    ctx.execute_instruction(config_ix, &[&maker])
+        .unwrap()
+        .assert_success();
+
+    let seed = 1u64;
+    let bond = 100_000_000u64;
+
+    let (bounty, _) = ctx.svm.get_pda_with_bump(
+        &[b"bounty", maker.pubkey().as_array(), &seed.to_le_bytes()],
+        &bounty_hunter::ID,
+    );
+    let (maker_profile, _) = ctx.svm.get_pda_with_bump(
+        &[b"maker_profile", maker.pubkey().as_ref()],
+        &bounty_hunter::ID,
+    );
+
+    let (min_reward, _) = ctx.svm.get_pda_with_bump(
+        &[b"min_reward", Pubkey::default().as_ref()],
+        &bounty_hunter::ID,
+    );
+    let create_ix = ctx
+        .program()
+        .accounts(bounty_hunter::accounts::CreateBounty {
+            maker: maker.pubkey(),
+            bounty,
+            maker_profile,
+            mint: None,
+            maker_token_account: None,
+            vault: None,
+            min_reward,
+            system_program: solana_system_interface::program::ID,
+            token_program: None,
+            associated_token_program: None,
+            event_authority,
+            program: bounty_hunter::ID,
+        })
+        .args(bounty_hunter::instruction::CreateBounty {
+            seed,
+            description: "popular bounty".to_string(),
+            link: "https://issue.link".to_string(),
+            reward: 1_000_000_000,
+            deadline: None,
+            prize_bps: vec![],
+            milestones: vec![],
+            arbiter: None,
+            access: BountyAccess::Public,
+            submission_bond: bond,
+            content_hash: None,
+        })
+        .instruction()
+        .unwrap();
+
+    ctx.execute_instruction(create_ix, &[&maker])
+        .unwrap()
+        .assert_success();
+
+    let (submission, _) = ctx.svm.get_pda_with_bump(
+        &[b"submission", spammer.pubkey().as_ref(), bounty.as_ref()],
+        &bounty_hunter::ID,
+    );
+
+    let submit_ix = ctx
+        .program()
+        .accounts(bounty_hunter::accounts::SubmitSolution {
+            hunter: spammer.pubkey(),
+            bounty,
+            submission,
+            system_program: solana_system_interface::program::ID,
+            event_authority,
+            program: bounty_hunter::ID,
+        })
+        .args(bounty_hunter::instruction::SubmitSolution {
+            link: "https://spam.link".to_string(),
+            notes: "buy now".to_string(),
+            proof: vec![],
+            content_hash: None,
+        })
+        .instruction()
+        .unwrap();
+
+    ctx.execute_instruction(submit_ix, &[&spammer])
+        .unwrap()
+        .assert_success();
+
+    // the bond sits in the submission on top of its rent
+    let s: Submission = ctx.get_account(&submission).unwrap();
+    assert_eq!(s.bond, bond);
+    let submission_lamports = ctx.svm.get_balance(&submission).unwrap();
+    let rent = submission_lamports - bond;
+    assert_eq!(
+        rent,
+        ctx.svm.minimum_balance_for_rent_exemption(
+            ctx.svm.get_account(&submission).unwrap().data.len()
+        )
+    );
+
+    let spammer_balance = ctx.svm.get_balance(&spammer.pubkey()).unwrap();
+    let treasury_balance = ctx.svm.get_balance(&treasury.pubkey()).unwrap();
+
+    let slash_ix = ctx
+        .program()
+        .accounts(bounty_hunter::accounts::SlashSubmission {
+            maker: maker.pubkey(),
+            bounty,
+            submission,
+            hunter: spammer.pubkey(),
+            config,
+            treasury: treasury.pubkey(),
+            event_authority,
+            program: bounty_hunter::ID,
+        })
+        .args(bounty_hunter::instruction::SlashSubmission {})
+        .instruction()
+        .unwrap();
+
+    ctx.execute_instruction(slash_ix, &[&maker])
+        .unwrap()
+        .assert_success();
+
+    // --- Assertions ---
+    ctx.svm.assert_account_closed(&submission);
+    assert_eq!(
+        ctx.svm.get_balance(&treasury.pubkey()).unwrap(),
+        treasury_balance + bond
+    );
+    assert_eq!(
+        ctx.svm.get_balance(&spammer.pubkey()).unwrap(),
+        spammer_balance + rent
+    );
+
+    // slashed entries still count, slashing can't clear the way to cancelling
+    let b: Bounty = ctx.get_account(&bounty).unwrap();
+    assert_eq!(b.submissions, 1);
+    assert_eq!(b.status, BountyStatus::InReview);
+
+    let cancel_ix = ctx
+        .program()
+        .accounts(bounty_hunter::accounts::CancelBounty {
+            maker: maker.pubkey(),
+            bounty,
+            maker_profile,
+            vault: None,
+            mint: None,
+            maker_token_account: None,
+            token_program: None,
+            associated_token_program: None,
+            memo_program: None,
+            event_authority,
+            program: bounty_hunter::ID,
+        })
+        .args(bounty_hunter::instruction::CancelBounty {})
+        .instruction()
+        .unwrap();
+
+    ctx.execute_instruction(cancel_ix, &[&maker])
+        .unwrap()
+        .assert_anchor_error("BountyHasSubmissions");
+
+    let b: Bounty = ctx.get_account(&bounty).unwrap();
+    assert_eq!(b.status, BountyStatus::InReview);
+    assert_eq!(b.escrowed, 1_000_000_000);
+}
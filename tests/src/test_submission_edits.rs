@@ -0,0 +1,177 @@
+use anchor_litesvm::{AnchorContext, AnchorLiteSVM, Pubkey, Signer};
+use bounty_hunter::state::{Bounty, BountyAccess, BountyStatus, Submission, SubmissionStatus};
+use litesvm_utils::{AssertionHelpers, TestHelpers};
+
+#[test]
+fn edit_review_and_withdraw_test() {
+    let mut ctx = AnchorLiteSVM::build_with_program(
+        bounty_hunter::ID,
+        include_bytes!("../../target/deploy/bounty_hunter.so"),
+    );
+    let (event_authority, _) = ctx
+        .svm
+        .get_pda_with_bump(&[b"__event_authority"], &bounty_hunter::ID);
+
+    let maker = ctx.svm.create_funded_account(10_000_000_000).unwrap();
+    let hunter = ctx.svm.create_funded_account(10_000_000_000).unwrap();
+
+    let seed = 1u64;
+
+    let (bounty, _) = ctx.svm.get_pda_with_bump(
+        &[b"bounty", maker.pubkey().as_array(), &seed.to_le_bytes()],
+        &bounty_hunter::ID,
+    );
+    let (maker_profile, _) = ctx.svm.get_pda_with_bump(
+        &[b"maker_profile", maker.pubkey().as_ref()],
+        &bounty_hunter::ID,
+    );
+
+    let (min_reward, _) = ctx.svm.get_pda_with_bump(
+        &[b"min_reward", Pubkey::default().as_ref()],
+        &bounty_hunter::ID,
+    );
+    let create_ix = ctx
+        .program()
+        .accounts(bounty_hunter::accounts::CreateBounty {
+            maker: maker.pubkey(),
+            bounty,
+            maker_profile,
+            mint: None,
+            maker_token_account: None,
+            vault: None,
+            min_reward,
+            system_program: solana_system_interface::program::ID,
+            token_program: None,
+            associated_token_program: None,
+            event_authority,
+            program: bounty_hunter::ID,
+        })
+        .args(bounty_hunter::instruction::CreateBounty {
+            seed,
+            description: "review me".to_string(),
+            link: "https://issue.link".to_string(),
+            reward: 1_000_000,
+            deadline: None,
+            prize_bps: vec![],
+            milestones: vec![],
+            arbiter: None,
+            access: BountyAccess::Public,
+            submission_bond: 0,
+            content_hash: None,
+        })
+        .instruction()
+        .unwrap();
+
+    ctx.execute_instruction(create_ix, &[&maker])
+        .unwrap()
+        .assert_success();
+
+    let (submission, _) = ctx.svm.get_pda_with_bump(
+        &[b"submission", hunter.pubkey().as_ref(), bounty.as_ref()],
+        &bounty_hunter::ID,
+    );
+
+    let submit_ix = ctx
+        .program()
+        .accounts(bounty_hunter::accounts::SubmitSolution {
+            hunter: hunter.pubkey(),
+            bounty,
+            submission,
+            system_program: solana_system_interface::program::ID,
+            event_authority,
+            program: bounty_hunter::ID,
+        })
+        .args(bounty_hunter::instruction::SubmitSolution {
+            link: "https://github.com/hunter/draft".to_string(),
+            notes: "draft".to_string(),
+            proof: vec![],
+            content_hash: None,
+        })
+        .instruction()
+        .unwrap();
+
+    ctx.execute_instruction(submit_ix, &[&hunter])
+        .unwrap()
+        .assert_success();
+
+    let edit_ix = |ctx: &AnchorContext, notes: &str| {
+        ctx.program()
+            .accounts(bounty_hunter::accounts::EditSubmission {
+                hunter: hunter.pubkey(),
+                bounty,
+                submission,
+                system_program: solana_system_interface::program::ID,
+                event_authority,
+                program: bounty_hunter::ID,
+            })
+            .args(bounty_hunter::instruction::EditSubmission {
+                link: "https://github.com/hunter/final".to_string(),
+                notes: notes.to_string(),
+                content_hash: None,
+            })
+            .instruction()
+            .unwrap()
+    };
+
+    let ix = edit_ix(&ctx, "final");
+    ctx.execute_instruction(ix, &[&hunter])
+        .unwrap()
+        .assert_success();
+
+    let s: Submission = ctx.get_account(&submission).unwrap();
+    assert_eq!(s.link, "https://github.com/hunter/final".to_string());
+    assert_eq!(s.edits, 1);
+    assert_eq!(s.status, SubmissionStatus::Pending);
+
+    // the maker locks the submission before reading it
+    let review_ix = ctx
+        .program()
+        .accounts(bounty_hunter::accounts::ReviewSubmission {
+            maker: maker.pubkey(),
+            bounty,
+            submission,
+            event_authority,
+            program: bounty_hunter::ID,
+        })
+        .args(bounty_hunter::instruction::ReviewSubmission {})
+        .instruction()
+        .unwrap();
+
+    ctx.execute_instruction(review_ix, &[&maker])
+        .unwrap()
+        .assert_success();
+
+    let ix = edit_ix(&ctx, "sneaky change");
+    ctx.execute_instruction(ix, &[&hunter])
+        .unwrap()
+        .assert_anchor_error("SubmissionLocked");
+
+    let s: Submission = ctx.get_account(&submission).unwrap();
+    assert_eq!(s.notes, "final".to_string());
+    assert_eq!(s.status, SubmissionStatus::InReview);
+
+    // withdrawing hands the rent back and reopens the bounty
+    let withdraw_ix = ctx
+        .program()
+        .accounts(bounty_hunter::accounts::WithdrawSubmission {
+            hunter: hunter.pubkey(),
+            bounty,
+            submission,
+            event_authority,
+            program: bounty_hunter::ID,
+        })
+        .args(bounty_hunter::instruction::WithdrawSubmission {})
+        .instruction()
+        .unwrap();
+
+    ctx.execute_instruction(withdraw_ix, &[&hunter])
+        .unwrap()
+        .assert_success();
+
+    // --- Assertions ---
+    ctx.svm.assert_account_closed(&submission);
+
+    let b: Bounty = ctx.get_account(&bounty).unwrap();
+    assert_eq!(b.submissions, 0);
+    assert_eq!(b.status, BountyStatus::Open);
+}
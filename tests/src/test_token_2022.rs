@@ -0,0 +1,383 @@
+use anchor_litesvm::{AnchorContext, AnchorLiteSVM, Instruction, Keypair, Pubkey, Signer};
+use bounty_hunter::state::{Bounty, BountyAccess};
+use litesvm_utils::{AssertionHelpers, TestHelpers};
+use spl_associated_token_account_client::{
+    address::get_associated_token_address_with_program_id,
+    instruction::create_associated_token_account,
+};
+use spl_token_2022::extension::{
+    transfer_fee::instruction::initialize_transfer_fee_config, ExtensionType, StateWithExtensions,
+};
+
+use crate::program_data::set_upgrade_authority;
+use crate::review::review_submission;
+
+/// creates `mint` as a Token-2022 mint, `init_extension` sets up its only extension
+fn create_mint_2022(
+    ctx: &mut AnchorContext,
+    authority: &Keypair,
+    mint: &Keypair,
+    extension: ExtensionType,
+    init_extension: Instruction,
+) {
+    let space =
+        ExtensionType::try_calculate_account_len::<spl_token_2022::state::Mint>(&[extension])
+            .unwrap();
+
+    let create_ix = solana_system_interface::instruction::create_account(
+        &authority.pubkey(),
+        &mint.pubkey(),
+        ctx.svm.minimum_balance_for_rent_exemption(space),
+        space as u64,
+        &spl_token_2022::ID,
+    );
+    let init_mint_ix = spl_token_2022::instruction::initialize_mint2(
+        &spl_token_2022::ID,
+        &mint.pubkey(),
+        &authority.pubkey(),
+        None,
+        6,
+    )
+    .unwrap();
+
+    ctx.execute_instructions(
+        vec![create_ix, init_extension, init_mint_ix],
+        &[authority, mint],
+    )
+    .unwrap()
+    .assert_success();
+}
+
+fn create_token_account_2022(
+    ctx: &mut AnchorContext,
+    payer: &Keypair,
+    owner: &Pubkey,
+    mint: &Pubkey,
+) -> Pubkey {
+    let ix = create_associated_token_account(&payer.pubkey(), owner, mint, &spl_token_2022::ID);
+    ctx.execute_instruction(ix, &[payer])
+        .unwrap()
+        .assert_success();
+    get_associated_token_address_with_program_id(owner, mint, &spl_token_2022::ID)
+}
+
+// the litesvm helpers unpack legacy token accounts only
+fn token_balance_2022(ctx: &AnchorContext, token_account: &Pubkey) -> u64 {
+    let account = ctx.svm.get_account(token_account).unwrap();
+    StateWithExtensions::<spl_token_2022::state::Account>::unpack(&account.data)
+        .unwrap()
+        .base
+        .amount
+}
+
+#[test]
+fn transfer_fee_bounty_test() {
+    let mut ctx = AnchorLiteSVM::build_with_program(
+        bounty_hunter::ID,
+        include_bytes!("../../target/deploy/bounty_hunter.so"),
+    );
+    let (event_authority, _) = ctx
+        .svm
+        .get_pda_with_bump(&[b"__event_authority"], &bounty_hunter::ID);
+
+    let maker = ctx.svm.create_funded_account(10_000_000_000).unwrap();
+    let hunter = ctx.svm.create_funded_account(10_000_000_000).unwrap();
+
+    // 1% withheld on every transfer
+    let mint = Keypair::new();
+    let fee_config_ix = initialize_transfer_fee_config(
+        &spl_token_2022::ID,
+        &mint.pubkey(),
+        None,
+        None,
+        100,
+        u64::MAX,
+    )
+    .unwrap();
+    create_mint_2022(
+        &mut ctx,
+        &maker,
+        &mint,
+        ExtensionType::TransferFeeConfig,
+        fee_config_ix,
+    );
+
+    let maker_token_account =
+        create_token_account_2022(&mut ctx, &maker, &maker.pubkey(), &mint.pubkey());
+    let mint_to_ix = spl_token_2022::instruction::mint_to(
+        &spl_token_2022::ID,
+        &mint.pubkey(),
+        &maker_token_account,
+        &maker.pubkey(),
+        &[],
+        10_000,
+    )
+    .unwrap();
+    ctx.execute_instruction(mint_to_ix, &[&maker])
+        .unwrap()
+        .assert_success();
+
+    let (config, _) = ctx.svm.get_pda_with_bump(&[b"config"], &bounty_hunter::ID);
+
+    let program_data = set_upgrade_authority(&mut ctx, &maker.pubkey());
+
+    let config_ix = ctx
+        .program()
+        .accounts(bounty_hunter::accounts::InitializeConfig {
+            admin: maker.pubkey(),
+            config,
+            program_data,
+            system_program: solana_system_interface::program::ID,
+            event_authority,
+            program: bounty_hunter::ID,
+        })
+        .args(bounty_hunter::instruction::InitializeConfig {
+            fee_bps: 0,
+            treasury: maker.pubkey(),
+        })
+        .instruction()
+        .unwrap();
+
+    ctx.execute_instruction(config_ix, &[&maker])
+        .unwrap()
+        .assert_success();
+
+    let seed = 1u64;
+    let reward = 1_000u64;
+
+    let (bounty, _) = ctx.svm.get_pda_with_bump(
+        &[b"bounty", maker.pubkey().as_array(), &seed.to_le_bytes()],
+        &bounty_hunter::ID,
+    );
+    let (maker_profile, _) = ctx.svm.get_pda_with_bump(
+        &[b"maker_profile", maker.pubkey().as_ref()],
+        &bounty_hunter::ID,
+    );
+    let vault =
+        get_associated_token_address_with_program_id(&bounty, &mint.pubkey(), &spl_token_2022::ID);
+
+    let (min_reward, _) = ctx
+        .svm
+        .get_pda_with_bump(&[b"min_reward", mint.pubkey().as_ref()], &bounty_hunter::ID);
+    let create_ix = ctx
+        .program()
+        .accounts(bounty_hunter::accounts::CreateBounty {
+            maker: maker.pubkey(),
+            bounty,
+            maker_profile,
+            mint: Some(mint.pubkey()),
+            maker_token_account: Some(maker_token_account),
+            vault: Some(vault),
+            min_reward,
+            system_program: solana_system_interface::program::ID,
+            token_program: Some(spl_token_2022::ID),
+            associated_token_program: Some(spl_associated_token_account_client::program::ID),
+            event_authority,
+            program: bounty_hunter::ID,
+        })
+        .args(bounty_hunter::instruction::CreateBounty {
+            seed,
+            description: "paid in a fee charging stablecoin".to_string(),
+            link: "https://issue.link".to_string(),
+            reward,
+            deadline: None,
+            prize_bps: vec![],
+            milestones: vec![],
+            arbiter: None,
+            access: BountyAccess::Public,
+            submission_bond: 0,
+            content_hash: None,
+        })
+        .instruction()
+        .unwrap();
+
+    ctx.execute_instruction(create_ix, &[&maker])
+        .unwrap()
+        .assert_success();
+
+    // the vault holds the reward plus what paying it out will cost, net of the deposit fee
+    let b: Bounty = ctx.get_account(&bounty).unwrap();
+    assert_eq!(b.escrowed, reward);
+    assert_eq!(b.fee_reserve, 11);
+    assert_eq!(token_balance_2022(&ctx, &vault), reward + 11);
+    assert_eq!(
+        token_balance_2022(&ctx, &maker_token_account),
+        10_000 - 1_022
+    );
+
+    let (submission, _) = ctx.svm.get_pda_with_bump(
+        &[b"submission", hunter.pubkey().as_ref(), bounty.as_ref()],
+        &bounty_hunter::ID,
+    );
+
+    let submit_ix = ctx
+        .program()
+        .accounts(bounty_hunter::accounts::SubmitSolution {
+            hunter: hunter.pubkey(),
+            bounty,
+            submission,
+            system_program: solana_system_interface::program::ID,
+            event_authority,
+            program: bounty_hunter::ID,
+        })
+        .args(bounty_hunter::instruction::SubmitSolution {
+            link: "https://github.com/hunter/solution".to_string(),
+            notes: "done".to_string(),
+            proof: vec![],
+            content_hash: None,
+        })
+        .instruction()
+        .unwrap();
+
+    ctx.execute_instruction(submit_ix, &[&hunter])
+        .unwrap()
+        .assert_success();
+
+    review_submission(&mut ctx, &maker, bounty, submission);
+
+    // the hunter never held the mint, the maker creates its token account on accept
+    let hunter_token_account = get_associated_token_address_with_program_id(
+        &hunter.pubkey(),
+        &mint.pubkey(),
+        &spl_token_2022::ID,
+    );
+
+    let (receipt, _) = ctx
+        .svm
+        .get_pda_with_bump(&[b"receipt", bounty.as_ref(), &[0]], &bounty_hunter::ID);
+
+    let (hunter_profile, _) = ctx.svm.get_pda_with_bump(
+        &[b"hunter_profile", hunter.pubkey().as_ref()],
+        &bounty_hunter::ID,
+    );
+    let accept_ix = ctx
+        .program()
+        .accounts(bounty_hunter::accounts::AcceptSolution {
+            maker: maker.pubkey(),
+            bounty,
+            submission,
+            receipt,
+            maker_profile,
+            hunter_profile,
+            vault: Some(vault),
+            hunter: hunter.pubkey(),
+            mint: Some(mint.pubkey()),
+            hunter_token_account: Some(hunter_token_account),
+            token_program: Some(spl_token_2022::ID),
+            associated_token_program: Some(spl_associated_token_account_client::program::ID),
+            memo_program: None,
+            config,
+            treasury: maker.pubkey(),
+            treasury_token_account: None,
+            system_program: solana_system_interface::program::ID,
+            event_authority,
+            program: bounty_hunter::ID,
+        })
+        .args(bounty_hunter::instruction::AcceptSolution {})
+        .instruction()
+        .unwrap();
+
+    ctx.execute_instruction(accept_ix, &[&maker])
+        .unwrap()
+        .assert_success();
+
+    // --- Assertions ---
+    assert_eq!(token_balance_2022(&ctx, &hunter_token_account), reward);
+    ctx.svm.assert_account_closed(&vault);
+}
+
+#[test]
+fn non_transferable_mint_rejected_test() {
+    let mut ctx = AnchorLiteSVM::build_with_program(
+        bounty_hunter::ID,
+        include_bytes!("../../target/deploy/bounty_hunter.so"),
+    );
+    let (event_authority, _) = ctx
+        .svm
+        .get_pda_with_bump(&[b"__event_authority"], &bounty_hunter::ID);
+
+    let maker = ctx.svm.create_funded_account(10_000_000_000).unwrap();
+
+    let mint = Keypair::new();
+    let non_transferable_ix = spl_token_2022::instruction::initialize_non_transferable_mint(
+        &spl_token_2022::ID,
+        &mint.pubkey(),
+    )
+    .unwrap();
+    create_mint_2022(
+        &mut ctx,
+        &maker,
+        &mint,
+        ExtensionType::NonTransferable,
+        non_transferable_ix,
+    );
+
+    let maker_token_account =
+        create_token_account_2022(&mut ctx, &maker, &maker.pubkey(), &mint.pubkey());
+    let mint_to_ix = spl_token_2022::instruction::mint_to(
+        &spl_token_2022::ID,
+        &mint.pubkey(),
+        &maker_token_account,
+        &maker.pubkey(),
+        &[],
+        10_000,
+    )
+    .unwrap();
+    ctx.execute_instruction(mint_to_ix, &[&maker])
+        .unwrap()
+        .assert_success();
+
+    let seed = 1u64;
+
+    let (bounty, _) = ctx.svm.get_pda_with_bump(
+        &[b"bounty", maker.pubkey().as_array(), &seed.to_le_bytes()],
+        &bounty_hunter::ID,
+    );
+    let (maker_profile, _) = ctx.svm.get_pda_with_bump(
+        &[b"maker_profile", maker.pubkey().as_ref()],
+        &bounty_hunter::ID,
+    );
+    let vault =
+        get_associated_token_address_with_program_id(&bounty, &mint.pubkey(), &spl_token_2022::ID);
+
+    let (min_reward, _) = ctx
+        .svm
+        .get_pda_with_bump(&[b"min_reward", mint.pubkey().as_ref()], &bounty_hunter::ID);
+    let create_ix = ctx
+        .program()
+        .accounts(bounty_hunter::accounts::CreateBounty {
+            maker: maker.pubkey(),
+            bounty,
+            maker_profile,
+            mint: Some(mint.pubkey()),
+            maker_token_account: Some(maker_token_account),
+            vault: Some(vault),
+            min_reward,
+            system_program: solana_system_interface::program::ID,
+            token_program: Some(spl_token_2022::ID),
+            associated_token_program: Some(spl_associated_token_account_client::program::ID),
+            event_authority,
+            program: bounty_hunter::ID,
+        })
+        .args(bounty_hunter::instruction::CreateBounty {
+            seed,
+            description: "soulbound reward".to_string(),
+            link: "https://issue.link".to_string(),
+            reward: 1_000,
+            deadline: None,
+            prize_bps: vec![],
+            milestones: vec![],
+            arbiter: None,
+            access: BountyAccess::Public,
+            submission_bond: 0,
+            content_hash: None,
+        })
+        .instruction()
+        .unwrap();
+
+    // --- Assertions ---
+    ctx.execute_instruction(create_ix, &[&maker])
+        .unwrap()
+        .assert_anchor_error("UnsupportedMint");
+    ctx.svm.assert_account_closed(&bounty);
+}
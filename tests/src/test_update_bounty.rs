@@ -0,0 +1,243 @@
+use anchor_litesvm::{AnchorContext, AnchorLiteSVM, Pubkey, Signer};
+use bounty_hunter::state::{Bounty, BountyAccess};
+use litesvm_utils::TestHelpers;
+
+use crate::program_data::set_upgrade_authority;
+use crate::review::review_submission;
+
+/// what the bounty holds on top of its rent
+fn escrow(ctx: &AnchorContext, bounty: &Pubkey) -> u64 {
+    let account = ctx.svm.get_account(bounty).unwrap();
+    account.lamports
+        - ctx
+            .svm
+            .minimum_balance_for_rent_exemption(account.data.len())
+}
+
+#[test]
+fn update_bounty_test() {
+    let mut ctx = AnchorLiteSVM::build_with_program(
+        bounty_hunter::ID,
+        include_bytes!("../../target/deploy/bounty_hunter.so"),
+    );
+    let (event_authority, _) = ctx
+        .svm
+        .get_pda_with_bump(&[b"__event_authority"], &bounty_hunter::ID);
+
+    let maker = ctx.svm.create_funded_account(10_000_000_000).unwrap();
+    let hunter = ctx.svm.create_funded_account(10_000_000_000).unwrap();
+    let reward = 1_000_000_000u64;
+
+    let (config, _) = ctx.svm.get_pda_with_bump(&[b"config"], &bounty_hunter::ID);
+
+    let program_data = set_upgrade_authority(&mut ctx, &maker.pubkey());
+
+    let config_ix = ctx
+        .program()
+        .accounts(bounty_hunter::accounts::InitializeConfig {
+            admin: maker.pubkey(),
+            config,
+            program_data,
+            system_program: solana_system_interface::program::ID,
+            event_authority,
+            program: bounty_hunter::ID,
+        })
+        .args(bounty_hunter::instruction::InitializeConfig {
+            fee_bps: 0,
+            treasury: maker.pubkey(),
+        })
+        .instruction()
+        .unwrap();
+
+    ctx.execute_instruction(config_ix, &[&maker])
+        .unwrap()
+        .assert_success();
+
+    let seed = 1u64;
+
+    let (bounty, _) = ctx.svm.get_pda_with_bump(
+        &[b"bounty", maker.pubkey().as_array(), &seed.to_le_bytes()],
+        &bounty_hunter::ID,
+    );
+    let (maker_profile, _) = ctx.svm.get_pda_with_bump(
+        &[b"maker_profile", maker.pubkey().as_ref()],
+        &bounty_hunter::ID,
+    );
+
+    let (min_reward, _) = ctx.svm.get_pda_with_bump(
+        &[b"min_reward", Pubkey::default().as_ref()],
+        &bounty_hunter::ID,
+    );
+    let create_ix = ctx
+        .program()
+        .accounts(bounty_hunter::accounts::CreateBounty {
+            maker: maker.pubkey(),
+            bounty,
+            maker_profile,
+            mint: None,
+            maker_token_account: None,
+            vault: None,
+            min_reward,
+            system_program: solana_system_interface::program::ID,
+            token_program: None,
+            associated_token_program: None,
+            event_authority,
+            program: bounty_hunter::ID,
+        })
+        .args(bounty_hunter::instruction::CreateBounty {
+            seed,
+            description: "fix teh parser".to_string(),
+            link: "https://issue.link".to_string(),
+            reward,
+            deadline: None,
+            prize_bps: vec![],
+            milestones: vec![],
+            arbiter: None,
+            access: BountyAccess::Public,
+            submission_bond: 0,
+            content_hash: None,
+        })
+        .instruction()
+        .unwrap();
+
+    ctx.execute_instruction(create_ix, &[&maker])
+        .unwrap()
+        .assert_success();
+
+    let b: Bounty = ctx.get_account(&bounty).unwrap();
+    assert_eq!(b.edits, 0);
+    let created_slot = b.updated_slot;
+
+    let update_ix = |ctx: &AnchorContext, description: &str| {
+        ctx.program()
+            .accounts(bounty_hunter::accounts::UpdateBounty {
+                maker: maker.pubkey(),
+                bounty,
+                system_program: solana_system_interface::program::ID,
+                event_authority,
+                program: bounty_hunter::ID,
+            })
+            .args(bounty_hunter::instruction::UpdateBounty {
+                description: description.to_string(),
+                link: "https://issue.link/2".to_string(),
+                content_hash: None,
+            })
+            .instruction()
+            .unwrap()
+    };
+
+    // growing the account is paid by the maker, not out of the escrow
+    ctx.svm.warp_to_slot(created_slot + 10);
+    let ix = update_ix(
+        &ctx,
+        "fix the parser, and handle trailing commas while you are at it",
+    );
+    ctx.execute_instruction(ix, &[&maker])
+        .unwrap()
+        .assert_success();
+
+    let b: Bounty = ctx.get_account(&bounty).unwrap();
+    assert_eq!(
+        b.description,
+        "fix the parser, and handle trailing commas while you are at it"
+    );
+    assert_eq!(b.link, "https://issue.link/2");
+    assert_eq!(b.edits, 1);
+    assert_eq!(b.updated_slot, created_slot + 10);
+    assert_eq!(escrow(&ctx, &bounty), reward);
+
+    // shrinking it only gives the rent back
+    let ix = update_ix(&ctx, "fix the parser");
+    ctx.execute_instruction(ix, &[&maker])
+        .unwrap()
+        .assert_success();
+
+    let b: Bounty = ctx.get_account(&bounty).unwrap();
+    assert_eq!(b.edits, 2);
+    assert_eq!(
+        ctx.svm.get_account(&bounty).unwrap().data.len(),
+        Bounty::space("fix the parser", "https://issue.link/2")
+    );
+    assert_eq!(escrow(&ctx, &bounty), reward);
+
+    let (submission, _) = ctx.svm.get_pda_with_bump(
+        &[b"submission", hunter.pubkey().as_ref(), bounty.as_ref()],
+        &bounty_hunter::ID,
+    );
+
+    let submit_ix = ctx
+        .program()
+        .accounts(bounty_hunter::accounts::SubmitSolution {
+            hunter: hunter.pubkey(),
+            bounty,
+            submission,
+            system_program: solana_system_interface::program::ID,
+            event_authority,
+            program: bounty_hunter::ID,
+        })
+        .args(bounty_hunter::instruction::SubmitSolution {
+            link: "https://github.com/hunter/solution".to_string(),
+            notes: "done".to_string(),
+            proof: vec![],
+            content_hash: None,
+        })
+        .instruction()
+        .unwrap();
+
+    ctx.execute_instruction(submit_ix, &[&hunter])
+        .unwrap()
+        .assert_success();
+
+    review_submission(&mut ctx, &maker, bounty, submission);
+
+    let (receipt, _) = ctx
+        .svm
+        .get_pda_with_bump(&[b"receipt", bounty.as_ref(), &[0]], &bounty_hunter::ID);
+    let (hunter_profile, _) = ctx.svm.get_pda_with_bump(
+        &[b"hunter_profile", hunter.pubkey().as_ref()],
+        &bounty_hunter::ID,
+    );
+
+    let accept_ix = ctx
+        .program()
+        .accounts(bounty_hunter::accounts::AcceptSolution {
+            maker: maker.pubkey(),
+            bounty,
+            submission,
+            receipt,
+            maker_profile,
+            hunter_profile,
+            vault: None,
+            hunter: hunter.pubkey(),
+            mint: None,
+            hunter_token_account: None,
+            token_program: None,
+            associated_token_program: None,
+            config,
+            treasury: maker.pubkey(),
+            treasury_token_account: None,
+            system_program: solana_system_interface::program::ID,
+            memo_program: None,
+            event_authority,
+            program: bounty_hunter::ID,
+        })
+        .args(bounty_hunter::instruction::AcceptSolution {})
+        .instruction()
+        .unwrap();
+
+    ctx.execute_instruction(accept_ix, &[&maker])
+        .unwrap()
+        .assert_success();
+
+    // --- Assertions ---
+
+    // the terms are settled once the hunter has been paid
+    let ix = update_ix(&ctx, "something else entirely");
+    ctx.execute_instruction(ix, &[&maker])
+        .unwrap()
+        .assert_anchor_error("BountyClosed");
+
+    let b: Bounty = ctx.get_account(&bounty).unwrap();
+    assert_eq!(b.description, "fix the parser");
+    assert_eq!(b.edits, 2);
+}
@@ -0,0 +1,186 @@
+use anchor_litesvm::{AnchorContext, AnchorLiteSVM, Instruction, Keypair, Pubkey, Signer};
+use bounty_hunter::state::{Bounty, BountyAccess};
+use litesvm_utils::TestHelpers;
+
+fn create_ix(
+    ctx: &AnchorContext,
+    maker: &Keypair,
+    bounty: Pubkey,
+    description: &str,
+    link: &str,
+    reward: u64,
+) -> Instruction {
+    let (event_authority, _) = ctx
+        .svm
+        .get_pda_with_bump(&[b"__event_authority"], &bounty_hunter::ID);
+    let (min_reward, _) = ctx.svm.get_pda_with_bump(
+        &[b"min_reward", Pubkey::default().as_ref()],
+        &bounty_hunter::ID,
+    );
+    let (maker_profile, _) = ctx.svm.get_pda_with_bump(
+        &[b"maker_profile", maker.pubkey().as_ref()],
+        &bounty_hunter::ID,
+    );
+
+    ctx.program()
+        .accounts(bounty_hunter::accounts::CreateBounty {
+            maker: maker.pubkey(),
+            bounty,
+            maker_profile,
+            mint: None,
+            maker_token_account: None,
+            vault: None,
+            min_reward,
+            system_program: solana_system_interface::program::ID,
+            token_program: None,
+            associated_token_program: None,
+            event_authority,
+            program: bounty_hunter::ID,
+        })
+        .args(bounty_hunter::instruction::CreateBounty {
+            seed: 1,
+            description: description.to_string(),
+            link: link.to_string(),
+            reward,
+            deadline: None,
+            prize_bps: vec![],
+            milestones: vec![],
+            arbiter: None,
+            access: BountyAccess::Public,
+            submission_bond: 0,
+            content_hash: None,
+        })
+        .instruction()
+        .unwrap()
+}
+
+#[test]
+fn create_bounty_validation_test() {
+    let mut ctx = AnchorLiteSVM::build_with_program(
+        bounty_hunter::ID,
+        include_bytes!("../../target/deploy/bounty_hunter.so"),
+    );
+
+    let maker = ctx.svm.create_funded_account(10_000_000_000).unwrap();
+    let (bounty, _) = ctx.svm.get_pda_with_bump(
+        &[b"bounty", maker.pubkey().as_array(), &1u64.to_le_bytes()],
+        &bounty_hunter::ID,
+    );
+
+    let description = "a".repeat(bounty_hunter::constants::MAX_DESCRIPTION_LEN + 1);
+    let link = format!(
+        "https://{}",
+        "a".repeat(bounty_hunter::constants::MAX_LINK_LEN)
+    );
+    let (parser, issue, reward) = ("fix the parser", "https://issue.link", 1_000_000_000);
+    let rejected = [
+        (parser, "", reward, "EmptyLink"),
+        (parser, "issue.link", reward, "InvalidLinkScheme"),
+        (parser, "ftp://issue.link", reward, "InvalidLinkScheme"),
+        (parser, "https://", reward, "InvalidLinkScheme"),
+        (parser, &link, reward, "LinkTooLong"),
+        (parser, issue, 0, "ZeroReward"),
+        (&description, issue, reward, "DescriptionTooLong"),
+    ];
+    for (description, link, reward, error) in rejected {
+        let ix = create_ix(&ctx, &maker, bounty, description, link, reward);
+        ctx.execute_instruction(ix, &[&maker])
+            .unwrap()
+            .assert_anchor_error(error);
+    }
+
+    let ix = create_ix(
+        &ctx,
+        &maker,
+        bounty,
+        "fix the parser",
+        "http://issue.link",
+        1_000_000_000,
+    );
+    ctx.execute_instruction(ix, &[&maker])
+        .unwrap()
+        .assert_success();
+
+    // --- Assertions ---
+    let b: Bounty = ctx.get_account(&bounty).unwrap();
+    assert_eq!(b.link, "http://issue.link");
+}
+
+#[test]
+fn submit_solution_validation_test() {
+    let mut ctx = AnchorLiteSVM::build_with_program(
+        bounty_hunter::ID,
+        include_bytes!("../../target/deploy/bounty_hunter.so"),
+    );
+    let (event_authority, _) = ctx
+        .svm
+        .get_pda_with_bump(&[b"__event_authority"], &bounty_hunter::ID);
+
+    let maker = ctx.svm.create_funded_account(10_000_000_000).unwrap();
+    let hunter = ctx.svm.create_funded_account(10_000_000_000).unwrap();
+    let (bounty, _) = ctx.svm.get_pda_with_bump(
+        &[b"bounty", maker.pubkey().as_array(), &1u64.to_le_bytes()],
+        &bounty_hunter::ID,
+    );
+
+    let ix = create_ix(
+        &ctx,
+        &maker,
+        bounty,
+        "fix the parser",
+        "https://issue.link",
+        1_000_000_000,
+    );
+    ctx.execute_instruction(ix, &[&maker])
+        .unwrap()
+        .assert_success();
+
+    let (submission, _) = ctx.svm.get_pda_with_bump(
+        &[b"submission", hunter.pubkey().as_ref(), bounty.as_ref()],
+        &bounty_hunter::ID,
+    );
+
+    let submit_ix = |ctx: &AnchorContext, link: &str, notes: String| {
+        ctx.program()
+            .accounts(bounty_hunter::accounts::SubmitSolution {
+                hunter: hunter.pubkey(),
+                bounty,
+                submission,
+                system_program: solana_system_interface::program::ID,
+                event_authority,
+                program: bounty_hunter::ID,
+            })
+            .args(bounty_hunter::instruction::SubmitSolution {
+                link: link.to_string(),
+                notes,
+                proof: vec![],
+                content_hash: None,
+            })
+            .instruction()
+            .unwrap()
+    };
+
+    let ix = submit_ix(&ctx, "github.com/hunter/solution", "done".to_string());
+    ctx.execute_instruction(ix, &[&hunter])
+        .unwrap()
+        .assert_anchor_error("InvalidLinkScheme");
+
+    let notes = "a".repeat(bounty_hunter::constants::MAX_NOTES_LEN + 1);
+    let ix = submit_ix(&ctx, "https://github.com/hunter/solution", notes);
+    ctx.execute_instruction(ix, &[&hunter])
+        .unwrap()
+        .assert_anchor_error("NotesTooLong");
+
+    let ix = submit_ix(
+        &ctx,
+        "https://github.com/hunter/solution",
+        "done".to_string(),
+    );
+    ctx.execute_instruction(ix, &[&hunter])
+        .unwrap()
+        .assert_success();
+
+    // --- Assertions ---
+    let b: Bounty = ctx.get_account(&bounty).unwrap();
+    assert_eq!(b.submissions, 1);
+}